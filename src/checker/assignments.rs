@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use crate::parser::TokenSpan;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Assignment {
+    Unassigned,
+    MaybeAssigned,
+    Assigned,
+}
+
+impl Assignment {
+    // The state of a value after control flow from two paths merges
+    fn join(self, other: Assignment) -> Assignment {
+        if self == other {
+            self
+        } else {
+            Assignment::MaybeAssigned
+        }
+    }
+}
+
+// A snapshot of the values which are not definitely assigned at some point in a function, keyed
+// by the depth of the scope declaring them and their name
+#[derive(Clone, Default)]
+pub struct Assignments {
+    values: HashMap<(usize, String), Assignment>,
+}
+
+impl Assignments {
+    pub fn insert(&mut self, depth: usize, name: &str, assignment: Assignment) {
+        if assignment != Assignment::Assigned {
+            self.values.insert((depth, name.to_owned()), assignment);
+        }
+    }
+
+    pub fn get(&self, depth: usize, name: &str) -> Assignment {
+        self.values
+            .get(&(depth, name.to_owned()))
+            .copied()
+            .unwrap_or(Assignment::Assigned)
+    }
+
+    // Merges the snapshots of every path reaching a point, where no paths means it is unreachable
+    pub fn join(paths: Vec<Assignments>) -> Assignments {
+        let mut paths = paths.into_iter();
+        let Some(mut result) = paths.next() else {
+            return Assignments::default();
+        };
+
+        for path in paths {
+            let mut values = HashMap::new();
+            for key in result.values.keys().chain(path.values.keys()) {
+                let assignment = result.get(key.0, &key.1).join(path.get(key.0, &key.1));
+                values.insert(key.clone(), assignment);
+            }
+            result.values = values;
+        }
+
+        result
+    }
+}
+
+// What the body of a loop does to the assignments of values, which decides the assignments after
+// the loop and whether it assigns an immutable value more than once
+#[derive(Default)]
+pub struct LoopState {
+    pub breaks: Vec<Assignments>,
+    pub continues: Vec<Assignments>,
+    // Immutable values declared outside the loop and assigned within it while unassigned, which
+    // is only an error if the loop may run the assignment again
+    pub assignments: Vec<LoopAssignment>,
+}
+
+pub struct LoopAssignment {
+    pub depth: usize,
+    pub name: String,
+    pub span: TokenSpan,
+    pub declaration: Option<TokenSpan>,
+}
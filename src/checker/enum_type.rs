@@ -1,28 +1,42 @@
 use std::{cell::OnceCell, collections::HashMap, rc::Rc};
 
 use crate::{
-    checker::{FunctionType, InterfaceType, Scope, Type, Types},
-    parser::EnumNode,
+    checker::{
+        FunctionType, InterfaceType, Scope, Type, TypeParameterBindings, TypeParameterList, Types,
+    },
+    parser::{EnumNode, NodeVec, TokenSpan, TypeNode},
 };
 
 pub struct EnumType {
     node: Rc<EnumNode>,
-    pub variants: HashMap<String, Option<Type>>,
+    pub type_parameters: TypeParameterList,
+    pub type_arguments: Vec<Type>,
+    bindings: TypeParameterBindings,
+    variants: OnceCell<HashMap<String, Option<Type>>>,
     methods: OnceCell<HashMap<String, EnumMethod>>,
 }
 
 impl EnumType {
-    pub fn from(node: Rc<EnumNode>, types: &impl Types) -> Rc<EnumType> {
-        let mut variants = HashMap::new();
-        for variant in node.variants.iter() {
-            let name = variant.name.clone();
-            let variant = variant.get_type(types).cloned();
-            variants.entry(name).or_insert(variant);
-        }
+    pub fn from(node: Rc<EnumNode>) -> Rc<EnumType> {
+        let type_parameters = node.get_type_parameters();
+        Self::with_bindings(node, type_parameters, TypeParameterBindings::new())
+    }
 
+    fn with_bindings(
+        node: Rc<EnumNode>,
+        type_parameters: TypeParameterList,
+        bindings: TypeParameterBindings,
+    ) -> Rc<EnumType> {
+        let type_arguments = type_parameters
+            .iter()
+            .map(|type_parameter| type_parameter.bind(&bindings))
+            .collect();
         Rc::new(EnumType {
             node,
-            variants,
+            type_parameters,
+            type_arguments,
+            bindings,
+            variants: OnceCell::new(),
             methods: OnceCell::new(),
         })
     }
@@ -31,11 +45,84 @@ impl EnumType {
         &self.node.name
     }
 
-    pub fn get_variant(self: &Rc<Self>, name: &String) -> Option<Type> {
+    // The span of the name in the type's declaration, which identifies it across modules
+    pub fn name_span(&self) -> TokenSpan {
+        self.node.name.span
+    }
+
+    // Whether this is a generic enum which has not been given any type arguments
+    pub fn is_unbound(&self) -> bool {
+        !self.type_parameters.is_empty() && self.bindings.is_empty()
+    }
+
+    pub fn bind(self: &Rc<Self>, types: &impl Types, bindings: &TypeParameterBindings) -> Rc<Self> {
+        if self.type_parameters.is_empty() {
+            return self.clone();
+        }
+
+        let type_arguments = self
+            .type_arguments
+            .iter()
+            .map(|type_argument| type_argument.bind(types, bindings))
+            .collect::<Vec<_>>();
+        let bindings = self.type_parameters.get_bindings(&type_arguments);
+        Self::with_bindings(self.node.clone(), self.type_parameters.clone(), bindings)
+    }
+
+    pub fn bind_arguments(
+        &self,
+        types: &impl Types,
+        bound_type_params: &NodeVec<TypeNode>,
+        bound_types: &[Type],
+    ) -> Rc<Self> {
+        let bindings = self
+            .type_parameters
+            .bind_types(types, bound_type_params, bound_types);
+        Self::with_bindings(self.node.clone(), self.type_parameters.clone(), bindings)
+    }
+
+    // The inner type of each variant, bound on first use so that variants can refer to the enum
+    pub fn get_variant_types(&self, types: &impl Types) -> &HashMap<String, Option<Type>> {
+        self.variants.get_or_init(|| self.init_variants(types))
+    }
+
+    fn init_variants(&self, types: &impl Types) -> HashMap<String, Option<Type>> {
+        let type_params = self.node.get_type_parameters_map();
+        let mut variants = HashMap::new();
+        for variant in self.node.variants.iter() {
+            let name = variant.name.clone();
+            let variant = variant
+                .get_type(types, type_params)
+                .map(|variant_type| variant_type.bind(types, &self.bindings));
+            variants.entry(name).or_insert(variant);
+        }
+        variants
+    }
+
+    // The variants and their inner types, in the order they were declared
+    pub fn get_variants(&self, types: &impl Types) -> Vec<(&String, Option<&Type>)> {
+        let variant_types = self.get_variant_types(types);
+        self.node
+            .variants
+            .iter()
+            .filter_map(|variant| {
+                let variant_type = variant_types.get(&variant.name.value)?;
+                Some((&variant.name.value, variant_type.as_ref()))
+            })
+            .collect()
+    }
+
+    pub fn get_variant(self: &Rc<Self>, types: &impl Types, name: &String) -> Option<Type> {
         let self_type = Type::Enum(self.clone());
-        self.variants
+        self.get_variant_types(types)
             .get(name)
             .map(|variant_type| match variant_type {
+                // The variants of a generic enum infer its type arguments when called
+                Some(inner_type) if self.is_unbound() => Type::Function(FunctionType::generic(
+                    self.type_parameters.clone(),
+                    vec![inner_type.clone()],
+                    self_type,
+                )),
                 Some(inner_type) => {
                     Type::Function(FunctionType::simple(inner_type.clone(), self_type))
                 }
@@ -51,12 +138,15 @@ impl EnumType {
 
     fn init_methods(&self, scope: &Scope) -> HashMap<String, EnumMethod> {
         let scope = scope.global();
+        let type_params = self.node.get_type_parameters_map();
         let mut methods = HashMap::new();
         if let Some(implementation) = self.node.implementation.as_ref() {
-            for method in implementation.get_methods(scope) {
+            for method in implementation.get_methods(scope, type_params) {
                 methods.entry(method.name).or_insert(EnumMethod {
                     public: method.public,
-                    function_type: method.function_type,
+                    is_static: method.is_static,
+                    span: method.span,
+                    function_type: method.function_type.bind(scope, &self.bindings),
                 });
             }
         }
@@ -64,15 +154,30 @@ impl EnumType {
         methods
     }
 
+    pub fn get_interfaces(&self, scope: &Scope) -> Vec<Rc<InterfaceType>> {
+        let type_params = self.node.get_type_parameters_map();
+        self.node
+            .implementation
+            .as_ref()
+            .map_or_else(Vec::new, |implementation| {
+                implementation.get_interfaces(scope, type_params, &self.bindings)
+            })
+    }
+
     pub fn implements(&self, scope: &Scope, interface_type: &Rc<InterfaceType>) -> bool {
+        let type_params = self.node.get_type_parameters_map();
         self.node
             .implementation
             .as_ref()
-            .is_some_and(|implementation| implementation.implements(scope, interface_type))
+            .is_some_and(|implementation| {
+                implementation.implements(scope, interface_type, type_params, &self.bindings)
+            })
     }
 }
 
 pub struct EnumMethod {
     pub public: bool,
+    pub is_static: bool,
+    pub span: TokenSpan,
     pub function_type: Rc<FunctionType>,
 }
@@ -1,15 +1,25 @@
 use std::rc::Rc;
 
-use crate::checker::{Type, TypeParameterBindings, Types};
+use crate::checker::{Type, TypeParameterBindings, TypeParameterList, Types, bind_list};
 
 pub struct FunctionType {
+    pub type_parameters: TypeParameterList,
     pub parameters: Vec<Type>,
     pub return_type: Box<Type>,
 }
 
 impl FunctionType {
     pub fn new(parameters: Vec<Type>, return_type: Type) -> Rc<Self> {
+        Self::generic(TypeParameterList::default(), parameters, return_type)
+    }
+
+    pub fn generic(
+        type_parameters: TypeParameterList,
+        parameters: Vec<Type>,
+        return_type: Type,
+    ) -> Rc<Self> {
         Rc::new(Self {
+            type_parameters,
             parameters,
             return_type: Box::new(return_type),
         })
@@ -19,18 +29,32 @@ impl FunctionType {
         Self::new(vec![input_type], output_type)
     }
 
+    pub fn is_generic(&self) -> bool {
+        !self.type_parameters.is_empty()
+    }
+
     pub fn as_static_method(self: Rc<Self>, self_type: Type) -> Type {
         let mut parameters = self.parameters.clone();
         parameters.insert(0, self_type.clone());
-        Type::Function(Self::new(parameters, *self.return_type.clone()))
+        Type::Function(Self::generic(
+            self.type_parameters.clone(),
+            parameters,
+            *self.return_type.clone(),
+        ))
     }
 
     pub fn bind(&self, types: &impl Types, bindings: &TypeParameterBindings) -> Rc<Self> {
+        Self::generic(
+            self.type_parameters.clone(),
+            bind_list(&self.parameters, types, bindings),
+            self.return_type.bind(types, bindings),
+        )
+    }
+
+    // Substitutes the function's own type parameters, producing a non-generic function
+    pub fn instantiate(&self, types: &impl Types, bindings: &TypeParameterBindings) -> Rc<Self> {
         Self::new(
-            self.parameters
-                .iter()
-                .map(|param| param.bind(types, bindings))
-                .collect(),
+            bind_list(&self.parameters, types, bindings),
             self.return_type.bind(types, bindings),
         )
     }
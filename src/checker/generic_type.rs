@@ -16,19 +16,9 @@ impl GenericType {
         bound_type_params: &NodeVec<TypeNode>,
         bound_types: &[Type],
     ) -> Type {
-        if bound_types.len() != self.type_parameters.len() {
-            types.print_error(
-                bound_type_params.span,
-                "Mismatched type parameters",
-                &format!(
-                    "expected {} types, found {}",
-                    self.type_parameters.len(),
-                    bound_type_params.len()
-                ),
-            );
-        }
-
-        let bindings = self.type_parameters.get_bindings(bound_types);
+        let bindings = self
+            .type_parameters
+            .bind_types(types, bound_type_params, bound_types);
         self.base_type.bind(types, &bindings)
     }
 }
@@ -1,9 +1,84 @@
 use std::{collections::HashMap, rc::Rc};
 
-use crate::checker::FunctionType;
+use crate::{
+    checker::{FunctionType, Type, TypeParameterBindings, TypeParameterList, Types},
+    parser::{NodeVec, TypeNode},
+};
 
 #[derive(Default)]
 pub struct InterfaceType {
     pub name: String,
+    pub type_parameters: TypeParameterList,
+    pub type_arguments: Vec<Type>,
+    bindings: TypeParameterBindings,
     pub methods: HashMap<String, Rc<FunctionType>>,
 }
+
+impl InterfaceType {
+    pub fn new(
+        name: String,
+        type_parameters: TypeParameterList,
+        methods: HashMap<String, Rc<FunctionType>>,
+    ) -> Rc<Self> {
+        let type_arguments = type_parameters
+            .iter()
+            .map(|type_parameter| Type::TypeParameter(type_parameter.clone()))
+            .collect();
+        Rc::new(InterfaceType {
+            name,
+            type_parameters,
+            type_arguments,
+            bindings: TypeParameterBindings::new(),
+            methods,
+        })
+    }
+
+    // Whether this is a generic interface which has not been given any type arguments
+    pub fn is_unbound(&self) -> bool {
+        !self.type_parameters.is_empty() && self.bindings.is_empty()
+    }
+
+    pub fn bind(self: &Rc<Self>, types: &impl Types, bindings: &TypeParameterBindings) -> Rc<Self> {
+        if self.type_parameters.is_empty() {
+            return self.clone();
+        }
+
+        let type_arguments = self
+            .type_arguments
+            .iter()
+            .map(|type_argument| type_argument.bind(types, bindings))
+            .collect::<Vec<_>>();
+        let methods = self.bind_methods(types, bindings);
+        let bindings = self.type_parameters.get_bindings(&type_arguments);
+        Rc::new(InterfaceType {
+            name: self.name.clone(),
+            type_parameters: self.type_parameters.clone(),
+            type_arguments,
+            bindings,
+            methods,
+        })
+    }
+
+    pub fn bind_arguments(
+        self: &Rc<Self>,
+        types: &impl Types,
+        bound_type_params: &NodeVec<TypeNode>,
+        bound_types: &[Type],
+    ) -> Rc<Self> {
+        let bindings = self
+            .type_parameters
+            .bind_types(types, bound_type_params, bound_types);
+        self.bind(types, &bindings)
+    }
+
+    fn bind_methods(
+        &self,
+        types: &impl Types,
+        bindings: &TypeParameterBindings,
+    ) -> HashMap<String, Rc<FunctionType>> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.clone(), method.bind(types, bindings)))
+            .collect()
+    }
+}
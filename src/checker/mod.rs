@@ -1,25 +1,39 @@
+mod assignments;
 mod enum_type;
 mod function_type;
 mod generic_type;
 mod interface_type;
+mod module_exports;
 mod module_scope;
+mod operator_interface;
+mod prelude;
+mod resolutions;
 mod scope;
 mod struct_type;
+mod tuple_type;
 mod type_fmt;
+mod type_inference;
 mod type_map;
 mod type_parameter;
 mod type_parameter_list;
 mod types;
 mod types_trait;
 
+pub use assignments::*;
 pub use enum_type::*;
 pub use function_type::*;
 pub use generic_type::*;
 pub use interface_type::*;
+pub use module_exports::*;
 pub use module_scope::*;
+pub use operator_interface::*;
+pub use prelude::*;
+pub use resolutions::*;
 pub use scope::*;
 pub use struct_type::*;
+pub use tuple_type::*;
 pub use type_fmt::*;
+pub use type_inference::*;
 pub use type_map::*;
 pub use type_parameter::*;
 pub use type_parameter_list::*;
@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+use crate::checker::{Type, TypeEntry};
+
+// The public types and values of a checked module, which other modules can import
+#[derive(Default)]
+pub struct ModuleExports {
+    pub types: HashMap<String, TypeEntry>,
+    pub values: HashMap<String, Type>,
+}
@@ -1,9 +1,13 @@
 use std::{collections::HashMap, rc::Rc};
 
 use crate::{
-    checker::{Scope, Type, TypeEntry, TypeMap, Types, new_type_id},
-    lexer::SourceCode,
-    parser::{EnumNode, InterfaceNode, NameNode, StructNode, TokenSpan, TypeAliasNode},
+    checker::{
+        ModuleExports, OperatorInterface, Scope, Type, TypeEntry, TypeMap, Types, new_type_id,
+    },
+    diagnostics::DiagnosticSink,
+    parser::{
+        EnumNode, InterfaceNode, NameNode, Node, StructNode, TypeAliasNode, TypeParameterListNode,
+    },
 };
 
 pub enum ModuleTypeNode {
@@ -22,6 +26,15 @@ impl ModuleTypeNode {
             ModuleTypeNode::TypeAlias(node) => node.get_type(scope).clone(),
         }
     }
+
+    fn get_type_parameters(&self) -> Option<&Node<TypeParameterListNode>> {
+        match self {
+            ModuleTypeNode::Enum(node) => node.type_parameters.as_ref(),
+            ModuleTypeNode::Interface(node) => node.type_parameters.as_ref(),
+            ModuleTypeNode::Struct(node) => node.type_parameters.as_ref(),
+            ModuleTypeNode::TypeAlias(node) => node.type_parameters.as_ref(),
+        }
+    }
 }
 
 struct ModuleTypeEntry {
@@ -39,30 +52,70 @@ impl ModuleTypeEntry {
 }
 
 pub struct ModuleScope {
-    source: Rc<SourceCode>,
+    diagnostics: Rc<DiagnosticSink>,
     lookup: HashMap<String, ModuleTypeEntry>,
+    imports: HashMap<String, TypeEntry>,
+    builtins: HashMap<String, TypeEntry>,
+    // The types of the prelude, which is `None` while checking the prelude itself
+    prelude: Option<HashMap<String, TypeEntry>>,
 }
 
 impl ModuleScope {
-    pub fn new(source: Rc<SourceCode>) -> Self {
+    pub fn new(diagnostics: Rc<DiagnosticSink>) -> Self {
         ModuleScope {
-            source,
+            diagnostics,
             lookup: HashMap::new(),
+            imports: HashMap::new(),
+            builtins: OperatorInterface::get_type_entries(),
+            prelude: None,
         }
     }
 
+    // Makes the types of the prelude available, while still letting the module shadow them
+    pub fn add_prelude(&mut self, prelude: &ModuleExports) {
+        self.builtins.extend(prelude.types.clone());
+        self.prelude = Some(prelude.types.clone());
+    }
+
     pub fn to_scope(self) -> Box<Scope> {
-        let types = self
-            .lookup
-            .iter()
-            .map(|(key, value)| (key.clone(), value.to_type_entry(&self)))
-            .collect();
-        Box::new(Scope::new(self.source.clone(), TypeMap::from(types)))
+        let mut types = self.builtins.clone();
+        types.extend(self.imports.clone());
+        types.extend(
+            self.lookup
+                .iter()
+                .map(|(key, value)| (key.clone(), value.to_type_entry(&self))),
+        );
+        let mut scope = Scope::new(self.diagnostics.clone(), TypeMap::from(types));
+        scope.prelude = self.prelude.clone();
+        Box::new(scope)
+    }
+
+    // Adds a type exported by another module, returning false if the name is already imported
+    pub fn import(&mut self, name: &str, entry: &TypeEntry) -> bool {
+        match self.imports.get(name) {
+            Some(existing) => existing.id == entry.id,
+            None => {
+                self.imports.insert(name.to_owned(), entry.clone());
+                true
+            }
+        }
+    }
+
+    pub fn get_type_entry(&self, name: &str) -> Option<TypeEntry> {
+        self.lookup.get(name).map(|entry| entry.to_type_entry(self))
     }
 
     pub fn declare(&mut self, name: &NameNode, node: ModuleTypeNode) {
+        if self.imports.contains_key(&name.value) {
+            self.diagnostics.error(
+                name.span,
+                "Duplicate type name",
+                "a type with this name is already imported",
+            );
+            return;
+        }
         if self.lookup.contains_key(&name.value) {
-            self.source.print_error(
+            self.diagnostics.error(
                 name.span,
                 "Duplicate type name",
                 "a type already exists with this name",
@@ -83,16 +136,36 @@ impl ModuleScope {
         for entry in self.lookup.values() {
             entry.node.get_type(self);
         }
+
+        for entry in self.lookup.values() {
+            if let Some(type_parameters) = entry.node.get_type_parameters() {
+                type_parameters.resolve_bounds(self);
+            }
+        }
     }
 }
 
 impl Types for ModuleScope {
     fn get_type_id(&self, name: &str) -> Option<usize> {
-        self.lookup.get(name).map(|entry| entry.id)
+        match self.lookup.get(name) {
+            Some(entry) => Some(entry.id),
+            None => self
+                .imports
+                .get(name)
+                .or_else(|| self.builtins.get(name))
+                .map(|entry| entry.id),
+        }
     }
 
     fn get_type(&self, name: &str) -> Option<Type> {
-        self.lookup.get(name).map(|entry| entry.node.get_type(self))
+        match self.lookup.get(name) {
+            Some(entry) => Some(entry.node.get_type(self)),
+            None => self
+                .imports
+                .get(name)
+                .or_else(|| self.builtins.get(name))
+                .map(|entry| entry.value.clone()),
+        }
     }
 
     fn get_return_type(&self) -> Option<Type> {
@@ -103,7 +176,14 @@ impl Types for ModuleScope {
         None
     }
 
-    fn print_error(&self, span: TokenSpan, message: &str, inline_message: &str) {
-        self.source.print_error(span, message, inline_message);
+    fn get_prelude_type(&self, name: &str) -> Option<Type> {
+        match &self.prelude {
+            Some(prelude) => prelude.get(name).map(|entry| entry.value.clone()),
+            None => self.get_type(name),
+        }
+    }
+
+    fn get_diagnostics(&self) -> &DiagnosticSink {
+        &self.diagnostics
     }
 }
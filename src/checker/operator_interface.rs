@@ -0,0 +1,212 @@
+use std::{collections::HashMap, fmt::Display, rc::Rc};
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::{
+    checker::{
+        FunctionType, InterfaceType, Scope, StructMemberType, Type, TypeEntry, TypeParameter,
+        TypeParameterList,
+    },
+    parser::{BinaryOperator, PrefixOperator, PrimitiveType, TokenSpan},
+};
+
+pub const CALL_OPERATOR: &str = "operator()";
+
+// The built-in interfaces which user defined types implement to overload operators
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+pub enum OperatorInterface {
+    Add,      // Add[Operand, Output] { operator_add(operand: Operand): Output; }
+    Subtract, // Subtract[Operand, Output] { operator_subtract(operand: Operand): Output; }
+    Multiply, // Multiply[Operand, Output] { operator_multiply(operand: Operand): Output; }
+    Divide,   // Divide[Operand, Output] { operator_divide(operand: Operand): Output; }
+    Mod,      // Mod[Operand, Output] { operator_mod(operand: Operand): Output; }
+    Equal,    // Equal[Operand] { operator_equal(operand: Operand): bool; }
+    Compare,  // Compare[Operand] { operator_compare(operand: Operand): int; }
+    Negate,   // Negate[Output] { operator_negate(): Output; }
+    Not,      // Not[Output] { operator_not(): Output; }
+    Call,     // Call[Argument, Output] { operator()(argument: Argument): Output; }
+}
+
+impl OperatorInterface {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Add => "Add",
+            Self::Subtract => "Subtract",
+            Self::Multiply => "Multiply",
+            Self::Divide => "Divide",
+            Self::Mod => "Mod",
+            Self::Equal => "Equal",
+            Self::Compare => "Compare",
+            Self::Negate => "Negate",
+            Self::Not => "Not",
+            Self::Call => "Call",
+        }
+    }
+
+    pub fn method(self) -> &'static str {
+        match self {
+            Self::Add => "operator_add",
+            Self::Subtract => "operator_subtract",
+            Self::Multiply => "operator_multiply",
+            Self::Divide => "operator_divide",
+            Self::Mod => "operator_mod",
+            Self::Equal => "operator_equal",
+            Self::Compare => "operator_compare",
+            Self::Negate => "operator_negate",
+            Self::Not => "operator_not",
+            Self::Call => CALL_OPERATOR,
+        }
+    }
+
+    // Compound assignments use the interface of the operator they combine with assignment
+    pub fn from_binary(operator: BinaryOperator) -> Option<Self> {
+        use BinaryOperator as O;
+        match operator {
+            O::Add | O::AddAssign => Some(Self::Add),
+            O::Subtract | O::SubtractAssign => Some(Self::Subtract),
+            O::Multiply | O::MultiplyAssign => Some(Self::Multiply),
+            O::Divide | O::DivideAssign => Some(Self::Divide),
+            O::Mod | O::ModAssign => Some(Self::Mod),
+            O::Equal | O::NotEqual => Some(Self::Equal),
+            O::LessThan | O::LessThanOrEqual | O::GreaterThan | O::GreaterThanOrEqual => {
+                Some(Self::Compare)
+            }
+            O::Assign
+            | O::Range
+            | O::RangeInclusive
+            | O::FunctionApplication
+            | O::Comma
+            | O::LogicalAnd
+            | O::LogicalOr => None,
+        }
+    }
+
+    pub fn from_prefix(operator: PrefixOperator) -> Self {
+        match operator {
+            PrefixOperator::LogicalNot => Self::Not,
+            PrefixOperator::Negative => Self::Negate,
+        }
+    }
+
+    fn get_type(self) -> Rc<InterfaceType> {
+        let parameter = |name: &str| Rc::new(TypeParameter::new(name.to_owned()));
+        let (type_parameters, parameters, return_type) = match self {
+            Self::Add | Self::Subtract | Self::Multiply | Self::Divide | Self::Mod => {
+                let (operand, output) = (parameter("Operand"), parameter("Output"));
+                let parameters = vec![Type::TypeParameter(operand.clone())];
+                (
+                    vec![operand, output.clone()],
+                    parameters,
+                    Type::TypeParameter(output),
+                )
+            }
+            Self::Equal | Self::Compare => {
+                let operand = parameter("Operand");
+                let parameters = vec![Type::TypeParameter(operand.clone())];
+                let return_type = if self == Self::Equal {
+                    PrimitiveType::Bool
+                } else {
+                    PrimitiveType::Int
+                };
+                (vec![operand], parameters, Type::Primitive(return_type))
+            }
+            Self::Negate | Self::Not => {
+                let output = parameter("Output");
+                (vec![output.clone()], vec![], Type::TypeParameter(output))
+            }
+            Self::Call => {
+                let (argument, output) = (parameter("Argument"), parameter("Output"));
+                let parameters = vec![Type::TypeParameter(argument.clone())];
+                (
+                    vec![argument, output.clone()],
+                    parameters,
+                    Type::TypeParameter(output),
+                )
+            }
+        };
+
+        let methods = HashMap::from([(
+            self.method().to_owned(),
+            FunctionType::new(parameters, return_type),
+        )]);
+        InterfaceType::new(
+            self.name().to_owned(),
+            TypeParameterList::new(type_parameters),
+            methods,
+        )
+    }
+
+    // The interfaces in scope in every module, unless shadowed by a type of the same name
+    pub fn get_type_entries() -> HashMap<String, TypeEntry> {
+        Self::iter()
+            .map(|operator| {
+                let entry = TypeEntry::new(Type::Interface(operator.get_type()));
+                (operator.name().to_owned(), entry)
+            })
+            .collect()
+    }
+
+    // Finds the method overloading this operator, reporting an error if the type has none
+    pub fn expect_method(
+        self,
+        scope: &Scope,
+        operand_type: &Type,
+        span: TokenSpan,
+        operator: impl Display,
+    ) -> Option<Rc<FunctionType>> {
+        let method = self.get_method(scope, operand_type);
+        if method.is_none() && !operand_type.is_error() {
+            scope.diagnostics.error(
+                span,
+                &format!("Operator `{operator}` is not implemented for type `{operand_type}`"),
+                &format!("type `{operand_type}` does not implement `{}`", self.name()),
+            );
+        }
+        method
+    }
+
+    // Finds the signature of the method overloading this operator for a type, if it has one
+    pub fn get_method(self, scope: &Scope, operand_type: &Type) -> Option<Rc<FunctionType>> {
+        let interfaces = match operand_type {
+            Type::Enum(enum_type) => enum_type.get_interfaces(scope),
+            Type::Struct(struct_type) => struct_type.get_interfaces(scope),
+            Type::TypeParameter(type_parameter) => type_parameter.get_bounds().to_vec(),
+            _ => return None,
+        };
+
+        let method = interfaces
+            .iter()
+            .filter(|interface| interface.name == self.name())
+            .find_map(|interface| interface.methods.get(self.method()).cloned());
+        if method.is_some() || self != Self::Call {
+            return method;
+        }
+
+        // The call operator may also be declared directly, to take any number of arguments
+        match operand_type {
+            Type::Enum(enum_type) => enum_type
+                .get_method(scope, &CALL_OPERATOR.to_owned())
+                .filter(|method| !method.is_static)
+                .map(|method| method.function_type.clone()),
+            Type::Struct(struct_type) => {
+                match struct_type.get_member(scope, &CALL_OPERATOR.to_owned()) {
+                    Some(member) => match &member.member_type {
+                        StructMemberType::Method(function_type) => Some(function_type.clone()),
+                        _ => None,
+                    },
+                    None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+// Whether operators on the type are resolved through the operator interfaces
+pub fn is_overloadable(operand_type: &Type) -> bool {
+    matches!(
+        operand_type,
+        Type::Enum(_) | Type::Struct(_) | Type::TypeParameter(_)
+    )
+}
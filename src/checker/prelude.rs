@@ -0,0 +1,54 @@
+use crate::checker::{Scope, Type, Types};
+
+pub const OPTION_TYPE: &str = "Option";
+pub const SOME_VARIANT: &str = "Some";
+pub const NONE_VARIANT: &str = "None";
+pub const RANGE_TYPE: &str = "Range";
+pub const ITERATOR_TYPE: &str = "Iterator";
+pub const NEXT_METHOD: &str = "next";
+
+// The built-in `Option` enum given an inner type, which is written as `T?`
+pub fn make_optional(types: &impl Types, inner_type: Type) -> Type {
+    match types.get_prelude_type(OPTION_TYPE) {
+        Some(Type::Enum(option_type)) => {
+            let bindings = option_type.type_parameters.get_bindings(&[inner_type]);
+            Type::Enum(option_type.bind(types, &bindings))
+        }
+        _ => Type::Error,
+    }
+}
+
+// The inner type of a built-in `Option`, or `None` if the type is not optional
+pub fn unwrap_optional(types: &impl Types, optional_type: &Type) -> Option<Type> {
+    let (Type::Enum(enum_type), Some(Type::Enum(option_type))) =
+        (optional_type, types.get_prelude_type(OPTION_TYPE))
+    else {
+        return None;
+    };
+
+    if enum_type.name_span().start_index != option_type.name_span().start_index {
+        return None;
+    }
+    enum_type
+        .get_variant_types(types)
+        .get(SOME_VARIANT)
+        .cloned()
+        .flatten()
+}
+
+// The type of the elements a `for` loop produces from a value, or `None` if it is not iterable
+pub fn get_element_type(scope: &Scope, iterable_type: &Type) -> Option<Type> {
+    let interfaces = match iterable_type {
+        Type::Array(element_type) => return Some(element_type.as_ref().clone()),
+        Type::Enum(enum_type) => enum_type.get_interfaces(scope),
+        Type::Struct(struct_type) => struct_type.get_interfaces(scope),
+        Type::TypeParameter(type_parameter) => type_parameter.get_bounds().to_vec(),
+        _ => return None,
+    };
+
+    interfaces
+        .iter()
+        .filter(|interface| interface.name == ITERATOR_TYPE)
+        .find_map(|interface| interface.methods.get(NEXT_METHOD))
+        .and_then(|next| unwrap_optional(scope, &next.return_type))
+}
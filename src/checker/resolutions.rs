@@ -0,0 +1,85 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    checker::Type,
+    parser::{PrimitiveType, TokenSpan},
+};
+
+// Types the checker could only determine with inference (e.g. enum variants resolved by their
+// expected type), keyed by the first token of the resolved node so later passes can reuse them.
+#[derive(Default)]
+pub struct Resolutions {
+    lookup: RefCell<HashMap<usize, Type>>,
+    // Optional contexts in which `?` short circuits, whose values are wrapped in `Some`
+    short_circuits: RefCell<HashSet<(usize, usize)>>,
+    // The positions of tuple elements accessed by name or index, as tuple values are unnamed
+    tuple_indices: RefCell<HashMap<usize, usize>>,
+    // Calls given fewer arguments than their function's parameters
+    partial_applications: RefCell<HashSet<(usize, usize)>>,
+    // The integer types of arithmetic operators, whose results must fit within their range
+    integer_types: RefCell<HashMap<usize, PrimitiveType>>,
+}
+
+impl Resolutions {
+    pub fn record(&self, span: TokenSpan, resolved_type: Type) {
+        self.lookup
+            .borrow_mut()
+            .insert(span.start_index, resolved_type);
+    }
+
+    pub fn get(&self, span: TokenSpan) -> Option<Type> {
+        self.lookup.borrow().get(&span.start_index).cloned()
+    }
+
+    pub fn record_short_circuit(&self, span: TokenSpan) {
+        self.short_circuits
+            .borrow_mut()
+            .insert((span.start_index, span.end_index));
+    }
+
+    pub fn record_tuple_index(&self, span: TokenSpan, index: usize) {
+        self.tuple_indices
+            .borrow_mut()
+            .insert(span.start_index, index);
+    }
+
+    pub fn get_tuple_index(&self, span: TokenSpan) -> Option<usize> {
+        self.tuple_indices.borrow().get(&span.start_index).copied()
+    }
+
+    pub fn record_partial_application(&self, span: TokenSpan) {
+        self.partial_applications
+            .borrow_mut()
+            .insert((span.start_index, span.end_index));
+    }
+
+    pub fn is_partial_application(&self, span: TokenSpan) -> bool {
+        self.partial_applications
+            .borrow()
+            .contains(&(span.start_index, span.end_index))
+    }
+
+    pub fn record_integer_type(&self, span: TokenSpan, integer_type: PrimitiveType) {
+        self.integer_types
+            .borrow_mut()
+            .insert(span.start_index, integer_type);
+    }
+
+    // Operators without a recorded type are applied to `int` values
+    pub fn get_integer_type(&self, span: TokenSpan) -> PrimitiveType {
+        self.integer_types
+            .borrow()
+            .get(&span.start_index)
+            .copied()
+            .unwrap_or(PrimitiveType::Int)
+    }
+
+    pub fn is_short_circuit(&self, span: TokenSpan) -> bool {
+        self.short_circuits
+            .borrow()
+            .contains(&(span.start_index, span.end_index))
+    }
+}
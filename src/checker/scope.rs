@@ -4,8 +4,12 @@ use std::{
 };
 
 use crate::{
-    checker::{EnumType, StructType, Type, TypeEntry, TypeMap, Types},
-    lexer::{EnumToken, Keyword, SourceCode},
+    checker::{
+        Assignment, Assignments, EnumType, LoopAssignment, LoopState, Resolutions, StructType,
+        Type, TypeEntry, TypeMap, Types,
+    },
+    diagnostics::DiagnosticSink,
+    lexer::{EnumToken, Keyword},
     parser::TokenSpan,
 };
 
@@ -17,30 +21,51 @@ pub enum ScopeType {
     Block,
     MatchCase,
     Loop,
+    // An expression within which `?` can short circuit, such as a `let` initializer
+    ShortCircuit,
+    StaticMethod,
     Type,
     Enum(Rc<EnumType>),
     Struct(Rc<StructType>),
 }
 
+#[derive(Clone)]
+pub struct ValueEntry {
+    pub value: Type,
+    pub mutable: bool,
+    pub assignment: Assignment,
+    // Where the value is declared, if it is declared by a name in the source
+    pub span: Option<TokenSpan>,
+}
+
 pub struct Scope {
-    // TODO consider making this private and just using scope.print_error() directly
-    pub source: Rc<SourceCode>,
+    pub diagnostics: Rc<DiagnosticSink>,
+    pub resolutions: Rc<Resolutions>,
     scope_type: ScopeType,
     parent: Option<Box<Scope>>,
-    values: HashMap<String, Type>,
+    values: HashMap<String, ValueEntry>,
     types: TypeMap,
     return_type: Option<Type>,
+    // Set on the global scope, since the module may shadow the types of the prelude
+    pub prelude: Option<HashMap<String, TypeEntry>>,
+    short_circuited: bool,
+    // Set on loop scopes, from the `break` and `continue` statements and assignments within them
+    loop_state: LoopState,
 }
 
 impl Scope {
-    pub fn new(source: Rc<SourceCode>, types: TypeMap) -> Self {
+    pub fn new(diagnostics: Rc<DiagnosticSink>, types: TypeMap) -> Self {
         Self {
-            source,
+            diagnostics,
+            resolutions: Rc::new(Resolutions::default()),
             scope_type: ScopeType::Global,
             parent: None,
             values: HashMap::new(),
             types,
             return_type: None,
+            prelude: None,
+            short_circuited: false,
+            loop_state: LoopState::default(),
         }
     }
 
@@ -58,12 +83,13 @@ impl Scope {
         scope_type: ScopeType,
         handler: impl FnOnce(Box<Scope>) -> (Box<Scope>, T),
     ) -> (Box<Scope>, T) {
-        let source = self.source.clone();
+        let diagnostics = self.diagnostics.clone();
         let types = TypeMap::new();
         let mut scope = Box::new(Self {
             scope_type: scope_type.clone(),
+            resolutions: self.resolutions.clone(),
             parent: Some(self),
-            ..Self::new(source, types)
+            ..Self::new(diagnostics, types)
         });
         if let ScopeType::Struct(self_type) = &scope_type {
             scope.add_type_and_value(Keyword::SelfType.as_str(), &Type::Struct(self_type.clone()));
@@ -80,13 +106,14 @@ impl Scope {
         return_type: &Type,
         handler: impl FnOnce(Box<Scope>) -> Box<Scope>,
     ) -> Box<Scope> {
-        let source = self.source.clone();
+        let diagnostics = self.diagnostics.clone();
         let types = TypeMap::new();
         let mut scope = Box::new(Self {
             scope_type: ScopeType::Function,
+            resolutions: self.resolutions.clone(),
             parent: Some(self),
             return_type: Some(return_type.clone()),
-            ..Self::new(source, types)
+            ..Self::new(diagnostics, types)
         });
         scope.add_type_and_value(Keyword::Result.as_str(), return_type);
         handler(scope).parent()
@@ -120,36 +147,211 @@ impl Scope {
         }
     }
 
+    // Records a use of `?` in the closest optional context, returning false if there is none
+    pub fn short_circuit(&mut self) -> bool {
+        match self.scope_type {
+            ScopeType::ShortCircuit => {
+                self.short_circuited = true;
+                true
+            }
+            // `?` cannot leave the function which contains it
+            ScopeType::Function | ScopeType::Closure => false,
+            _ => self
+                .parent
+                .as_mut()
+                .is_some_and(|parent| parent.short_circuit()),
+        }
+    }
+
+    pub fn is_short_circuited(&self) -> bool {
+        self.short_circuited
+    }
+
+    // Records the assignments at a `break` or `continue` of the closest loop
+    pub fn record_loop_jump(&mut self, is_break: bool) {
+        let assignments = self.get_assignments();
+        if let Some(loop_state) = self.get_loop_state(None) {
+            match is_break {
+                true => loop_state.breaks.push(assignments),
+                false => loop_state.continues.push(assignments),
+            }
+        }
+    }
+
+    // Records the assignment of an unassigned immutable value in the closest loop within the
+    // scope declaring it, if there is one
+    pub fn record_loop_assignment(&mut self, assignment: LoopAssignment) {
+        if let Some(loop_state) = self.get_loop_state(Some(assignment.depth)) {
+            loop_state.assignments.push(assignment);
+        }
+    }
+
+    // The state of the closest loop, which must be nested deeper than the given depth
+    fn get_loop_state(&mut self, min_depth: Option<usize>) -> Option<&mut LoopState> {
+        if min_depth.is_some_and(|min_depth| self.depth() <= min_depth) {
+            return None;
+        }
+        match self.scope_type {
+            ScopeType::Loop => Some(&mut self.loop_state),
+            ScopeType::Function | ScopeType::Closure => None,
+            _ => self
+                .parent
+                .as_mut()
+                .and_then(|parent| parent.get_loop_state(min_depth)),
+        }
+    }
+
+    pub fn take_loop_state(&mut self) -> LoopState {
+        std::mem::take(&mut self.loop_state)
+    }
+
+    // The depth of the scope declaring a value, which identifies it within `Assignments`
+    pub fn get_value_depth(&self, name: &String) -> Option<usize> {
+        if self.values.contains_key(name) {
+            Some(self.depth())
+        } else {
+            self.parent
+                .as_ref()
+                .and_then(|parent| parent.get_value_depth(name))
+        }
+    }
+
     fn parent(self) -> Box<Scope> {
         self.parent.unwrap()
     }
 
     pub fn add_value(&mut self, name: &str, value: Type) {
-        self.values.insert(name.to_owned(), value);
+        let entry = ValueEntry {
+            value,
+            mutable: false,
+            assignment: Assignment::Assigned,
+            span: None,
+        };
+        self.values.insert(name.to_owned(), entry);
+    }
+
+    pub fn declare_value(&mut self, name: &str, value: Type, mutable: bool, span: TokenSpan) {
+        self.insert_declared_value(name, value, mutable, Assignment::Assigned, span);
+    }
+
+    // Declares a value without an initializer, which must be assigned before it is used
+    pub fn declare_unassigned_value(
+        &mut self,
+        name: &str,
+        value: Type,
+        mutable: bool,
+        span: TokenSpan,
+    ) {
+        self.insert_declared_value(name, value, mutable, Assignment::Unassigned, span);
+    }
+
+    fn insert_declared_value(
+        &mut self,
+        name: &str,
+        value: Type,
+        mutable: bool,
+        assignment: Assignment,
+        span: TokenSpan,
+    ) {
+        let entry = ValueEntry {
+            value,
+            mutable,
+            assignment,
+            span: Some(span),
+        };
+        self.values.insert(name.to_owned(), entry);
     }
 
     pub fn add_value_or(&mut self, name: &str, value: Type, if_present: impl Fn(&Scope)) {
         let entry = self.values.entry(name.to_owned());
         if let Entry::Vacant(v) = entry {
-            v.insert(value);
+            v.insert(ValueEntry {
+                value,
+                mutable: false,
+                assignment: Assignment::Assigned,
+                span: None,
+            });
         } else {
             if_present(self);
         }
     }
 
     pub fn get_value(&self, name: &String) -> Option<Type> {
-        self.get_local_value(name)
-            .or_else(|| self.get_parent_value(name))
+        self.get_value_entry(name).map(|entry| entry.value.clone())
     }
 
     pub fn get_local_value(&self, name: &String) -> Option<Type> {
-        self.values.get(name).cloned()
+        self.get_local_value_entry(name)
+            .map(|entry| entry.value.clone())
+    }
+
+    pub fn get_value_entry(&self, name: &String) -> Option<&ValueEntry> {
+        self.get_local_value_entry(name).or_else(|| {
+            self.parent
+                .as_ref()
+                .and_then(|parent| parent.get_value_entry(name))
+        })
+    }
+
+    pub fn get_local_value_entry(&self, name: &String) -> Option<&ValueEntry> {
+        self.values.get(name)
+    }
+
+    // Records that a value is definitely assigned from this point on
+    pub fn assign_value(&mut self, name: &String) {
+        match self.values.get_mut(name) {
+            Some(entry) => entry.assignment = Assignment::Assigned,
+            None => {
+                if let Some(parent) = self.parent.as_mut() {
+                    parent.assign_value(name);
+                }
+            }
+        }
+    }
+
+    // Whether a value is declared outside the closest enclosing scope matching the predicate
+    pub fn is_declared_outside(
+        &self,
+        name: &String,
+        predicate: impl Fn(&ScopeType) -> bool,
+    ) -> bool {
+        if self.values.contains_key(name) {
+            false
+        } else if predicate(&self.scope_type) {
+            self.parent
+                .as_ref()
+                .is_some_and(|parent| parent.get_value_entry(name).is_some())
+        } else {
+            self.parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_declared_outside(name, predicate))
+        }
     }
 
-    fn get_parent_value(&self, name: &String) -> Option<Type> {
-        self.parent
+    pub fn get_assignments(&self) -> Assignments {
+        let mut assignments = self
+            .parent
             .as_ref()
-            .and_then(|parent| parent.get_value(name))
+            .map_or_else(Assignments::default, |parent| parent.get_assignments());
+        let depth = self.depth();
+        for (name, entry) in &self.values {
+            assignments.insert(depth, name, entry.assignment);
+        }
+        assignments
+    }
+
+    pub fn set_assignments(&mut self, assignments: &Assignments) {
+        let depth = self.depth();
+        for (name, entry) in self.values.iter_mut() {
+            entry.assignment = assignments.get(depth, name);
+        }
+        if let Some(parent) = self.parent.as_mut() {
+            parent.set_assignments(assignments);
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.parent.as_ref().map_or(0, |parent| parent.depth() + 1)
     }
 
     pub fn get_type_entry(&self, name: &str) -> Option<TypeEntry> {
@@ -199,7 +401,15 @@ impl Types for Scope {
         }
     }
 
-    fn print_error(&self, span: TokenSpan, message: &str, inline_message: &str) {
-        self.source.print_error(span, message, inline_message);
+    fn get_prelude_type(&self, name: &str) -> Option<Type> {
+        let global = self.global();
+        match &global.prelude {
+            Some(prelude) => prelude.get(name).map(|entry| entry.value.clone()),
+            None => global.get_type(name),
+        }
+    }
+
+    fn get_diagnostics(&self) -> &DiagnosticSink {
+        &self.diagnostics
     }
 }
@@ -1,32 +1,119 @@
-use std::{cell::OnceCell, collections::HashMap, rc::Rc};
+use std::{
+    cell::OnceCell,
+    collections::{HashMap, hash_map::Entry},
+    rc::Rc,
+};
 
 use crate::{
-    checker::{FunctionType, InterfaceType, Scope, Type, Types},
-    parser::StructNode,
+    checker::{
+        FunctionType, InterfaceType, Scope, Type, TypeParameterBindings, TypeParameterList, Types,
+    },
+    parser::{Node, NodeVec, StructFieldNode, StructNode, TokenSpan, TypeNode},
 };
 
 pub struct StructType {
     node: Rc<StructNode>,
+    pub type_parameters: TypeParameterList,
+    pub type_arguments: Vec<Type>,
+    bindings: TypeParameterBindings,
     constructor: OnceCell<Rc<FunctionType>>,
     members: OnceCell<HashMap<String, StructMember>>,
 }
 
 impl StructType {
-    pub fn from(node: Rc<StructNode>, types: &impl Types) -> Rc<StructType> {
-        let struct_type = Rc::new(StructType {
+    pub fn from(node: Rc<StructNode>) -> Rc<StructType> {
+        let type_parameters = node.get_type_parameters();
+        Self::with_bindings(node, type_parameters, TypeParameterBindings::new())
+    }
+
+    fn with_bindings(
+        node: Rc<StructNode>,
+        type_parameters: TypeParameterList,
+        bindings: TypeParameterBindings,
+    ) -> Rc<StructType> {
+        let type_arguments = type_parameters
+            .iter()
+            .map(|type_parameter| type_parameter.bind(&bindings))
+            .collect();
+        Rc::new(StructType {
             node,
+            type_parameters,
+            type_arguments,
+            bindings,
             constructor: OnceCell::new(),
             members: OnceCell::new(),
-        });
-        // Immediately initialize constructor using the module level types
-        struct_type.get_constructor(types);
-        struct_type
+        })
     }
 
     pub fn name(&self) -> &String {
         &self.node.name
     }
 
+    // The span of the name in the type's declaration, which identifies it across modules
+    pub fn name_span(&self) -> TokenSpan {
+        self.node.name.span
+    }
+
+    // Finds the first field which is not `pub`, making the constructor private to the struct
+    pub fn get_private_field_node(&self) -> Option<&Node<StructFieldNode>> {
+        self.node.fields.iter().find(|field| !field.public)
+    }
+
+    // The position of a field, which is also the position of its constructor parameter
+    pub fn get_field_index(&self, name: &str) -> Option<usize> {
+        self.node
+            .fields
+            .iter()
+            .position(|field| field.name.value == name)
+    }
+
+    pub fn get_field_names(&self) -> Vec<&String> {
+        self.node
+            .fields
+            .iter()
+            .map(|field| &field.name.value)
+            .collect()
+    }
+
+    pub fn get_field_node(&self, name: &str) -> Option<&StructFieldNode> {
+        self.node
+            .fields
+            .iter()
+            .find(|field| field.name.value == name)
+            .map(|field| &field.value)
+    }
+
+    // Whether this is a generic struct which has not been given any type arguments
+    pub fn is_unbound(&self) -> bool {
+        !self.type_parameters.is_empty() && self.bindings.is_empty()
+    }
+
+    pub fn bind(self: &Rc<Self>, types: &impl Types, bindings: &TypeParameterBindings) -> Rc<Self> {
+        if self.type_parameters.is_empty() {
+            return self.clone();
+        }
+
+        let type_arguments = self
+            .type_arguments
+            .iter()
+            .map(|type_argument| type_argument.bind(types, bindings))
+            .collect::<Vec<_>>();
+        let bindings = self.type_parameters.get_bindings(&type_arguments);
+        Self::with_bindings(self.node.clone(), self.type_parameters.clone(), bindings)
+    }
+
+    pub fn bind_arguments(
+        &self,
+        types: &impl Types,
+        bound_type_params: &NodeVec<TypeNode>,
+        bound_types: &[Type],
+    ) -> Rc<Self> {
+        let bindings = self
+            .type_parameters
+            .bind_types(types, bound_type_params, bound_types);
+        Self::with_bindings(self.node.clone(), self.type_parameters.clone(), bindings)
+    }
+
     pub fn get_constructor(self: &Rc<Self>, types: &impl Types) -> Rc<FunctionType> {
         self.constructor
             .get_or_init(|| self.init_constructor(types))
@@ -34,37 +121,96 @@ impl StructType {
     }
 
     fn init_constructor(self: &Rc<Self>, types: &impl Types) -> Rc<FunctionType> {
+        let type_params = self.node.get_type_parameters_map();
         let parameters = self
             .node
             .fields
             .iter()
-            .map(|field| field.get_type(types).clone())
+            .map(|field| {
+                field
+                    .get_type(types, type_params)
+                    .bind(types, &self.bindings)
+            })
             .collect();
         let return_type = Type::Struct(self.clone());
 
+        // The constructor of a generic struct infers its type arguments when called
+        if self.is_unbound() {
+            return FunctionType::generic(self.type_parameters.clone(), parameters, return_type);
+        }
+
         FunctionType::new(parameters, return_type)
     }
 
     pub fn get_member(&self, scope: &Scope, name: &String) -> Option<&StructMember> {
-        self.members
-            .get_or_init(|| self.init_members(scope))
-            .get(name)
+        self.get_members(scope).get(name)
     }
 
     fn init_members(&self, scope: &Scope) -> HashMap<String, StructMember> {
         let scope = scope.global();
+        let mut members = self.get_own_members(scope);
+        for (name, member) in self.get_promoted_members(scope) {
+            members.entry(name).or_insert(member);
+        }
+        members
+    }
+
+    pub fn get_promoted_members(&self, scope: &Scope) -> HashMap<String, StructMember> {
+        self.init_promoted_members(scope).0
+    }
+
+    // Members promoted by more than one spread field, which are not declared by the struct itself
+    pub fn get_promotion_conflicts(&self, scope: &Scope) -> Vec<PromotionConflict> {
+        let own_members = self.get_own_members(scope);
+        let (_, mut conflicts) = self.init_promoted_members(scope);
+        conflicts.retain(|conflict| !own_members.contains_key(&conflict.name));
+        conflicts.sort_by(|a, b| a.name.cmp(&b.name));
+        conflicts
+    }
+
+    fn init_promoted_members(
+        &self,
+        scope: &Scope,
+    ) -> (HashMap<String, StructMember>, Vec<PromotionConflict>) {
+        let mut members = HashMap::new();
+        let mut conflicts = vec![];
+        let mut visited = vec![self.name_span().start_index];
+        self.promote_members(scope, &mut members, &mut conflicts, &mut visited, None);
+        (members, conflicts)
+    }
+
+    fn get_own_members(&self, scope: &Scope) -> HashMap<String, StructMember> {
+        let type_params = self.node.get_type_parameters_map();
         let mut members = HashMap::new();
         for field in self.node.fields.iter() {
-            let member = field.get_member(scope);
+            let field_type = field
+                .get_type(scope, type_params)
+                .bind(scope, &self.bindings);
+            let member = StructMember {
+                public: field.public,
+                mutable: field.mutable,
+                span: field.name.span,
+                spread_field: None,
+                member_type: StructMemberType::Field(field_type),
+            };
             let name = field.name.clone();
             members.entry(name).or_insert(member);
         }
 
         if let Some(implementation) = self.node.implementation.as_ref() {
-            for method in implementation.get_methods(scope) {
+            for method in implementation.get_methods(scope, type_params) {
                 members.entry(method.name).or_insert(StructMember {
                     public: method.public,
-                    member_type: StructMemberType::Method(method.function_type),
+                    mutable: false,
+                    span: method.span,
+                    spread_field: None,
+                    member_type: if method.is_static {
+                        StructMemberType::StaticMethod(
+                            method.function_type.bind(scope, &self.bindings),
+                        )
+                    } else {
+                        StructMemberType::Method(method.function_type.bind(scope, &self.bindings))
+                    },
                 });
             }
         }
@@ -72,29 +218,158 @@ impl StructType {
         members
     }
 
+    // The public instance members of spread fields are promoted, in field order, unless a member
+    // of the same name is declared closer to the struct. Nested spread fields are only promoted
+    // through when they are public, keeping the visibility of the outermost spread field. A member
+    // promoted through two of the struct's spread fields is a conflict.
+    fn promote_members(
+        &self,
+        scope: &Scope,
+        members: &mut HashMap<String, StructMember>,
+        conflicts: &mut Vec<PromotionConflict>,
+        visited: &mut Vec<usize>,
+        outer_field: Option<(bool, &SpreadField)>,
+    ) {
+        for (field, inner_type) in self.get_spread_fields(scope) {
+            if outer_field.is_some() && !field.public {
+                continue;
+            }
+            let own_field = SpreadField {
+                name: field.name.value.clone(),
+                span: field.name.span,
+            };
+            let (public, spread_field) = outer_field.unwrap_or((field.public, &own_field));
+
+            // Structs spread within each other would otherwise promote members indefinitely
+            let id = inner_type.name_span().start_index;
+            if visited.contains(&id) {
+                continue;
+            }
+            visited.push(id);
+
+            for (name, member) in inner_type.get_own_members(scope) {
+                let is_static = matches!(member.member_type, StructMemberType::StaticMethod(_));
+                if !member.public || is_static {
+                    continue;
+                }
+                match members.entry(name) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(StructMember {
+                            public,
+                            spread_field: Some(spread_field.clone()),
+                            ..member
+                        });
+                    }
+                    Entry::Occupied(entry) => {
+                        let first = entry.get().spread_field.as_ref();
+                        let is_conflict = first.is_some_and(|first| {
+                            first.span.start_index != spread_field.span.start_index
+                        }) && !conflicts
+                            .iter()
+                            .any(|conflict| &conflict.name == entry.key());
+                        if let Some(first) = first.filter(|_| is_conflict) {
+                            conflicts.push(PromotionConflict {
+                                name: entry.key().clone(),
+                                first: first.clone(),
+                                second: spread_field.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+            inner_type.promote_members(
+                scope,
+                members,
+                conflicts,
+                visited,
+                Some((public, spread_field)),
+            );
+            visited.pop();
+        }
+    }
+
+    // The spread fields and their struct types, skipping fields which are not structs
+    fn get_spread_fields(&self, scope: &Scope) -> Vec<(&StructFieldNode, Rc<StructType>)> {
+        let type_params = self.node.get_type_parameters_map();
+        self.node
+            .fields
+            .iter()
+            .filter(|field| field.is_spread)
+            .filter_map(|field| {
+                match field
+                    .get_type(scope, type_params)
+                    .bind(scope, &self.bindings)
+                {
+                    Type::Struct(struct_type) => Some((&field.value, struct_type)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    // Members of a struct, including those promoted from its spread fields
+    pub fn get_members(&self, scope: &Scope) -> &HashMap<String, StructMember> {
+        self.members.get_or_init(|| self.init_members(scope))
+    }
+
+    pub fn get_interfaces(&self, scope: &Scope) -> Vec<Rc<InterfaceType>> {
+        let type_params = self.node.get_type_parameters_map();
+        self.node
+            .implementation
+            .as_ref()
+            .map_or_else(Vec::new, |implementation| {
+                implementation.get_interfaces(scope, type_params, &self.bindings)
+            })
+    }
+
     pub fn implements(&self, scope: &Scope, interface_type: &Rc<InterfaceType>) -> bool {
+        let type_params = self.node.get_type_parameters_map();
         self.node
             .implementation
             .as_ref()
-            .is_some_and(|implementation| implementation.implements(scope, interface_type))
+            .is_some_and(|implementation| {
+                implementation.implements(scope, interface_type, type_params, &self.bindings)
+            })
     }
 }
 
 pub struct StructMember {
     pub public: bool,
+    pub mutable: bool,
+    pub span: TokenSpan,
+    // The outermost spread field a promoted member is accessed through, deciding its visibility
+    pub spread_field: Option<SpreadField>,
     pub member_type: StructMemberType,
 }
 
+// A spread field through which members are promoted
+#[derive(Clone)]
+pub struct SpreadField {
+    pub name: String,
+    pub span: TokenSpan,
+}
+
+// A member which two spread fields of a struct both promote
+pub struct PromotionConflict {
+    pub name: String,
+    pub first: SpreadField,
+    pub second: SpreadField,
+}
+
+#[derive(Clone)]
 pub enum StructMemberType {
     Field(Type),
     Method(Rc<FunctionType>),
+    StaticMethod(Rc<FunctionType>),
 }
 
 impl StructMemberType {
     pub fn get_type(&self) -> Type {
         match self {
             Self::Field(field_type) => field_type.clone(),
-            Self::Method(function_type) => Type::Function(function_type.clone()),
+            Self::Method(function_type) | Self::StaticMethod(function_type) => {
+                Type::Function(function_type.clone())
+            }
         }
     }
 
@@ -104,6 +379,8 @@ impl StructMemberType {
                 Type::Function(FunctionType::simple(self_type, field_type.clone()))
             }
             Self::Method(function_type) => function_type.clone().as_static_method(self_type),
+            // Static methods take no receiver, so keep their own signature
+            Self::StaticMethod(function_type) => Type::Function(function_type.clone()),
         }
     }
 }
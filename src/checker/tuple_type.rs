@@ -0,0 +1,109 @@
+use std::{collections::HashSet, rc::Rc};
+
+use crate::{checker::Type, diagnostics::DiagnosticSink, parser::TokenSpan};
+
+// The elements of a tuple type, which are either all named or all unnamed
+pub struct TupleType {
+    pub elements: Vec<Type>,
+    pub names: Option<Vec<String>>,
+}
+
+// An element of a tuple type or literal, before the tuple's names are validated
+pub struct TupleElement {
+    pub name: Option<String>,
+    pub element_type: Type,
+    pub span: TokenSpan,
+}
+
+impl TupleType {
+    pub fn new(elements: Vec<Type>) -> Rc<Self> {
+        Rc::new(Self {
+            elements,
+            names: None,
+        })
+    }
+
+    // Reports duplicate names, and names mixed with unnamed elements, which leave the tuple unnamed
+    pub fn from_elements(diagnostics: &DiagnosticSink, elements: Vec<TupleElement>) -> Rc<Self> {
+        let is_named = elements
+            .first()
+            .is_some_and(|element| element.name.is_some());
+        let mut names = HashSet::new();
+        let mut is_valid = true;
+        for element in &elements {
+            match &element.name {
+                Some(_) if !is_named => {
+                    is_valid = false;
+                    report_mixed_names(diagnostics, element.span);
+                }
+                None if is_named => {
+                    is_valid = false;
+                    report_mixed_names(diagnostics, element.span);
+                }
+                Some(name) if !names.insert(name) => {
+                    is_valid = false;
+                    diagnostics.error(
+                        element.span,
+                        &format!("Duplicate tuple element `{name}`"),
+                        "an element with this name is declared elsewhere in this tuple",
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let (names, elements): (Vec<_>, Vec<_>) = elements
+            .into_iter()
+            .map(|element| (element.name, element.element_type))
+            .unzip();
+        Rc::new(Self {
+            elements,
+            names: names
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .filter(|_| is_valid),
+        })
+    }
+
+    pub fn with_elements(&self, elements: Vec<Type>) -> Rc<Self> {
+        Rc::new(Self {
+            elements,
+            names: self.names.clone(),
+        })
+    }
+
+    // Finds an element by its name, or by its zero based position
+    pub fn get_index(&self, field: &str) -> Option<usize> {
+        let index = match &self.names {
+            Some(names) => names.iter().position(|name| name == field),
+            None => None,
+        };
+        index
+            .or_else(|| field.parse::<usize>().ok())
+            .filter(|index| *index < self.elements.len())
+    }
+
+    // The name of each element, if the tuple is named
+    pub fn get_names(&self) -> Vec<Option<String>> {
+        match &self.names {
+            Some(names) => names.iter().cloned().map(Some).collect(),
+            None => vec![None; self.elements.len()],
+        }
+    }
+
+    // Named tuples only differ when both are named, so unnamed tuples convert to and from them
+    pub fn has_compatible_names(&self, other: &TupleType) -> bool {
+        match (&self.names, &other.names) {
+            (Some(left), Some(right)) => left == right,
+            _ => true,
+        }
+    }
+}
+
+fn report_mixed_names(diagnostics: &DiagnosticSink, span: TokenSpan) {
+    diagnostics.error(
+        span,
+        "Mixed named and unnamed tuple elements",
+        "tuple elements must either all be named or all be unnamed",
+    );
+}
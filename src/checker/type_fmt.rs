@@ -14,10 +14,32 @@ impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::Array(element_type) => write!(f, "[{element_type}]"),
-            Type::Enum(enum_type) => write!(f, "{}", enum_type.name()),
+            Type::Enum(enum_type) => {
+                write!(f, "{}", enum_type.name())?;
+                write_type_arguments(f, &enum_type.type_arguments)
+            }
             Type::Function(function_type) => {
+                if function_type.is_generic() {
+                    let type_parameters = function_type
+                        .type_parameters
+                        .iter()
+                        .map(|type_parameter| {
+                            if type_parameter.is_variadic {
+                                format!("{}{type_parameter}", Symbol::Ellipsis)
+                            } else {
+                                type_parameter.to_string()
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    write!(f, "[")?;
+                    write_list(f, &type_parameters)?;
+                    write!(f, "] ")?;
+                }
                 let show_parentheses = function_type.parameters.len() != 1
-                    || matches!(function_type.parameters[0], Type::Tuple(_));
+                    || matches!(
+                        function_type.parameters[0],
+                        Type::Tuple(_) | Type::Spread(_)
+                    );
                 if show_parentheses {
                     write!(f, "(")?;
                 }
@@ -32,21 +54,53 @@ impl Display for Type {
                 write_list(f, &generic_type.type_parameters)?;
                 write!(f, "]")
             }
-            Type::Interface(interface_type) => write!(f, "{}", interface_type.name),
+            Type::Interface(interface_type) => {
+                write!(f, "{}", interface_type.name)?;
+                write_type_arguments(f, &interface_type.type_arguments)
+            }
             Type::Primitive(primitive_type) => write!(f, "{primitive_type}"),
-            Type::Struct(struct_type) => write!(f, "{}", struct_type.name()),
-            Type::Tuple(items) => {
+            Type::Struct(struct_type) => {
+                write!(f, "{}", struct_type.name())?;
+                write_type_arguments(f, &struct_type.type_arguments)
+            }
+            Type::Tuple(tuple_type) => {
                 write!(f, "(")?;
-                write_list(f, items)?;
+                match &tuple_type.names {
+                    Some(names) => {
+                        let elements = names
+                            .iter()
+                            .zip(tuple_type.elements.iter())
+                            .map(|(name, element)| format!("{name}: {element}"))
+                            .collect::<Vec<_>>();
+                        write_list(f, &elements)?;
+                    }
+                    None => write_list(f, &tuple_type.elements)?,
+                }
                 write!(f, ")")
             }
             Type::TypeParameter(type_parameter) => write!(f, "{}", type_parameter.name),
+            Type::Spread(type_parameter) => {
+                write!(f, "{}{}", Symbol::Ellipsis, type_parameter.name)
+            }
             Type::Void => write!(f, "{}", Keyword::Void),
             Type::Error => write!(f, "{{Unknown}}"),
         }
     }
 }
 
+fn write_type_arguments(
+    f: &mut std::fmt::Formatter<'_>,
+    type_arguments: &[Type],
+) -> std::fmt::Result {
+    if type_arguments.is_empty() {
+        return Ok(());
+    }
+
+    write!(f, "[")?;
+    write_list(f, type_arguments)?;
+    write!(f, "]")
+}
+
 fn write_list<T: Display>(f: &mut std::fmt::Formatter<'_>, list: &[T]) -> std::fmt::Result {
     for (index, element) in list.iter().enumerate() {
         write!(f, "{element}")?;
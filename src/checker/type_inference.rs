@@ -0,0 +1,220 @@
+use std::rc::Rc;
+
+use crate::{
+    checker::{
+        FunctionType, InterfaceType, Scope, TupleType, Type, TypeParameter, TypeParameterBindings,
+        TypeParameterList,
+    },
+    parser::TokenSpan,
+};
+
+// Solves the type parameters of a generic function from the types it is used with
+pub struct TypeInference {
+    type_parameters: TypeParameterList,
+    bindings: TypeParameterBindings,
+}
+
+impl TypeInference {
+    pub fn new(function_type: &FunctionType) -> Self {
+        Self {
+            type_parameters: function_type.type_parameters.clone(),
+            bindings: TypeParameterBindings::new(),
+        }
+    }
+
+    // Substitutes the type parameters which have been inferred so far
+    pub fn bind(&self, scope: &Scope, unbound_type: &Type) -> Type {
+        if self.bindings.is_empty() {
+            return unbound_type.clone();
+        }
+
+        unbound_type.bind(scope, &self.bindings)
+    }
+
+    pub fn unify(&mut self, parameter_type: &Type, argument_type: &Type) {
+        if self.type_parameters.is_empty() {
+            return;
+        }
+
+        match (parameter_type, argument_type) {
+            (_, Type::Error) => {}
+            // Variadic parameters are only solved by tuples, so other types are reported as
+            // mismatches rather than bound
+            (Type::TypeParameter(type_parameter), _)
+                if type_parameter.is_variadic && self.type_parameters.contains(type_parameter) =>
+            {
+                if let Some(elements) = argument_type.spread_elements() {
+                    self.bind_variadic(type_parameter, &elements);
+                }
+            }
+            (Type::TypeParameter(type_parameter), _)
+                if self.type_parameters.contains(type_parameter) =>
+            {
+                self.bindings
+                    .entry(type_parameter.clone())
+                    .or_insert_with(|| argument_type.clone());
+            }
+            (Type::Array(left), Type::Array(right)) => self.unify(left, right),
+            (Type::Enum(left), Type::Enum(right)) if left.name() == right.name() => {
+                self.unify_all(&left.type_arguments, &right.type_arguments);
+            }
+            (Type::Function(left), right) => {
+                if let Some(right) = right.to_function() {
+                    self.unify_all(&left.parameters, &right.parameters);
+                    self.unify(&left.return_type, &right.return_type);
+                }
+            }
+            (Type::Interface(left), Type::Interface(right)) if left.name == right.name => {
+                self.unify_all(&left.type_arguments, &right.type_arguments);
+            }
+            (Type::Struct(left), Type::Struct(right)) if left.name() == right.name() => {
+                self.unify_all(&left.type_arguments, &right.type_arguments);
+            }
+            (Type::Tuple(left), Type::Tuple(right)) => {
+                self.unify_all(&left.elements, &right.elements);
+            }
+            _ => {}
+        }
+    }
+
+    // A spread variadic parameter takes the argument types between those matching the parameters
+    // before and after it
+    fn unify_all(&mut self, parameter_types: &[Type], argument_types: &[Type]) {
+        let spread_index = parameter_types.iter().position(|parameter_type| {
+            matches!(parameter_type, Type::Spread(type_parameter)
+                if self.type_parameters.contains(type_parameter))
+        });
+        let Some(spread_index) = spread_index else {
+            self.unify_each(parameter_types, argument_types);
+            return;
+        };
+
+        let (before, after) = (
+            &parameter_types[..spread_index],
+            &parameter_types[spread_index + 1..],
+        );
+        self.unify_each(before, argument_types);
+        if argument_types.len() < before.len() + after.len() {
+            return;
+        }
+
+        let spread_end = argument_types.len() - after.len();
+        if let Type::Spread(type_parameter) = &parameter_types[spread_index] {
+            self.bind_variadic(type_parameter, &argument_types[spread_index..spread_end]);
+        }
+        self.unify_each(after, &argument_types[spread_end..]);
+    }
+
+    fn unify_each(&mut self, parameter_types: &[Type], argument_types: &[Type]) {
+        for (parameter_type, argument_type) in parameter_types.iter().zip(argument_types.iter()) {
+            self.unify(parameter_type, argument_type);
+        }
+    }
+
+    fn bind_variadic(&mut self, type_parameter: &Rc<TypeParameter>, elements: &[Type]) {
+        let bound_type = match elements {
+            [Type::Spread(spread_parameter)] => Type::TypeParameter(spread_parameter.clone()),
+            _ => Type::Tuple(TupleType::new(elements.to_vec())),
+        };
+        self.bindings
+            .entry(type_parameter.clone())
+            .or_insert(bound_type);
+    }
+
+    // Completes inference, reporting parameters which could not be solved or whose bounds
+    // are not satisfied by the inferred type
+    pub fn finish(mut self, scope: &Scope, span: TokenSpan) -> TypeParameterBindings {
+        for type_parameter in self.solve(scope).iter() {
+            scope.diagnostics.error(
+                span,
+                &format!("Could not infer type parameter `{type_parameter}`"),
+                &format!("cannot determine type `{type_parameter}` from this usage"),
+            );
+            self.bindings.insert(type_parameter.clone(), Type::Error);
+        }
+
+        self.check_bounds(scope, span);
+        self.bindings
+    }
+
+    // Completes inference for a partial application, returning the parameters which remain
+    // unsolved so that the resulting function can stay generic over them
+    pub fn finish_partial(&mut self, scope: &Scope, span: TokenSpan) -> TypeParameterList {
+        let unresolved = self.solve(scope);
+        self.check_bounds(scope, span);
+        unresolved
+    }
+
+    fn solve(&mut self, scope: &Scope) -> TypeParameterList {
+        for type_parameter in self.type_parameters.clone().iter() {
+            self.unify_bounds(scope, type_parameter);
+        }
+
+        let unresolved = self
+            .type_parameters
+            .iter()
+            .filter(|type_parameter| !self.bindings.contains_key(*type_parameter))
+            .cloned()
+            .collect();
+        TypeParameterList::new(unresolved)
+    }
+
+    fn check_bounds(&self, scope: &Scope, span: TokenSpan) {
+        for type_parameter in self.type_parameters.iter() {
+            if let Some(bound_type) = self.bindings.get(type_parameter) {
+                check_bounds(scope, span, type_parameter, bound_type, &self.bindings);
+            }
+        }
+    }
+
+    pub fn get_bindings(&self) -> &TypeParameterBindings {
+        &self.bindings
+    }
+
+    // Solves parameters which only appear in the bounds of another, inferred parameter
+    fn unify_bounds(&mut self, scope: &Scope, type_parameter: &Rc<TypeParameter>) {
+        let Some(bound_type) = self.bindings.get(type_parameter).cloned() else {
+            return;
+        };
+
+        let interfaces = get_interfaces(scope, &bound_type);
+        for bound in type_parameter.get_bounds() {
+            let implemented = interfaces
+                .iter()
+                .find(|interface_type| interface_type.name == bound.name);
+            if let Some(implemented) = implemented {
+                self.unify_all(&bound.type_arguments, &implemented.type_arguments);
+            }
+        }
+    }
+}
+
+// Verifies that a type bound to a type parameter implements each of its bounds
+pub fn check_bounds(
+    scope: &Scope,
+    span: TokenSpan,
+    type_parameter: &TypeParameter,
+    bound_type: &Type,
+    bindings: &TypeParameterBindings,
+) {
+    for bound in type_parameter.get_bounds() {
+        let bound = Type::Interface(bound.bind(scope, bindings));
+        if !bound_type.is_assignable_to(&bound, scope) {
+            scope.diagnostics.error(
+                span,
+                &format!("Type `{bound_type}` does not satisfy bound `{bound}`"),
+                &format!("type parameter `{type_parameter}` must implement `{bound}`"),
+            );
+        }
+    }
+}
+
+fn get_interfaces(scope: &Scope, implementing_type: &Type) -> Vec<Rc<InterfaceType>> {
+    match implementing_type {
+        Type::Enum(enum_type) => enum_type.get_interfaces(scope),
+        Type::Interface(interface_type) => vec![interface_type.clone()],
+        Type::Struct(struct_type) => struct_type.get_interfaces(scope),
+        Type::TypeParameter(type_parameter) => type_parameter.get_bounds().to_vec(),
+        _ => vec![],
+    }
+}
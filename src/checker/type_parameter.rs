@@ -1,4 +1,5 @@
 use std::{
+    cell::OnceCell,
     collections::HashMap,
     fmt::Display,
     hash::Hash,
@@ -6,25 +7,42 @@ use std::{
     sync::atomic::{AtomicUsize, Ordering},
 };
 
-use crate::checker::{Type, TypeParameterBindings};
+use crate::checker::{InterfaceType, Type, TypeParameterBindings};
 
 pub type TypeParameterMap = HashMap<String, Rc<TypeParameter>>;
 
-#[derive(Eq)]
 pub struct TypeParameter {
     pub name: String,
+    // Variadic parameters stand for a tuple of types, which can be spread into a type list
+    pub is_variadic: bool,
     id: usize,
+    bounds: OnceCell<Vec<Rc<InterfaceType>>>,
 }
 
 impl TypeParameter {
     pub fn new(name: String) -> Self {
+        Self::with_variadic(name, false)
+    }
+
+    pub fn with_variadic(name: String, is_variadic: bool) -> Self {
         static COUNTER: AtomicUsize = AtomicUsize::new(0);
         Self {
             name,
+            is_variadic,
             id: COUNTER.fetch_add(1, Ordering::Relaxed),
+            bounds: OnceCell::new(),
         }
     }
 
+    // The interfaces any type bound to this parameter must implement
+    pub fn get_bounds(&self) -> &[Rc<InterfaceType>] {
+        self.bounds.get().map_or(&[], |bounds| bounds)
+    }
+
+    pub fn set_bounds(&self, bounds: Vec<Rc<InterfaceType>>) {
+        let _ = self.bounds.set(bounds);
+    }
+
     pub fn bind(self: &Rc<Self>, bindings: &TypeParameterBindings) -> Type {
         bindings
             .get(self)
@@ -39,6 +57,8 @@ impl PartialEq for TypeParameter {
     }
 }
 
+impl Eq for TypeParameter {}
+
 impl Hash for TypeParameter {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.id.hash(state);
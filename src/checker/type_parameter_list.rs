@@ -1,10 +1,13 @@
 use std::{collections::HashMap, ops::Deref, rc::Rc};
 
-use crate::checker::{Type, TypeParameter};
+use crate::{
+    checker::{Type, TypeParameter, Types},
+    parser::{NodeVec, TypeNode},
+};
 
 pub type TypeParameterBindings = HashMap<Rc<TypeParameter>, Type>;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TypeParameterList {
     list: Vec<Rc<TypeParameter>>,
 }
@@ -23,6 +26,41 @@ impl TypeParameterList {
 
         bindings
     }
+
+    pub fn bind_types(
+        &self,
+        types: &impl Types,
+        bound_type_params: &NodeVec<TypeNode>,
+        bound_types: &[Type],
+    ) -> TypeParameterBindings {
+        if bound_types.len() != self.len() {
+            types.get_diagnostics().error(
+                bound_type_params.span,
+                "Mismatched type parameters",
+                &format!(
+                    "expected {} types, found {}",
+                    self.len(),
+                    bound_type_params.len()
+                ),
+            );
+        }
+
+        let type_parameters = self.list.iter().zip(bound_types.iter());
+        for (index, (type_parameter, bound_type)) in type_parameters.enumerate() {
+            if type_parameter.is_variadic
+                && bound_type.spread_elements().is_none()
+                && !bound_type.is_error()
+            {
+                types.get_diagnostics().error(
+                    bound_type_params[index].span,
+                    &format!("Variadic type parameter `{type_parameter}` must be a tuple"),
+                    &format!("found type `{bound_type}`"),
+                );
+            }
+        }
+
+        self.get_bindings(bound_types)
+    }
 }
 
 impl Deref for TypeParameterList {
@@ -2,8 +2,8 @@ use std::rc::Rc;
 
 use crate::{
     checker::{
-        EnumType, FunctionType, GenericType, InterfaceType, Scope, StructType, TypeParameter,
-        TypeParameterBindings, Types,
+        EnumType, FunctionType, GenericType, InterfaceType, Scope, StructType, TupleType,
+        TypeParameter, TypeParameterBindings, Types,
     },
     parser::PrimitiveType,
 };
@@ -17,8 +17,10 @@ pub enum Type {
     Interface(Rc<InterfaceType>),
     Primitive(PrimitiveType),
     Struct(Rc<StructType>),
-    Tuple(Rc<Vec<Type>>),
+    Tuple(Rc<TupleType>),
     TypeParameter(Rc<TypeParameter>),
+    // A variadic type parameter spread into a list of parameter or element types
+    Spread(Rc<TypeParameter>),
     Void,
     Error,
 }
@@ -37,6 +39,11 @@ impl Type {
             return true;
         }
 
+        // A tuple of only a spread variadic parameter is the parameter itself
+        if let (Some(left), Some(right)) = (self.as_variadic(), other.as_variadic()) {
+            return left == right;
+        }
+
         match self {
             // TODO should arrays be contravariant?
             Type::Array(left) => match other {
@@ -44,7 +51,10 @@ impl Type {
                 _ => false,
             },
             Type::Enum(left) => match other {
-                Type::Enum(right) => left.name() == right.name(),
+                Type::Enum(right) => {
+                    left.name() == right.name()
+                        && are_equivalent(&left.type_arguments, &right.type_arguments, scope)
+                }
                 _ => false,
             },
             Type::Function(left) => match other.to_function() {
@@ -64,9 +74,16 @@ impl Type {
             },
             Type::Generic(_) => panic!("It should not be possible to produce a generic type"),
             Type::Interface(left) => match other {
-                Type::Interface(right) => left.name == right.name,
+                Type::Interface(right) => {
+                    left.name == right.name
+                        && are_equivalent(&left.type_arguments, &right.type_arguments, scope)
+                }
                 Type::Enum(right) => right.implements(scope, left),
                 Type::Struct(right) => right.implements(scope, left),
+                Type::TypeParameter(right) => right
+                    .get_bounds()
+                    .iter()
+                    .any(|bound| self.is_assignable_from(&Type::Interface(bound.clone()), scope)),
                 _ => false,
             },
             Type::Primitive(left) => match other {
@@ -74,15 +91,20 @@ impl Type {
                 _ => false,
             },
             Type::Struct(left) => match other {
-                Type::Struct(right) => left.name() == right.name(),
+                Type::Struct(right) => {
+                    left.name() == right.name()
+                        && are_equivalent(&left.type_arguments, &right.type_arguments, scope)
+                }
                 _ => false,
             },
             Type::Tuple(left) => match other {
                 Type::Tuple(right) => {
-                    left.len() == right.len()
+                    left.elements.len() == right.elements.len()
+                        && left.has_compatible_names(right)
                         && left
+                            .elements
                             .iter()
-                            .zip(right.iter())
+                            .zip(right.elements.iter())
                             .all(|(left, right)| left.is_assignable_from(right, scope))
                 }
                 _ => false,
@@ -91,6 +113,10 @@ impl Type {
                 Type::TypeParameter(right) => left == right,
                 _ => false,
             },
+            Type::Spread(left) => match other {
+                Type::Spread(right) => left == right,
+                _ => false,
+            },
             Type::Void => matches!(other, Type::Void),
             Type::Error => true,
         }
@@ -99,19 +125,19 @@ impl Type {
     pub fn bind(&self, types: &impl Types, bindings: &TypeParameterBindings) -> Type {
         match self {
             Type::Array(t) => Type::Array(Box::new(t.bind(types, bindings))),
-            // TODO implement bind for enums
-            Type::Enum(t) => Type::Enum(t.clone()),
+            Type::Enum(t) => Type::Enum(t.bind(types, bindings)),
             Type::Function(t) => Type::Function(t.bind(types, bindings)),
             Type::Generic(_) => panic!("It should not be possible to bind a generic type"),
-            // TODO implement bind for interfaces
-            Type::Interface(t) => Type::Interface(t.clone()),
+            Type::Interface(t) => Type::Interface(t.bind(types, bindings)),
             Type::Primitive(t) => Type::Primitive(*t),
-            // TODO implement bind for structs
-            Type::Struct(t) => Type::Struct(t.clone()),
-            Type::Tuple(elements) => Type::Tuple(Rc::new(
-                elements.iter().map(|t| t.bind(types, bindings)).collect(),
-            )),
+            Type::Struct(t) => Type::Struct(t.bind(types, bindings)),
+            Type::Tuple(t) => Type::Tuple(t.with_elements(bind_list(&t.elements, types, bindings))),
             Type::TypeParameter(t) => t.bind(bindings),
+            // Spreads are expanded by the list containing them, so only a renaming applies here
+            Type::Spread(t) => match t.bind(bindings) {
+                Type::TypeParameter(bound) if bound.is_variadic => Type::Spread(bound),
+                _ => Type::Spread(t.clone()),
+            },
             Type::Void => Type::Void,
             Type::Error => Type::Error,
         }
@@ -129,6 +155,43 @@ impl Type {
         }
     }
 
+    pub fn is_numeric(&self) -> bool {
+        match self {
+            Self::Primitive(primitive) => primitive.is_numeric(),
+            Self::Error => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        self.is_numeric() || self.is_primitive(PrimitiveType::Char)
+    }
+
+    // The types this type contributes when spread into a list, if it can be spread
+    pub fn spread_elements(&self) -> Option<Vec<Type>> {
+        match self {
+            Type::Tuple(tuple_type) => Some(tuple_type.elements.clone()),
+            Type::TypeParameter(type_parameter) if type_parameter.is_variadic => {
+                Some(vec![Type::Spread(type_parameter.clone())])
+            }
+            _ => None,
+        }
+    }
+
+    // The variadic parameter this type stands for, either directly or as a tuple spreading it
+    pub fn as_variadic(&self) -> Option<&Rc<TypeParameter>> {
+        match self {
+            Type::TypeParameter(type_parameter) if type_parameter.is_variadic => {
+                Some(type_parameter)
+            }
+            Type::Tuple(tuple_type) => match tuple_type.elements.as_slice() {
+                [Type::Spread(type_parameter)] => Some(type_parameter),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn to_function(&self) -> Option<Rc<FunctionType>> {
         match self {
             Type::Array(element_type) => Some(FunctionType::simple(
@@ -140,3 +203,27 @@ impl Type {
         }
     }
 }
+
+// Binds each type in a list, splicing in the types bound to spread variadic parameters
+pub fn bind_list(list: &[Type], types: &impl Types, bindings: &TypeParameterBindings) -> Vec<Type> {
+    list.iter()
+        .flat_map(|element| match element {
+            Type::Spread(type_parameter) => {
+                let bound_type = type_parameter.bind(bindings);
+                bound_type
+                    .spread_elements()
+                    .unwrap_or_else(|| vec![bound_type])
+            }
+            _ => vec![element.bind(types, bindings)],
+        })
+        .collect()
+}
+
+// Type arguments are invariant, so they must match exactly
+fn are_equivalent(left: &[Type], right: &[Type], scope: &Scope) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .all(|(left, right)| left.is_equivalent_to(right, scope))
+}
@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use crate::lexer::{CharacterSpan, SourceCode};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+// The part of the compiler which produced a diagnostic
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    // Reading the program's source files
+    Loader,
+    Lexer,
+    Parser,
+    Checker,
+    Runtime,
+}
+
+// A span of characters within one of the program's source files
+#[derive(Clone)]
+pub struct SourceSpan {
+    pub source: Rc<SourceCode>,
+    pub span: CharacterSpan,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Label {
+    pub span: SourceSpan,
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub phase: Phase,
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub primary: Label,
+    pub secondary: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, phase: Phase, message: &str, primary: Label) -> Self {
+        Self {
+            severity,
+            phase,
+            code: None,
+            message: message.to_owned(),
+            primary,
+            secondary: vec![],
+            notes: vec![],
+            help: None,
+        }
+    }
+
+    pub fn error(span: SourceSpan, message: &str, label: &str) -> Self {
+        Self::new(
+            Severity::Error,
+            Phase::Checker,
+            message,
+            Label::new(span, label),
+        )
+    }
+
+    pub fn warning(span: SourceSpan, message: &str, label: &str) -> Self {
+        Self::new(
+            Severity::Warning,
+            Phase::Checker,
+            message,
+            Label::new(span, label),
+        )
+    }
+
+    pub fn with_phase(mut self, phase: Phase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_label(mut self, span: SourceSpan, message: &str) -> Self {
+        self.secondary.push(Label::new(span, message));
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_owned());
+        self
+    }
+
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_owned());
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Label {
+    pub fn new(span: SourceSpan, message: &str) -> Self {
+        Self {
+            span,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl SourceSpan {
+    pub fn new(source: Rc<SourceCode>, span: CharacterSpan) -> Self {
+        Self { source, span }
+    }
+}
+
+impl PartialEq for SourceSpan {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.source, &other.source) && self.span == other.span
+    }
+}
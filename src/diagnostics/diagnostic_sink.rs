@@ -0,0 +1,76 @@
+use std::{cell::RefCell, rc::Rc, thread};
+
+use crate::{
+    diagnostics::{Diagnostic, ErrorFormat, SourceSpan},
+    lexer::SourceMap,
+    parser::TokenSpan,
+};
+
+// Collects the diagnostics reported while compiling a program
+pub struct DiagnosticSink {
+    pub sources: Rc<SourceMap>,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+}
+
+impl DiagnosticSink {
+    pub fn new(sources: Rc<SourceMap>) -> Self {
+        Self {
+            sources,
+            diagnostics: RefCell::new(vec![]),
+        }
+    }
+
+    // Reports a diagnostic, ignoring exact duplicates of previously reported diagnostics
+    pub fn report(&self, diagnostic: Diagnostic) {
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    pub fn error(&self, span: TokenSpan, message: &str, label: &str) {
+        self.report(Diagnostic::error(self.span(span), message, label));
+    }
+
+    pub fn warning(&self, span: TokenSpan, message: &str, label: &str) {
+        self.report(Diagnostic::warning(self.span(span), message, label));
+    }
+
+    pub fn span(&self, span: TokenSpan) -> SourceSpan {
+        let source = self.sources.get(span);
+        let characters = source.character_span(span);
+        SourceSpan::new(source, characters)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.borrow().iter().any(Diagnostic::is_error)
+    }
+
+    pub fn take(&self) -> Vec<Diagnostic> {
+        self.diagnostics.take()
+    }
+
+    // Renders the diagnostics which have not been taken if the compiler panics before the guard
+    // is dropped, so that the errors reported before a crash are not lost
+    pub fn render_on_panic(&self, error_format: ErrorFormat) -> PanicGuard<'_> {
+        PanicGuard {
+            sink: self,
+            error_format,
+        }
+    }
+}
+
+pub struct PanicGuard<'a> {
+    sink: &'a DiagnosticSink,
+    error_format: ErrorFormat,
+}
+
+impl Drop for PanicGuard<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            for diagnostic in self.sink.take() {
+                self.error_format.render(&diagnostic);
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::diagnostics::{
+    Diagnostic, Phase, render, render_json, render_json_message, render_message,
+};
+
+// How diagnostics are printed, chosen with the `--error-format` option
+#[derive(Clone, Copy)]
+pub enum ErrorFormat {
+    Human,
+    Json,
+}
+
+impl ErrorFormat {
+    pub fn render(self, diagnostic: &Diagnostic) {
+        match self {
+            ErrorFormat::Human => render(diagnostic),
+            ErrorFormat::Json => println!("{}", render_json(diagnostic)),
+        }
+    }
+
+    // Prints an error which does not refer to any source code, such as a file which is missing
+    pub fn render_message(self, phase: Phase, path: &str, message: &str) {
+        match self {
+            ErrorFormat::Human => render_message(phase, message),
+            ErrorFormat::Json => println!("{}", render_json_message(phase, path, message)),
+        }
+    }
+}
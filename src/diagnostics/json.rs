@@ -0,0 +1,112 @@
+use std::fmt::Write;
+
+use crate::{
+    diagnostics::{Diagnostic, Label, Phase, Severity, SourceSpan},
+    lexer::CharacterLocation,
+};
+
+// Formats a diagnostic as a single line JSON object, for editors and other tools
+pub fn render_json(diagnostic: &Diagnostic) -> String {
+    let severity = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    };
+
+    let mut json = String::from("{");
+    let path = &diagnostic.primary.span.source.path;
+    write!(json, "\"path\":{}", string(path)).unwrap();
+    write!(json, ",\"severity\":{}", string(severity)).unwrap();
+    write!(json, ",\"phase\":{}", string(phase_name(diagnostic.phase))).unwrap();
+    write!(json, ",\"code\":{}", optional(diagnostic.code)).unwrap();
+    write!(json, ",\"message\":{}", string(&diagnostic.message)).unwrap();
+    write!(json, ",\"label\":{}", string(&diagnostic.primary.message)).unwrap();
+    write!(json, ",\"span\":{}", span(&diagnostic.primary.span)).unwrap();
+
+    let labels = diagnostic.secondary.iter().map(label).collect::<Vec<_>>();
+    write!(json, ",\"labels\":[{}]", labels.join(",")).unwrap();
+    let notes = diagnostic
+        .notes
+        .iter()
+        .map(|note| string(note))
+        .collect::<Vec<_>>();
+    write!(json, ",\"notes\":[{}]", notes.join(",")).unwrap();
+    write!(json, ",\"help\":{}", optional(diagnostic.help.as_deref())).unwrap();
+    json.push('}');
+    json
+}
+
+// Formats an error which does not refer to any source code, with the same fields as a diagnostic
+pub fn render_json_message(phase: Phase, path: &str, message: &str) -> String {
+    let mut json = String::from("{");
+    write!(json, "\"path\":{}", string(path)).unwrap();
+    write!(json, ",\"severity\":{}", string("error")).unwrap();
+    write!(json, ",\"phase\":{}", string(phase_name(phase))).unwrap();
+    write!(json, ",\"code\":{}", optional(None)).unwrap();
+    write!(json, ",\"message\":{}", string(message)).unwrap();
+    write!(json, ",\"label\":null,\"span\":null").unwrap();
+    write!(json, ",\"labels\":[],\"notes\":[]").unwrap();
+    write!(json, ",\"help\":{}", optional(None)).unwrap();
+    json.push('}');
+    json
+}
+
+fn phase_name(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Loader => "loader",
+        Phase::Lexer => "lexer",
+        Phase::Parser => "parser",
+        Phase::Checker => "checker",
+        Phase::Runtime => "runtime",
+    }
+}
+
+fn label(label: &Label) -> String {
+    format!(
+        "{{\"path\":{},\"span\":{},\"message\":{}}}",
+        string(&label.span.source.path),
+        span(&label.span),
+        string(&label.message)
+    )
+}
+
+fn span(span: &SourceSpan) -> String {
+    format!(
+        "{{\"start\":{},\"end\":{}}}",
+        location(span.span.start),
+        location(span.span.end)
+    )
+}
+
+// Lines and columns are one-based, as in the terminal output, while bytes are offsets
+fn location(location: CharacterLocation) -> String {
+    format!(
+        "{{\"byte\":{},\"line\":{},\"column\":{}}}",
+        location.byte,
+        location.line + 1,
+        location.column + 1
+    )
+}
+
+fn optional(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_owned(), string)
+}
+
+fn string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                write!(json, "\\u{:04x}", character as u32).unwrap()
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
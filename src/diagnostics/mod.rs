@@ -0,0 +1,11 @@
+mod diagnostic;
+mod diagnostic_sink;
+mod error_format;
+mod json;
+mod render;
+
+pub use diagnostic::*;
+pub use diagnostic_sink::*;
+pub use error_format::*;
+pub use json::*;
+pub use render::*;
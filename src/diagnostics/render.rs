@@ -0,0 +1,131 @@
+use std::cmp::min;
+
+use colored::{ColoredString, Colorize};
+
+use crate::{
+    diagnostics::{Diagnostic, Phase, Severity, SourceSpan},
+    lexer::CharacterSpan,
+};
+
+// Prints a diagnostic to the terminal, with the source code it refers to
+pub fn render(diagnostic: &Diagnostic) {
+    let title = get_title(diagnostic.severity, diagnostic.phase);
+    let title = match diagnostic.code {
+        Some(code) => format!("{title}[{code}]:"),
+        None => format!("{title}:"),
+    };
+    println!(
+        "{} {}",
+        apply_severity(&title, diagnostic.severity),
+        diagnostic.message
+    );
+
+    let primary = &diagnostic.primary;
+    print_span(&primary.span, '^', &primary.message, diagnostic.severity);
+    for label in &diagnostic.secondary {
+        print_span(&label.span, '-', &label.message, Severity::Note);
+    }
+    for note in &diagnostic.notes {
+        println!("   {} {} {note}", "=".bold().cyan(), "note:".bold());
+    }
+    if let Some(help) = diagnostic.help.as_ref() {
+        println!("   {} {} {help}", "=".bold().cyan(), "help:".bold());
+    }
+    println!();
+}
+
+// Prints an error which does not refer to any source code
+pub fn render_message(phase: Phase, message: &str) {
+    let title = format!("{}:", get_title(Severity::Error, phase));
+    println!("{} {message}", apply_severity(&title, Severity::Error));
+}
+
+fn get_title(severity: Severity, phase: Phase) -> &'static str {
+    match (severity, phase) {
+        (Severity::Error, Phase::Lexer) => "Lexer error",
+        (Severity::Error, Phase::Parser) => "Syntax error",
+        (Severity::Error, Phase::Runtime) => "Runtime error",
+        (Severity::Error, Phase::Loader | Phase::Checker) => "Error",
+        (Severity::Warning, _) => "Warning",
+        (Severity::Note, _) => "Note",
+    }
+}
+
+fn print_span(span: &SourceSpan, underline: char, message: &str, severity: Severity) {
+    let source = &span.source;
+    let CharacterSpan {
+        start: start_character,
+        end: end_character,
+    } = span.span;
+    let start_byte = start_character.byte;
+    let end_byte = end_character.byte;
+    let end_byte_clamped = min(end_byte, source.source.len());
+
+    let prefix = source.span_prefix(start_byte);
+    let body = &source.source[start_byte..min(end_byte, end_byte_clamped)];
+    let suffix = source.span_suffix(end_byte);
+
+    println!(
+        "  {} {}:{}",
+        "-->".cyan().bold(),
+        source.path,
+        start_character,
+    );
+
+    let lines = body.split('\n').collect::<Vec<_>>();
+    for (line_offset, &line_text) in lines.iter().enumerate() {
+        let line = start_character.line + line_offset + 1;
+        print_line_header(line);
+        if line_offset == 0 {
+            print!("{prefix}");
+        }
+
+        print!("{line_text}");
+        if line_offset == lines.len() - 1 {
+            println!("{suffix}");
+        } else {
+            println!();
+        }
+
+        print!("   {} ", "|".bold().cyan());
+        if line_offset == 0 {
+            for _ in prefix.chars() {
+                print!(" ");
+            }
+        }
+
+        let underline = underline.to_string();
+        let mut is_indent = true;
+        for line_char in line_text.chars() {
+            if !line_char.is_whitespace() {
+                is_indent = false;
+            }
+            if is_indent {
+                print!(" ");
+            } else {
+                print!("{}", apply_severity(underline.as_str(), severity));
+            }
+        }
+
+        if line_offset == lines.len() - 1 {
+            print!(" {}", apply_severity(message, severity));
+        }
+        println!();
+    }
+}
+
+fn apply_severity(text: &str, severity: Severity) -> ColoredString {
+    match severity {
+        Severity::Error => text.red().bold(),
+        Severity::Warning => text.yellow().bold(),
+        Severity::Note => text.green().bold(),
+    }
+}
+
+fn print_line_header(line: usize) {
+    print!(
+        "{:>2} {} ",
+        line.to_string().bold().cyan(),
+        "|".bold().cyan(),
+    );
+}
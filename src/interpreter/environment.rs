@@ -0,0 +1,58 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::interpreter::Value;
+
+// An immutable chain of bindings. Binding a name creates a new environment so that closures
+// keep seeing the bindings that existed when they were created, even if a name is later shadowed.
+#[derive(Clone, Default)]
+pub struct Environment<'a> {
+    head: Option<Rc<Binding<'a>>>,
+}
+
+struct Binding<'a> {
+    name: &'a str,
+    value: RefCell<Value<'a>>,
+    parent: Option<Rc<Binding<'a>>>,
+}
+
+impl<'a> Environment<'a> {
+    pub fn new() -> Self {
+        Environment::default()
+    }
+
+    pub fn bind(&self, name: &'a str, value: Value<'a>) -> Self {
+        Environment {
+            head: Some(Rc::new(Binding {
+                name,
+                value: RefCell::new(value),
+                parent: self.head.clone(),
+            })),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        self.find(name)
+            .map(|binding| binding.value.borrow().clone())
+    }
+
+    pub fn assign(&self, name: &str, value: Value<'a>) -> bool {
+        match self.find(name) {
+            Some(binding) => {
+                *binding.value.borrow_mut() = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&Rc<Binding<'a>>> {
+        let mut current = self.head.as_ref();
+        while let Some(binding) = current {
+            if binding.name == name {
+                return Some(binding);
+            }
+            current = binding.parent.as_ref();
+        }
+        None
+    }
+}
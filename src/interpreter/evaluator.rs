@@ -0,0 +1,706 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+use crate::{
+    checker::{CALL_OPERATOR, OPTION_TYPE, RANGE_TYPE, Resolutions},
+    diagnostics::{DiagnosticSink, ErrorFormat, Phase},
+    interpreter::{
+        ControlFlow, EnumValue, Environment, Evaluation, FunctionValue, Receiver, RuntimeError,
+        StructValue, Value,
+    },
+    lexer::SourceCode,
+    parser::{
+        ClosureExpressionNode, EnumNode, FunctionBodyNode, FunctionNode, ImplementationEntryNode,
+        ImplementationNode, Module, ModuleDefinitionNode, NameNode, Node, PRELUDE_MODULE,
+        ProgramNode, StructNode, TokenSpan,
+    },
+};
+
+const MAIN_FUNCTION: &str = "main";
+pub const SELF_VALUE: &str = "self";
+// Deeper recursion is reported as an error rather than overflowing the stack of the interpreter
+const MAX_CALL_DEPTH: usize = 10_000;
+
+pub enum TypeDefinition<'a> {
+    Enum(&'a EnumNode),
+    Struct(&'a StructNode),
+}
+
+pub enum Member<'a> {
+    Field(Value<'a>),
+    Method(Value<'a>),
+}
+
+impl<'a> Member<'a> {
+    pub fn value(self) -> Value<'a> {
+        match self {
+            Member::Field(value) | Member::Method(value) => value,
+        }
+    }
+}
+
+// The values defined or imported at the top level of a module
+struct ModuleGlobals<'a> {
+    source: &'a SourceCode,
+    values: HashMap<&'a str, Value<'a>>,
+}
+
+pub struct Interpreter<'a> {
+    pub resolutions: &'a Resolutions,
+    // Keyed by the first token of the type's name, which is distinct across modules
+    pub types: HashMap<usize, TypeDefinition<'a>>,
+    modules: Vec<ModuleGlobals<'a>>,
+    main: Option<&'a FunctionNode>,
+    // The built-in types declared by the prelude
+    pub option: &'a EnumNode,
+    pub range: &'a StructNode,
+    // The number of function and closure calls currently being evaluated
+    call_depth: Cell<usize>,
+}
+
+impl<'a> Interpreter<'a> {
+    // Creates an interpreter for modules ordered after their imports, with the main module last
+    pub fn new(modules: &'a [Module], resolutions: &'a Resolutions) -> Self {
+        let mut types = HashMap::new();
+        let mut globals: Vec<ModuleGlobals> = vec![];
+        for module in modules {
+            let mut values = HashMap::new();
+            for definition in module.program.definitions.iter() {
+                match &definition.definition {
+                    ModuleDefinitionNode::Enum(node) => {
+                        types.insert(node.name.span.start_index, TypeDefinition::Enum(node));
+                    }
+                    ModuleDefinitionNode::Function(node) => {
+                        let function = FunctionValue::Function {
+                            node,
+                            receiver: Receiver::None,
+                        };
+                        values.insert(node.name().as_str(), Value::function(function));
+                    }
+                    ModuleDefinitionNode::Struct(node) => {
+                        let constructor = FunctionValue::Constructor(node);
+                        values.insert(node.name.value.as_str(), Value::function(constructor));
+                        types.insert(node.name.span.start_index, TypeDefinition::Struct(node));
+                    }
+                    ModuleDefinitionNode::Interface(_) | ModuleDefinitionNode::TypeAlias(_) => {}
+                }
+            }
+
+            for &import in module.imports.iter().flatten().chain(&module.prelude) {
+                let exporter = &modules[import].program;
+                for definition in exporter.definitions.iter().filter(|def| def.public) {
+                    let name = definition.definition.name().value.as_str();
+                    if let Some(value) = globals[import].values.get(name) {
+                        values.entry(name).or_insert_with(|| value.clone());
+                    }
+                }
+            }
+
+            globals.push(ModuleGlobals {
+                source: &module.source,
+                values,
+            });
+        }
+
+        // Only the main module's `main` function is run
+        let main =
+            modules.last().and_then(|module| {
+                module.program.definitions.iter().find_map(|definition| {
+                    match &definition.definition {
+                        ModuleDefinitionNode::Function(node) if node.name() == MAIN_FUNCTION => {
+                            Some(node.as_ref())
+                        }
+                        _ => None,
+                    }
+                })
+            });
+
+        let prelude = &modules[PRELUDE_MODULE].program;
+        let ModuleDefinitionNode::Enum(option) = prelude_definition(prelude, OPTION_TYPE) else {
+            panic!("`{OPTION_TYPE}` should be an enum");
+        };
+        let ModuleDefinitionNode::Struct(range) = prelude_definition(prelude, RANGE_TYPE) else {
+            panic!("`{RANGE_TYPE}` should be a struct");
+        };
+
+        Self {
+            resolutions,
+            types,
+            modules: globals,
+            main,
+            option,
+            range,
+            call_depth: Cell::new(0),
+        }
+    }
+
+    // Finds a value defined or imported by the module containing the span
+    pub fn get_global(&self, name: &str, span: TokenSpan) -> Option<Value<'a>> {
+        self.modules
+            .iter()
+            .find(|module| module.source.contains(span.start_index))
+            .and_then(|module| module.values.get(name).cloned())
+    }
+
+    // Runs the program's main function, returning whether it completed successfully
+    pub fn run(&self, diagnostics: &DiagnosticSink, error_format: ErrorFormat) -> bool {
+        let Some(main) = self.main else {
+            let path = self.modules.last().map_or("", |module| &module.source.path);
+            error_format.render_message(
+                Phase::Runtime,
+                path,
+                &format!("could not find a `{MAIN_FUNCTION}` function"),
+            );
+            return false;
+        };
+
+        if !main.signature.parameters.is_empty() {
+            let error = RuntimeError::new(
+                main.signature.parameters.span,
+                &format!("`{MAIN_FUNCTION}` must not take parameters"),
+                "expected no parameters",
+            );
+            error_format.render(&error.to_diagnostic(diagnostics));
+            return false;
+        }
+
+        match self.call_function(main, &Receiver::None, vec![]) {
+            Ok(Value::Void) | Err(ControlFlow::Break | ControlFlow::Continue) => true,
+            Ok(value) => {
+                println!("{value}");
+                true
+            }
+            Err(ControlFlow::Return(_) | ControlFlow::ShortCircuit) => true,
+            Err(ControlFlow::Error(error)) => {
+                error_format.render(&error.to_diagnostic(diagnostics));
+                false
+            }
+        }
+    }
+
+    pub fn call(
+        &self,
+        function: &Value<'a>,
+        mut arguments: Vec<Value<'a>>,
+        span: TokenSpan,
+    ) -> Evaluation<'a> {
+        let function = match function {
+            Value::Array(elements) => return Ok(self.index(elements, &arguments, span)?),
+            Value::Function(function) => function.clone(),
+            _ if let Some(method) = self.get_method(function, CALL_OPERATOR) => {
+                return self.call(&method, arguments, span);
+            }
+            _ => {
+                let error = RuntimeError::new(
+                    span,
+                    "Cannot use value as a function",
+                    &format!("value `{function}` is not callable"),
+                );
+                return Err(error.into());
+            }
+        };
+
+        let arity = function.arity();
+        if arguments.len() < arity {
+            let partial = FunctionValue::Partial {
+                function: Value::Function(function),
+                arguments,
+            };
+            return Ok(Value::function(partial));
+        }
+
+        if arguments.len() > arity && !function.is_variadic() {
+            let error = RuntimeError::new(
+                span,
+                "Too many arguments",
+                &format!(
+                    "expected {arity} arguments but received {}",
+                    arguments.len()
+                ),
+            );
+            return Err(error.into());
+        }
+
+        match function.as_ref() {
+            FunctionValue::Closure { node, environment } => {
+                self.nested_call(span, || self.call_closure(node, environment, arguments))
+            }
+            FunctionValue::Constructor(node) => Ok(Value::Struct(Rc::new(StructValue {
+                node,
+                fields: RefCell::new(arguments.iter().map(Value::copy).collect()),
+            }))),
+            FunctionValue::Deferred {
+                function,
+                field,
+                arguments: field_arguments,
+            } => {
+                let receiver = self.call(function, arguments, span)?;
+                self.access_member(&receiver, field, field_arguments.clone(), span)
+            }
+            FunctionValue::Function { node, receiver } => {
+                self.nested_call(span, || self.call_function(node, receiver, arguments))
+            }
+            FunctionValue::Member {
+                field,
+                arguments: field_arguments,
+            } => self.access_member(&arguments[0], field, field_arguments.clone(), span),
+            FunctionValue::Partial {
+                function,
+                arguments: bound_arguments,
+            } => {
+                let mut all_arguments = bound_arguments.clone();
+                all_arguments.extend(arguments);
+                self.call(function, all_arguments, span)
+            }
+            FunctionValue::StaticMember { field, .. } => {
+                let receiver = arguments.remove(0);
+                match self.get_member(&receiver, field)? {
+                    Member::Field(value) => Ok(value),
+                    Member::Method(method) => self.call(&method, arguments, span),
+                }
+            }
+            FunctionValue::Variant { node, variant } => Ok(Value::Enum(Rc::new(EnumValue {
+                node,
+                variant,
+                value: arguments.pop().as_ref().map(Value::copy),
+            }))),
+        }
+    }
+
+    // Evaluates the body of a called function or closure, unless calls are nested too deeply
+    fn nested_call(
+        &self,
+        span: TokenSpan,
+        call: impl FnOnce() -> Evaluation<'a>,
+    ) -> Evaluation<'a> {
+        let depth = self.call_depth.get();
+        if depth >= MAX_CALL_DEPTH {
+            let error = RuntimeError::new(
+                span,
+                "Maximum call depth exceeded",
+                &format!("this call is nested more than {MAX_CALL_DEPTH} calls deep"),
+            );
+            return Err(error.into());
+        }
+
+        self.call_depth.set(depth + 1);
+        let result = call();
+        self.call_depth.set(depth);
+        result
+    }
+
+    pub fn call_function(
+        &self,
+        node: &'a FunctionNode,
+        receiver: &Receiver<'a>,
+        arguments: Vec<Value<'a>>,
+    ) -> Evaluation<'a> {
+        let mut environment = Environment::new();
+        match receiver {
+            Receiver::None => {}
+            Receiver::Bound(self_value) => {
+                environment = self.bind_receiver(&environment, self_value);
+            }
+            Receiver::Static(implementation) => {
+                environment = bind_static_methods(&environment, implementation);
+            }
+        }
+
+        for (parameter, argument) in node.signature.parameters.iter().zip(arguments) {
+            environment = environment.bind(&parameter.name.value, argument.copy());
+        }
+
+        let result = match &node.body.value {
+            FunctionBodyNode::Expression(expression) => {
+                self.evaluate_optional(expression, node.body.span, &environment)
+            }
+            FunctionBodyNode::Block(block) => self.evaluate_block(block, &environment),
+        };
+
+        catch_return(result)
+    }
+
+    fn call_closure(
+        &self,
+        node: &'a ClosureExpressionNode,
+        environment: &Environment<'a>,
+        arguments: Vec<Value<'a>>,
+    ) -> Evaluation<'a> {
+        let mut environment = environment.clone();
+        let mut arguments = arguments.into_iter();
+        for parameter in node.parameters.iter() {
+            let argument = match parameter {
+                Some(parameter) if parameter.is_spread => {
+                    Value::Tuple(Rc::new(arguments.by_ref().collect()))
+                }
+                _ => match arguments.next() {
+                    Some(argument) => argument,
+                    None => break,
+                },
+            };
+            if let Some(parameter) = parameter {
+                environment = environment.bind(&parameter.name.value, argument.copy());
+            }
+        }
+
+        catch_return(self.evaluate_optional(&node.body, node.body.span, &environment))
+    }
+
+    fn bind_receiver(
+        &self,
+        environment: &Environment<'a>,
+        self_value: &Value<'a>,
+    ) -> Environment<'a> {
+        let mut environment = environment.clone();
+        let implementation = match self_value {
+            Value::Struct(struct_value) => {
+                // Bound first so that members declared closer to the struct take precedence
+                for owner in promoted_owners(struct_value).iter().rev() {
+                    environment = self.bind_promoted(&environment, owner);
+                }
+                let node = struct_value.node;
+                let fields = struct_value.fields.borrow();
+                for (field, value) in node.fields.iter().zip(fields.iter()) {
+                    environment = environment.bind(&field.name.value, value.clone());
+                }
+                node.implementation.as_ref()
+            }
+            Value::Enum(enum_value) => enum_value.node.implementation.as_ref(),
+            _ => None,
+        };
+
+        for method in implementation.iter().flat_map(|node| methods(node)) {
+            let bound_method = FunctionValue::Function {
+                node: method,
+                receiver: Receiver::Bound(self_value.clone()),
+            };
+            environment = environment.bind(method.name(), Value::function(bound_method));
+        }
+        if let Some(implementation) = implementation {
+            environment = bind_static_methods(&environment, implementation);
+        }
+
+        environment.bind(SELF_VALUE, self_value.clone())
+    }
+
+    fn bind_promoted(
+        &self,
+        environment: &Environment<'a>,
+        owner: &Rc<StructValue<'a>>,
+    ) -> Environment<'a> {
+        let mut environment = environment.clone();
+        let fields = owner.fields.borrow();
+        for (field, value) in owner.node.fields.iter().zip(fields.iter()) {
+            if field.public {
+                environment = environment.bind(&field.name.value, value.clone());
+            }
+        }
+
+        let implementation = owner.node.implementation.iter();
+        for method in implementation.flat_map(public_methods) {
+            let bound_method = FunctionValue::Function {
+                node: method,
+                receiver: Receiver::Bound(Value::Struct(owner.clone())),
+            };
+            environment = environment.bind(method.name(), Value::function(bound_method));
+        }
+        environment
+    }
+
+    pub fn access_member(
+        &self,
+        receiver: &Value<'a>,
+        field: &'a NameNode,
+        arguments: Option<Vec<Value<'a>>>,
+        span: TokenSpan,
+    ) -> Evaluation<'a> {
+        let member = self.get_member(receiver, field)?.value();
+        match arguments {
+            Some(arguments) => self.call(&member, arguments, span),
+            None => Ok(member),
+        }
+    }
+
+    pub fn get_member(
+        &self,
+        receiver: &Value<'a>,
+        field: &'a NameNode,
+    ) -> Result<Member<'a>, RuntimeError> {
+        if let Value::Struct(struct_value) = receiver
+            && let Some(index) = struct_value.field_index(&field.value)
+        {
+            let value = struct_value.fields.borrow()[index].clone();
+            return Ok(Member::Field(value));
+        }
+
+        if let Value::Tuple(elements) = receiver
+            && let Some(index) = self.resolutions.get_tuple_index(field.span)
+        {
+            return Ok(Member::Field(elements[index].clone()));
+        }
+
+        if let Some(method) = self.get_method(receiver, &field.value) {
+            return Ok(Member::Method(method));
+        }
+
+        if let Value::Struct(struct_value) = receiver {
+            for owner in promoted_owners(struct_value) {
+                if let Some(index) = public_field_index(&owner, &field.value) {
+                    let value = owner.fields.borrow()[index].clone();
+                    return Ok(Member::Field(value));
+                }
+
+                let implementation = owner.node.implementation.iter();
+                let mut methods = implementation.flat_map(public_methods);
+                if let Some(method) = methods.find(|method| *method.name() == field.value) {
+                    let bound_method = FunctionValue::Function {
+                        node: method,
+                        receiver: Receiver::Bound(Value::Struct(owner.clone())),
+                    };
+                    return Ok(Member::Method(Value::function(bound_method)));
+                }
+            }
+        }
+
+        Err(RuntimeError::new(
+            field.span,
+            &format!("Could not find member `{field}`"),
+            &format!("value `{receiver}` has no such member"),
+        ))
+    }
+
+    // Finds a method of a struct or enum, bound to the receiver
+    pub fn get_method(&self, receiver: &Value<'a>, name: &str) -> Option<Value<'a>> {
+        let implementation = match receiver {
+            Value::Struct(struct_value) => struct_value.node.implementation.as_ref(),
+            Value::Enum(enum_value) => enum_value.node.implementation.as_ref(),
+            _ => None,
+        };
+
+        if let Some(method) = implementation.and_then(|node| find_method(node, name)) {
+            let bound_method = FunctionValue::Function {
+                node: method,
+                receiver: Receiver::Bound(receiver.clone()),
+            };
+            return Some(Value::function(bound_method));
+        }
+
+        // Enums may defer interface implementations to the values of their variants
+        match receiver {
+            Value::Enum(enum_value) => enum_value
+                .value
+                .as_ref()
+                .and_then(|value| self.get_method(value, name)),
+            _ => None,
+        }
+    }
+
+    pub fn set_field(
+        &self,
+        receiver: &Value<'a>,
+        field: &'a NameNode,
+        value: Value<'a>,
+    ) -> Result<(), RuntimeError> {
+        if let Value::Struct(struct_value) = receiver
+            && let Some(index) = struct_value.field_index(&field.value)
+        {
+            struct_value.fields.borrow_mut()[index] = value;
+            return Ok(());
+        }
+
+        if let Value::Struct(struct_value) = receiver {
+            for owner in promoted_owners(struct_value) {
+                if let Some(index) = public_field_index(&owner, &field.value) {
+                    owner.fields.borrow_mut()[index] = value;
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(RuntimeError::new(
+            field.span,
+            &format!("Cannot assign to member `{field}`"),
+            &format!("value `{receiver}` has no such field"),
+        ))
+    }
+
+    pub fn index(
+        &self,
+        elements: &RefCell<Vec<Value<'a>>>,
+        arguments: &[Value<'a>],
+        span: TokenSpan,
+    ) -> Result<Value<'a>, RuntimeError> {
+        let index = self.get_index(elements, arguments, span)?;
+        Ok(elements.borrow()[index].clone())
+    }
+
+    pub fn get_index(
+        &self,
+        elements: &RefCell<Vec<Value<'a>>>,
+        arguments: &[Value<'a>],
+        span: TokenSpan,
+    ) -> Result<usize, RuntimeError> {
+        let [Value::Int(index)] = arguments else {
+            return Err(RuntimeError::new(
+                span,
+                "Invalid array index",
+                "arrays must be called with a single integer",
+            ));
+        };
+
+        let length = elements.borrow().len();
+        match usize::try_from(*index) {
+            Ok(index) if index < length => Ok(index),
+            _ => Err(RuntimeError::new(
+                span,
+                "Array index out of bounds",
+                &format!("index {index} is out of bounds for array of length {length}"),
+            )),
+        }
+    }
+
+    pub fn variant(&self, node: &'a EnumNode, name: &str) -> Option<Value<'a>> {
+        let variant = node
+            .variants
+            .iter()
+            .find(|variant| variant.name.value == name)?;
+        let variant_name = variant.name.value.as_str();
+        if variant.type_def.is_some() {
+            let constructor = FunctionValue::Variant {
+                node,
+                variant: variant_name,
+            };
+            Some(Value::function(constructor))
+        } else {
+            Some(Value::Enum(Rc::new(EnumValue {
+                node,
+                variant: variant_name,
+                value: None,
+            })))
+        }
+    }
+}
+
+fn prelude_definition<'a>(prelude: &'a ProgramNode, name: &str) -> &'a ModuleDefinitionNode {
+    prelude
+        .definitions
+        .iter()
+        .map(|definition| &definition.definition)
+        .find(|definition| definition.name().value == name)
+        .unwrap_or_else(|| panic!("prelude should declare `{name}`"))
+}
+
+fn catch_return(result: Evaluation<'_>) -> Evaluation<'_> {
+    match result {
+        Err(ControlFlow::Return(value)) => Ok(value),
+        result => result,
+    }
+}
+
+fn bind_static_methods<'a>(
+    environment: &Environment<'a>,
+    implementation: &'a Node<ImplementationNode>,
+) -> Environment<'a> {
+    let mut environment = environment.clone();
+    for method in static_methods(implementation) {
+        let static_method = FunctionValue::Function {
+            node: method,
+            receiver: Receiver::Static(implementation),
+        };
+        environment = environment.bind(method.name(), Value::function(static_method));
+    }
+    environment
+}
+
+// The methods called on an instance, including those implementing interfaces
+fn methods(implementation: &Node<ImplementationNode>) -> impl Iterator<Item = &FunctionNode> {
+    implementation
+        .entries
+        .iter()
+        .flat_map(|entry| match &entry.value {
+            ImplementationEntryNode::Method(method) if method.is_static => vec![],
+            ImplementationEntryNode::Method(method) => vec![&method.function.value],
+            ImplementationEntryNode::Interface(interface) => interface
+                .methods
+                .iter()
+                .flatten()
+                .map(|method| &method.value)
+                .collect(),
+        })
+}
+
+// Methods which may be promoted into a struct spreading the implementing struct
+fn public_methods(
+    implementation: &Node<ImplementationNode>,
+) -> impl Iterator<Item = &FunctionNode> {
+    implementation
+        .entries
+        .iter()
+        .flat_map(|entry| match &entry.value {
+            ImplementationEntryNode::Method(method) if method.is_static || !method.public => {
+                vec![]
+            }
+            ImplementationEntryNode::Method(method) => vec![&method.function.value],
+            ImplementationEntryNode::Interface(interface) => interface
+                .methods
+                .iter()
+                .flatten()
+                .map(|method| &method.value)
+                .collect(),
+        })
+}
+
+// The values of spread fields whose members are promoted into a struct, in order of precedence
+fn promoted_owners<'a>(struct_value: &StructValue<'a>) -> Vec<Rc<StructValue<'a>>> {
+    let mut owners = vec![];
+    collect_promoted_owners(struct_value, false, &mut owners);
+    owners
+}
+
+// Nested spread fields are only promoted through when they are public
+fn collect_promoted_owners<'a>(
+    struct_value: &StructValue<'a>,
+    public_only: bool,
+    owners: &mut Vec<Rc<StructValue<'a>>>,
+) {
+    let fields = struct_value.fields.borrow();
+    for (field, value) in struct_value.node.fields.iter().zip(fields.iter()) {
+        if let Value::Struct(owner) = value
+            && field.is_spread
+            && (field.public || !public_only)
+        {
+            owners.push(owner.clone());
+            collect_promoted_owners(owner, true, owners);
+        }
+    }
+}
+
+fn public_field_index(owner: &StructValue, name: &str) -> Option<usize> {
+    owner
+        .field_index(name)
+        .filter(|index| owner.node.fields[*index].public)
+}
+
+pub fn static_methods(
+    implementation: &Node<ImplementationNode>,
+) -> impl Iterator<Item = &FunctionNode> {
+    implementation
+        .entries
+        .iter()
+        .filter_map(|entry| match &entry.value {
+            ImplementationEntryNode::Method(method) if method.is_static => {
+                Some(&method.function.value)
+            }
+            _ => None,
+        })
+}
+
+fn find_method<'a>(
+    implementation: &'a Node<ImplementationNode>,
+    name: &str,
+) -> Option<&'a FunctionNode> {
+    methods(implementation).find(|method| method.name() == name)
+}
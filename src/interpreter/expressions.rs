@@ -0,0 +1,540 @@
+use std::rc::Rc;
+
+use crate::{
+    checker::{NONE_VARIANT, SOME_VARIANT, Type},
+    interpreter::{
+        ControlFlow, EnumValue, Environment, Evaluation, FunctionValue, Interpreter, Receiver,
+        RuntimeError, SELF_VALUE, TypeDefinition, Value, static_methods,
+    },
+    parser::{
+        ExpressionNode, MatchNode, MatchPatternNode, MemberTypeExpressionNode,
+        MemberValueExpressionNode, NameNode, Node, StructNode, TokenSpan, has_field_arguments,
+        has_named_arguments,
+    },
+};
+
+const ESCAPE: char = '\\';
+
+impl<'a> Interpreter<'a> {
+    pub fn evaluate(
+        &self,
+        expression: &'a ExpressionNode,
+        span: TokenSpan,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        match expression {
+            ExpressionNode::Array(node) => {
+                let elements = self.evaluate_list(&node.elements, environment)?;
+                Ok(Value::array(elements.iter().map(Value::copy).collect()))
+            }
+            ExpressionNode::BinaryOp(node) => self.evaluate_binary_op(node, span, environment),
+            ExpressionNode::Block(node) => self.evaluate_block(node, environment),
+            ExpressionNode::BooleanLiteral(value) => Ok(Value::Bool(*value)),
+            ExpressionNode::Cast(node) => self.evaluate_cast(node, environment),
+            ExpressionNode::CharacterLiteral(literal) => {
+                let character = unescape(literal, span)?;
+                let mut characters = character.chars();
+                match (characters.next(), characters.next()) {
+                    (Some(character), None) => Ok(Value::Char(character)),
+                    _ => Err(RuntimeError::new(
+                        span,
+                        "Invalid character literal",
+                        "expected exactly one character",
+                    )
+                    .into()),
+                }
+            }
+            ExpressionNode::Closure(node) => Ok(Value::function(FunctionValue::Closure {
+                node,
+                environment: environment.clone(),
+            })),
+            ExpressionNode::DeferredMember(node) => {
+                let arguments = self.evaluate_optional_list(&node.arguments, environment)?;
+                Ok(Value::function(FunctionValue::Member {
+                    field: &node.field,
+                    arguments,
+                }))
+            }
+            ExpressionNode::FunctionCall(node) => {
+                let function = self.evaluate_node(&node.function, environment)?;
+                let arguments = match &function {
+                    Value::Function(constructor)
+                        if let FunctionValue::Constructor(struct_node) = constructor.as_ref()
+                            && has_field_arguments(&node.arguments) =>
+                    {
+                        self.evaluate_field_arguments(
+                            struct_node,
+                            &node.arguments,
+                            environment,
+                            span,
+                        )?
+                    }
+                    _ if has_named_arguments(&node.arguments) => {
+                        return Err(RuntimeError::new(
+                            span,
+                            "Unexpected named argument",
+                            "named arguments must be arguments of a constructor call",
+                        )
+                        .into());
+                    }
+                    _ => self.evaluate_list(&node.arguments, environment)?,
+                };
+                // Variadic closures take any number of arguments, so partial application is
+                // decided by the checker
+                if self.resolutions.is_partial_application(node.function.span) {
+                    let partial = FunctionValue::Partial {
+                        function,
+                        arguments,
+                    };
+                    return Ok(Value::function(partial));
+                }
+                self.call(&function, arguments, span)
+            }
+            ExpressionNode::IfExpression(node) => {
+                if self.evaluate_predicate(&node.predicate, environment)? {
+                    self.evaluate_node(&node.if_true, environment)
+                } else {
+                    self.evaluate_node(&node.if_false, environment)
+                }
+            }
+            ExpressionNode::FloatLiteral(value) => Ok(Value::Float(value.value)),
+            ExpressionNode::IntegerLiteral(value) => Ok(Value::Int(value.value)),
+            ExpressionNode::Match(node) => self.evaluate_match(node, environment),
+            ExpressionNode::MemberType(node) => self.evaluate_member_type(node),
+            ExpressionNode::MemberValue(node) => {
+                self.evaluate_member_value(node, span, environment)
+            }
+            ExpressionNode::Name(name) => self.evaluate_name(name, environment),
+            ExpressionNode::PostfixOp(node) => self.evaluate_postfix_op(node, environment),
+            ExpressionNode::PrefixOp(node) => self.evaluate_prefix_op(node, environment),
+            ExpressionNode::SelfRef(name) => {
+                let self_value = self.evaluate_self(name.span, environment)?;
+                Ok(self.get_member(&self_value, name)?.value())
+            }
+            ExpressionNode::SelfValue(span) => self.evaluate_self(*span, environment),
+            ExpressionNode::StringLiteral(literal) => {
+                let characters = unescape(literal, span)?.chars().map(Value::Char).collect();
+                Ok(Value::array(characters))
+            }
+            ExpressionNode::Tuple(node) => {
+                let mut elements = vec![];
+                for expression in node.expressions.iter() {
+                    self.evaluate_element(expression, environment, &mut elements)?;
+                }
+                Ok(Value::Tuple(Rc::new(
+                    elements.iter().map(Value::copy).collect(),
+                )))
+            }
+            ExpressionNode::TypeBinding(node) => self.evaluate_node(&node.left, environment),
+            ExpressionNode::ClosureParameter(_)
+            | ExpressionNode::NamedArgument(_)
+            | ExpressionNode::Spread(_)
+            | ExpressionNode::Error => {
+                Err(
+                    RuntimeError::new(span, "Cannot evaluate expression", "invalid expression")
+                        .into(),
+                )
+            }
+        }
+    }
+
+    pub fn evaluate_node(
+        &self,
+        node: &'a Node<ExpressionNode>,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        self.evaluate(&node.value, node.span, environment)
+    }
+
+    // Evaluates an expression within which `?` can short circuit, yielding `None` if it does
+    pub fn evaluate_optional(
+        &self,
+        expression: &'a ExpressionNode,
+        span: TokenSpan,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        match self.evaluate(expression, span, environment) {
+            Err(ControlFlow::ShortCircuit) => Ok(self.optional(NONE_VARIANT, None)),
+            Ok(value) if self.resolutions.is_short_circuit(span) => {
+                Ok(self.optional(SOME_VARIANT, Some(value)))
+            }
+            result => result,
+        }
+    }
+
+    fn optional(&self, variant: &'static str, value: Option<Value<'a>>) -> Value<'a> {
+        Value::Enum(Rc::new(EnumValue {
+            node: self.option,
+            variant,
+            value,
+        }))
+    }
+
+    pub fn evaluate_predicate(
+        &self,
+        node: &'a Node<ExpressionNode>,
+        environment: &Environment<'a>,
+    ) -> Result<bool, ControlFlow<'a>> {
+        match self.evaluate_node(node, environment)? {
+            Value::Bool(value) => Ok(value),
+            value => Err(RuntimeError::new(
+                node.span,
+                "Expected a boolean value",
+                &format!("found value `{value}`"),
+            )
+            .into()),
+        }
+    }
+
+    fn evaluate_list(
+        &self,
+        expressions: &'a [Node<ExpressionNode>],
+        environment: &Environment<'a>,
+    ) -> Result<Vec<Value<'a>>, ControlFlow<'a>> {
+        let mut values = vec![];
+        for expression in expressions.iter() {
+            self.evaluate_element(expression, environment, &mut values)?;
+        }
+        Ok(values)
+    }
+
+    // Orders the arguments of a constructor call with named arguments or a leading spread by
+    // field, taking the fields not given from a leading spread struct
+    fn evaluate_field_arguments(
+        &self,
+        struct_node: &'a StructNode,
+        arguments: &'a [Node<ExpressionNode>],
+        environment: &Environment<'a>,
+        span: TokenSpan,
+    ) -> Result<Vec<Value<'a>>, ControlFlow<'a>> {
+        let mut fields = vec![None; struct_node.fields.len()];
+        let mut position = 0;
+        for argument in arguments {
+            match &argument.value {
+                ExpressionNode::Spread(spread) => {
+                    match self.evaluate_node(&spread.expression, environment)? {
+                        Value::Struct(base) => {
+                            let values = base.fields.borrow();
+                            for (field, value) in fields.iter_mut().zip(values.iter()) {
+                                *field = Some(value.clone());
+                            }
+                        }
+                        // A leading tuple gives the first fields by position
+                        Value::Tuple(elements) => {
+                            for (field, value) in fields.iter_mut().zip(elements.iter()) {
+                                *field = Some(value.clone());
+                            }
+                            position = elements.len();
+                        }
+                        _ => {}
+                    }
+                }
+                ExpressionNode::NamedArgument(named) => {
+                    let value = self.evaluate_node(&named.value, environment)?;
+                    let index = struct_node
+                        .fields
+                        .iter()
+                        .position(|field| field.name.value == named.name.value);
+                    if let Some(field) = index.and_then(|index| fields.get_mut(index)) {
+                        *field = Some(value);
+                    }
+                }
+                _ => {
+                    let value = self.evaluate_node(argument, environment)?;
+                    if let Some(field) = fields.get_mut(position) {
+                        *field = Some(value);
+                    }
+                    position += 1;
+                }
+            }
+        }
+
+        fields
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                RuntimeError::new(
+                    span,
+                    "Missing field arguments",
+                    "not every field was given a value",
+                )
+                .into()
+            })
+    }
+
+    fn evaluate_optional_list(
+        &self,
+        expressions: &'a Option<Node<Vec<Node<ExpressionNode>>>>,
+        environment: &Environment<'a>,
+    ) -> Result<Option<Vec<Value<'a>>>, ControlFlow<'a>> {
+        expressions
+            .as_ref()
+            .map(|expressions| self.evaluate_list(expressions, environment))
+            .transpose()
+    }
+
+    // Evaluates a list element, splicing the values of spread tuples into the list
+    fn evaluate_element(
+        &self,
+        expression: &'a Node<ExpressionNode>,
+        environment: &Environment<'a>,
+        values: &mut Vec<Value<'a>>,
+    ) -> Result<(), ControlFlow<'a>> {
+        let ExpressionNode::Spread(spread) = &expression.value else {
+            values.push(self.evaluate_node(expression, environment)?);
+            return Ok(());
+        };
+
+        match self.evaluate_node(&spread.expression, environment)? {
+            Value::Tuple(elements) => values.extend(elements.iter().cloned()),
+            value => values.push(value),
+        }
+        Ok(())
+    }
+
+    fn evaluate_name(&self, name: &'a NameNode, environment: &Environment<'a>) -> Evaluation<'a> {
+        if let Some(value) = environment.get(name) {
+            return Ok(value);
+        }
+
+        let resolved_value = match self.resolutions.get(name.span) {
+            Some(Type::Enum(enum_type)) => match self.types.get(&enum_type.name_span().start_index)
+            {
+                Some(TypeDefinition::Enum(node)) => self.variant(node, name),
+                _ => None,
+            },
+            Some(Type::Struct(struct_type)) => {
+                match self.types.get(&struct_type.name_span().start_index) {
+                    Some(TypeDefinition::Struct(node)) => {
+                        Some(Value::function(FunctionValue::Constructor(node)))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(value) = resolved_value.or_else(|| self.get_global(name, name.span)) {
+            return Ok(value);
+        }
+
+        Err(RuntimeError::new(
+            name.span,
+            &format!("Could not find value `{name}`"),
+            "no such value at runtime",
+        )
+        .into())
+    }
+
+    pub fn evaluate_self(&self, span: TokenSpan, environment: &Environment<'a>) -> Evaluation<'a> {
+        environment.get(SELF_VALUE).ok_or_else(|| {
+            RuntimeError::new(span, "Could not find `self`", "no receiver at runtime").into()
+        })
+    }
+
+    fn evaluate_member_type(&self, node: &'a MemberTypeExpressionNode) -> Evaluation<'a> {
+        if let Some(Type::Enum(enum_type)) = self.resolutions.get(node.left.span)
+            && let Some(TypeDefinition::Enum(enum_node)) =
+                self.types.get(&enum_type.name_span().start_index)
+            && let Some(variant) = self.variant(enum_node, &node.field)
+        {
+            return Ok(variant);
+        }
+
+        if let Some(value) = self.static_method(node) {
+            return Ok(value);
+        }
+
+        let arity = match self.resolutions.get(node.field.span) {
+            Some(Type::Function(function_type)) => function_type.parameters.len(),
+            _ => 1,
+        };
+
+        Ok(Value::function(FunctionValue::StaticMember {
+            field: &node.field,
+            arity,
+        }))
+    }
+
+    fn static_method(&self, node: &'a MemberTypeExpressionNode) -> Option<Value<'a>> {
+        let name_span = match self.resolutions.get(node.left.span)? {
+            Type::Enum(enum_type) => enum_type.name_span(),
+            Type::Struct(struct_type) => struct_type.name_span(),
+            _ => return None,
+        };
+        let implementation = match self.types.get(&name_span.start_index)? {
+            TypeDefinition::Enum(enum_node) => enum_node.implementation.as_ref()?,
+            TypeDefinition::Struct(struct_node) => struct_node.implementation.as_ref()?,
+        };
+
+        let method =
+            static_methods(implementation).find(|method| *method.name() == node.field.value)?;
+        Some(Value::function(FunctionValue::Function {
+            node: method,
+            receiver: Receiver::Static(implementation),
+        }))
+    }
+
+    fn evaluate_member_value(
+        &self,
+        node: &'a MemberValueExpressionNode,
+        span: TokenSpan,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let receiver = self.evaluate_node(&node.left, environment)?;
+        let arguments = self.evaluate_optional_list(&node.arguments, environment)?;
+
+        // Member access on a function applies to the result of calling it
+        if receiver.is_callable() {
+            return Ok(Value::function(FunctionValue::Deferred {
+                function: receiver,
+                field: &node.field,
+                arguments,
+            }));
+        }
+
+        self.access_member(&receiver, &node.field, arguments, span)
+    }
+
+    pub fn evaluate_match(
+        &self,
+        node: &'a MatchNode,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let subject = self.evaluate_node(&node.subject, environment)?;
+        for case in node.cases.iter() {
+            if let Some(environment) = self.match_pattern(&case.pattern, &subject, environment)? {
+                return self.evaluate_node(&case.if_match, &environment);
+            }
+        }
+
+        Err(RuntimeError::new(
+            node.subject.span,
+            "No match case applies",
+            &format!("value `{subject}` was not matched"),
+        )
+        .into())
+    }
+
+    // Returns the environment extended with the pattern's bindings, or `None` if the value
+    // does not match
+    pub fn match_pattern(
+        &self,
+        pattern: &'a Node<MatchPatternNode>,
+        value: &Value<'a>,
+        environment: &Environment<'a>,
+    ) -> Result<Option<Environment<'a>>, ControlFlow<'a>> {
+        match (&pattern.value, value) {
+            (MatchPatternNode::Wildcard | MatchPatternNode::Else, _) => {
+                Ok(Some(environment.clone()))
+            }
+            (MatchPatternNode::Binding(name), _) => Ok(Some(environment.bind(name, value.copy()))),
+            (MatchPatternNode::Literal(literal), _) => {
+                let literal = self.evaluate(literal, pattern.span, environment)?;
+                Ok(literal.equals(value).then(|| environment.clone()))
+            }
+            (MatchPatternNode::Variant(variant_pattern), Value::Enum(enum_value)) => {
+                if enum_value.variant != variant_pattern.name.value {
+                    return Ok(None);
+                }
+
+                match (&variant_pattern.inner_pattern, &enum_value.value) {
+                    (Some(inner_pattern), Some(inner_value)) => {
+                        self.match_pattern(inner_pattern, inner_value, environment)
+                    }
+                    (Some(_), None) => Ok(None),
+                    (None, _) => Ok(Some(environment.clone())),
+                }
+            }
+            (MatchPatternNode::Variant(variant_pattern), Value::Struct(struct_value)) => {
+                if struct_value.node.name.value != variant_pattern.name.value {
+                    return Ok(None);
+                }
+                let patterns = variant_pattern.inner_pattern.as_deref();
+                let patterns = patterns.map(std::slice::from_ref).unwrap_or_default();
+                self.match_patterns(patterns, &struct_value.fields.borrow(), environment)
+            }
+            (MatchPatternNode::Struct(struct_pattern), Value::Struct(struct_value)) => {
+                if struct_value.node.name.value != struct_pattern.name.value {
+                    return Ok(None);
+                }
+                let fields = struct_value.fields.borrow();
+                self.match_patterns(&struct_pattern.fields, &fields, environment)
+            }
+            (MatchPatternNode::NamedStruct(struct_pattern), Value::Struct(struct_value)) => {
+                if struct_value.node.name.value != struct_pattern.name.value {
+                    return Ok(None);
+                }
+
+                let fields = struct_value.fields.borrow();
+                let mut environment = environment.clone();
+                for field in &struct_pattern.fields {
+                    let Some(index) = struct_value.field_index(&field.name.value) else {
+                        return Ok(None);
+                    };
+                    let Some(bound) =
+                        self.match_pattern(&field.pattern, &fields[index], &environment)?
+                    else {
+                        return Ok(None);
+                    };
+                    environment = bound;
+                }
+                Ok(Some(environment))
+            }
+            (MatchPatternNode::Tuple(patterns), Value::Tuple(elements)) => {
+                self.match_patterns(patterns, elements, environment)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn match_patterns(
+        &self,
+        patterns: &'a [Node<MatchPatternNode>],
+        values: &[Value<'a>],
+        environment: &Environment<'a>,
+    ) -> Result<Option<Environment<'a>>, ControlFlow<'a>> {
+        if patterns.len() != values.len() {
+            return Ok(None);
+        }
+
+        let mut environment = environment.clone();
+        for (pattern, value) in patterns.iter().zip(values.iter()) {
+            let Some(bound) = self.match_pattern(pattern, value, &environment)? else {
+                return Ok(None);
+            };
+            environment = bound;
+        }
+
+        Ok(Some(environment))
+    }
+}
+
+fn unescape(literal: &str, span: TokenSpan) -> Result<String, RuntimeError> {
+    let mut result = String::new();
+    let mut characters = literal.chars();
+    while let Some(character) = characters.next() {
+        if character != ESCAPE {
+            result.push(character);
+            continue;
+        }
+
+        let escaped = match characters.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some(character @ ('\\' | '\'' | '"')) => character,
+            _ => {
+                return Err(RuntimeError::new(
+                    span,
+                    "Invalid escape sequence",
+                    "unknown escape sequence in literal",
+                ));
+            }
+        };
+        result.push(escaped);
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,12 @@
+mod environment;
+mod evaluator;
+mod expressions;
+mod operators;
+mod runtime_error;
+mod statements;
+mod value;
+
+pub use environment::*;
+pub use evaluator::*;
+pub use runtime_error::*;
+pub use value::*;
@@ -0,0 +1,468 @@
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
+
+use crate::{
+    checker::{OperatorInterface, SOME_VARIANT},
+    interpreter::{
+        ControlFlow, Environment, Evaluation, Interpreter, RuntimeError, StructValue, Value,
+    },
+    parser::{
+        BinaryOpExpressionNode, BinaryOperator, CastExpressionNode, ExpressionNode, NameNode, Node,
+        Operator, PostfixOpExpressionNode, PostfixOperator, PrefixOpExpressionNode, PrefixOperator,
+        PrimitiveType, TokenSpan, TypeNode,
+    },
+};
+
+impl<'a> Interpreter<'a> {
+    pub fn evaluate_binary_op(
+        &self,
+        node: &'a BinaryOpExpressionNode,
+        span: TokenSpan,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let operator = node.operator.value;
+        match operator {
+            BinaryOperator::LogicalAnd => Ok(Value::Bool(
+                self.evaluate_predicate(&node.left, environment)?
+                    && self.evaluate_predicate(&node.right, environment)?,
+            )),
+            BinaryOperator::LogicalOr => Ok(Value::Bool(
+                self.evaluate_predicate(&node.left, environment)?
+                    || self.evaluate_predicate(&node.right, environment)?,
+            )),
+            BinaryOperator::Assign => {
+                let value =
+                    self.evaluate_optional(&node.right.value, node.right.span, environment)?;
+                self.assign(&node.left, value.clone(), environment)?;
+                Ok(value)
+            }
+            BinaryOperator::AddAssign
+            | BinaryOperator::SubtractAssign
+            | BinaryOperator::MultiplyAssign
+            | BinaryOperator::DivideAssign
+            | BinaryOperator::ModAssign => {
+                // The target is resolved once, so its receiver and index are evaluated once
+                let place = self.resolve_place(&node.left, environment)?;
+                let left = self.read_place(&place, &node.left, environment)?;
+                let right = self.evaluate_node(&node.right, environment)?;
+                let value = self.apply(node, base_operator(operator), &left, right, span)?;
+                self.write_place(place, value.clone(), environment, node.left.span)?;
+                Ok(value)
+            }
+            BinaryOperator::Range | BinaryOperator::RangeInclusive => {
+                let start = self.evaluate_node(&node.left, environment)?;
+                let end = self.evaluate_node(&node.right, environment)?;
+                self.range(node, &start, &end)
+            }
+            BinaryOperator::FunctionApplication => {
+                let argument = self.evaluate_node(&node.left, environment)?;
+                let function = self.evaluate_node(&node.right, environment)?;
+                self.call(&function, vec![argument], span)
+            }
+            _ => {
+                let left = self.evaluate_node(&node.left, environment)?;
+                let right = self.evaluate_node(&node.right, environment)?;
+                self.apply(node, operator, &left, right, span)
+            }
+        }
+    }
+
+    fn apply(
+        &self,
+        node: &'a BinaryOpExpressionNode,
+        operator: BinaryOperator,
+        left: &Value<'a>,
+        right: Value<'a>,
+        span: TokenSpan,
+    ) -> Evaluation<'a> {
+        // Structs and enums implementing an operator interface overload the operator
+        if let Some(interface) = OperatorInterface::from_binary(operator)
+            && let Some(method) = self.get_method(left, interface.method())
+        {
+            let result = self.call(&method, vec![right], span)?;
+            return Ok(match (operator, result) {
+                (BinaryOperator::NotEqual, Value::Bool(equal)) => Value::Bool(!equal),
+                (_, Value::Int(ordering)) if interface == OperatorInterface::Compare => {
+                    Value::Bool(is_ordered(operator, ordering.cmp(&0)))
+                }
+                (_, result) => result,
+            });
+        }
+
+        match operator {
+            BinaryOperator::Equal => Ok(Value::Bool(left.equals(&right))),
+            BinaryOperator::NotEqual => Ok(Value::Bool(!left.equals(&right))),
+            BinaryOperator::LessThan
+            | BinaryOperator::LessThanOrEqual
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterThanOrEqual => compare(node, left, &right),
+            _ => {
+                let integer_type = self.resolutions.get_integer_type(node.operator.span);
+                arithmetic(node, operator, left, &right, integer_type)
+            }
+        }
+    }
+
+    // Ranges are values of the prelude's `Range` struct, which excludes its end
+    fn range(
+        &self,
+        node: &'a BinaryOpExpressionNode,
+        start: &Value<'a>,
+        end: &Value<'a>,
+    ) -> Evaluation<'a> {
+        let (Value::Int(start), Value::Int(end)) = (start, end) else {
+            return Err(RuntimeError::new(
+                node.operator.span,
+                &format!("Invalid range for `{}`", node.operator.as_token()),
+                &format!("cannot create a range from `{start}` to `{end}`"),
+            )
+            .into());
+        };
+
+        let end = match node.operator.value {
+            BinaryOperator::RangeInclusive => {
+                Some(end + 1).filter(|end| fits_integer(*end, PrimitiveType::Int))
+            }
+            _ => Some(*end),
+        };
+        let Some(end) = end else {
+            return Err(RuntimeError::new(
+                node.right.span,
+                "Range end out of bounds",
+                "inclusive ranges must end before the maximum `int`",
+            )
+            .into());
+        };
+
+        Ok(Value::Struct(Rc::new(StructValue {
+            node: self.range,
+            fields: RefCell::new(vec![Value::Int(*start), Value::Int(end)]),
+        })))
+    }
+
+    pub fn evaluate_prefix_op(
+        &self,
+        node: &'a PrefixOpExpressionNode,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let value = self.evaluate_node(&node.expression, environment)?;
+        let interface = OperatorInterface::from_prefix(node.operator.value);
+        if let Some(method) = self.get_method(&value, interface.method()) {
+            return self.call(&method, vec![], node.operator.span);
+        }
+
+        let result = match (node.operator.value, &value) {
+            (PrefixOperator::LogicalNot, Value::Bool(value)) => Some(Value::Bool(!value)),
+            (PrefixOperator::Negative, Value::Int(value)) => {
+                let integer_type = self.resolutions.get_integer_type(node.operator.span);
+                if !fits_integer(-value, integer_type) {
+                    return Err(RuntimeError::new(
+                        node.operator.span,
+                        &format!("Invalid operand for `{}`", node.operator.as_token()),
+                        &format!("result does not fit in `{integer_type}`"),
+                    )
+                    .into());
+                }
+                Some(Value::Int(-value))
+            }
+            (PrefixOperator::Negative, Value::Float(value)) => Some(Value::Float(-value)),
+            _ => None,
+        };
+
+        result.ok_or_else(|| {
+            RuntimeError::new(
+                node.operator.span,
+                &format!("Invalid operand for `{}`", node.operator.as_token()),
+                &format!("cannot apply to value `{value}`"),
+            )
+            .into()
+        })
+    }
+
+    pub fn evaluate_postfix_op(
+        &self,
+        node: &'a PostfixOpExpressionNode,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let value = self.evaluate_node(&node.expression, environment)?;
+        match (node.operator.value, &value) {
+            (PostfixOperator::NullShortCircuit, Value::Enum(enum_value)) => {
+                match &enum_value.value {
+                    Some(inner_value) if enum_value.variant == SOME_VARIANT => {
+                        Ok(inner_value.clone())
+                    }
+                    _ => Err(ControlFlow::ShortCircuit),
+                }
+            }
+            _ => Err(RuntimeError::new(
+                node.operator.span,
+                &format!("Invalid operand for `{}`", node.operator.as_token()),
+                &format!("cannot apply to value `{value}`"),
+            )
+            .into()),
+        }
+    }
+
+    pub fn evaluate_cast(
+        &self,
+        node: &'a CastExpressionNode,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let value = self.evaluate_node(&node.expression, environment)?;
+        let TypeNode::Primitive(target) = node.target.value else {
+            return Ok(value);
+        };
+
+        let result = match value {
+            Value::Bool(value) => convert_integer(i128::from(value), target),
+            Value::Char(value) => convert_integer(i128::from(u32::from(value)), target),
+            Value::Float(value) => convert_float(value, target),
+            Value::Int(value) => convert_integer(value, target),
+            _ => None,
+        };
+
+        result.ok_or_else(|| {
+            RuntimeError::new(
+                node.target.span,
+                &format!("Invalid cast to `{target}`"),
+                &format!("cannot cast value `{value}`"),
+            )
+            .into()
+        })
+    }
+
+    fn assign(
+        &self,
+        target: &'a Node<ExpressionNode>,
+        value: Value<'a>,
+        environment: &Environment<'a>,
+    ) -> Result<(), ControlFlow<'a>> {
+        let place = self.resolve_place(target, environment)?;
+        self.write_place(place, value, environment, target.span)
+    }
+
+    fn resolve_place(
+        &self,
+        target: &'a Node<ExpressionNode>,
+        environment: &Environment<'a>,
+    ) -> Result<Place<'a>, ControlFlow<'a>> {
+        match &target.value {
+            ExpressionNode::Name(name) => Ok(Place::Name(name)),
+            ExpressionNode::SelfRef(name) => {
+                let receiver = self.evaluate_self(name.span, environment)?;
+                Ok(Place::Field(receiver, name))
+            }
+            ExpressionNode::MemberValue(node) if node.arguments.is_none() => {
+                let receiver = self.evaluate_node(&node.left, environment)?;
+                Ok(Place::Field(receiver, &node.field))
+            }
+            ExpressionNode::FunctionCall(node) => {
+                let Value::Array(elements) = self.evaluate_node(&node.function, environment)?
+                else {
+                    return Err(invalid_assignment(target.span).into());
+                };
+                let arguments = node
+                    .arguments
+                    .iter()
+                    .map(|argument| self.evaluate_node(argument, environment))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let index = self.get_index(&elements, &arguments, target.span)?;
+                Ok(Place::Element(elements, index))
+            }
+            _ => Err(invalid_assignment(target.span).into()),
+        }
+    }
+
+    fn read_place(
+        &self,
+        place: &Place<'a>,
+        target: &'a Node<ExpressionNode>,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        match place {
+            Place::Name(_) => self.evaluate_node(target, environment),
+            Place::Field(receiver, field) => Ok(self.get_member(receiver, field)?.value()),
+            Place::Element(elements, index) => Ok(elements.borrow()[*index].clone()),
+        }
+    }
+
+    fn write_place(
+        &self,
+        place: Place<'a>,
+        value: Value<'a>,
+        environment: &Environment<'a>,
+        span: TokenSpan,
+    ) -> Result<(), ControlFlow<'a>> {
+        let value = value.copy();
+        match place {
+            Place::Name(name) if environment.assign(name, value.clone()) => Ok(()),
+            Place::Name(_) => Err(invalid_assignment(span).into()),
+            Place::Field(receiver, field) => Ok(self.set_field(&receiver, field, value)?),
+            Place::Element(elements, index) => {
+                elements.borrow_mut()[index] = value;
+                Ok(())
+            }
+        }
+    }
+}
+
+// The location written by an assignment, with its receiver or array and index already evaluated
+enum Place<'a> {
+    Name(&'a NameNode),
+    Field(Value<'a>, &'a NameNode),
+    Element(Rc<RefCell<Vec<Value<'a>>>>, usize),
+}
+
+// Integers wrap around to fit within the target width, as in two's complement
+fn convert_integer<'a>(value: i128, target: PrimitiveType) -> Option<Value<'a>> {
+    match target {
+        PrimitiveType::Char => u8::try_from(value)
+            .ok()
+            .map(|value| Value::Char(value.into())),
+        PrimitiveType::F32 => Some(Value::Float(f64::from(value as f32))),
+        PrimitiveType::F64 | PrimitiveType::Float => Some(Value::Float(value as f64)),
+        _ => Some(Value::Int(wrap_integer(value, target))),
+    }
+}
+
+// Floats are truncated towards zero and saturate at the bounds of integer types
+fn convert_float<'a>(value: f64, target: PrimitiveType) -> Option<Value<'a>> {
+    if target == PrimitiveType::F32 {
+        return Some(Value::Float(f64::from(value as f32)));
+    }
+    if target.is_float() {
+        return Some(Value::Float(value));
+    }
+
+    let (min, max) = target.get_range()?;
+    let value = (value as i128).clamp(min, max);
+    Some(Value::Int(wrap_integer(value, target)))
+}
+
+fn wrap_integer(value: i128, target: PrimitiveType) -> i128 {
+    match (target.get_bits(), target.is_signed()) {
+        (Some(bits), true) => {
+            let shift = 128 - bits;
+            (value << shift) >> shift
+        }
+        (Some(bits), false) => value & ((1 << bits) - 1),
+        _ => value,
+    }
+}
+
+fn fits_integer(value: i128, integer_type: PrimitiveType) -> bool {
+    integer_type
+        .get_range()
+        .is_some_and(|(min, max)| (min..=max).contains(&value))
+}
+
+fn invalid_assignment(span: TokenSpan) -> RuntimeError {
+    RuntimeError::new(
+        span,
+        "Cannot assign to expression",
+        "not assignable at runtime",
+    )
+}
+
+fn base_operator(operator: BinaryOperator) -> BinaryOperator {
+    match operator {
+        BinaryOperator::AddAssign => BinaryOperator::Add,
+        BinaryOperator::SubtractAssign => BinaryOperator::Subtract,
+        BinaryOperator::MultiplyAssign => BinaryOperator::Multiply,
+        BinaryOperator::DivideAssign => BinaryOperator::Divide,
+        BinaryOperator::ModAssign => BinaryOperator::Mod,
+        operator => operator,
+    }
+}
+
+fn arithmetic<'a>(
+    node: &BinaryOpExpressionNode,
+    operator: BinaryOperator,
+    left: &Value<'a>,
+    right: &Value<'a>,
+    integer_type: PrimitiveType,
+) -> Evaluation<'a> {
+    let result = match (left, right) {
+        (Value::Int(_), Value::Int(0))
+            if matches!(operator, BinaryOperator::Divide | BinaryOperator::Mod) =>
+        {
+            let error = RuntimeError::new(
+                node.right.span,
+                "Division by zero",
+                "this expression evaluated to zero",
+            );
+            return Err(error.into());
+        }
+        (Value::Int(left), Value::Int(right)) => match operator {
+            BinaryOperator::Add => left.checked_add(*right),
+            BinaryOperator::Subtract => left.checked_sub(*right),
+            BinaryOperator::Multiply => left.checked_mul(*right),
+            BinaryOperator::Divide => left.checked_div(*right),
+            BinaryOperator::Mod => left.checked_rem(*right),
+            _ => None,
+        }
+        .filter(|value| fits_integer(*value, integer_type))
+        .map(Value::Int),
+        (Value::Float(left), Value::Float(right)) => match operator {
+            BinaryOperator::Add => Some(left + right),
+            BinaryOperator::Subtract => Some(left - right),
+            BinaryOperator::Multiply => Some(left * right),
+            BinaryOperator::Divide => Some(left / right),
+            BinaryOperator::Mod => Some(left % right),
+            _ => None,
+        }
+        .map(Value::Float),
+        _ => None,
+    };
+
+    result.ok_or_else(|| {
+        let inline_message = if matches!((left, right), (Value::Int(_), Value::Int(_))) {
+            format!("result does not fit in `{integer_type}`")
+        } else {
+            format!("cannot apply to values `{left}` and `{right}`")
+        };
+        RuntimeError::new(
+            node.operator.span,
+            &format!("Invalid arithmetic for `{}`", operator.as_token()),
+            &inline_message,
+        )
+        .into()
+    })
+}
+
+fn compare<'a>(
+    node: &BinaryOpExpressionNode,
+    left: &Value<'a>,
+    right: &Value<'a>,
+) -> Evaluation<'a> {
+    let ordering = match (left, right) {
+        (Value::Int(left), Value::Int(right)) => left.partial_cmp(right),
+        (Value::Float(left), Value::Float(right)) => left.partial_cmp(right),
+        (Value::Char(left), Value::Char(right)) => left.partial_cmp(right),
+        _ => None,
+    };
+
+    // Comparisons involving NaN are always false
+    if ordering.is_none() && matches!((left, right), (Value::Float(_), Value::Float(_))) {
+        return Ok(Value::Bool(false));
+    }
+
+    let Some(ordering) = ordering else {
+        let error = RuntimeError::new(
+            node.operator.span,
+            &format!("Cannot compare values with `{}`", node.operator.as_token()),
+            &format!("cannot compare `{left}` and `{right}`"),
+        );
+        return Err(error.into());
+    };
+
+    Ok(Value::Bool(is_ordered(node.operator.value, ordering)))
+}
+
+fn is_ordered(operator: BinaryOperator, ordering: Ordering) -> bool {
+    match operator {
+        BinaryOperator::LessThan => ordering.is_lt(),
+        BinaryOperator::LessThanOrEqual => ordering.is_le(),
+        BinaryOperator::GreaterThan => ordering.is_gt(),
+        _ => ordering.is_ge(),
+    }
+}
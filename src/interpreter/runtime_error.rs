@@ -0,0 +1,44 @@
+use crate::{
+    diagnostics::{Diagnostic, DiagnosticSink, Phase},
+    interpreter::Value,
+    parser::TokenSpan,
+};
+
+pub struct RuntimeError {
+    pub span: TokenSpan,
+    pub message: String,
+    pub inline_message: String,
+}
+
+impl RuntimeError {
+    pub fn new(span: TokenSpan, message: &str, inline_message: &str) -> Self {
+        Self {
+            span,
+            message: message.to_owned(),
+            inline_message: inline_message.to_owned(),
+        }
+    }
+
+    pub fn to_diagnostic(&self, diagnostics: &DiagnosticSink) -> Diagnostic {
+        let span = diagnostics.span(self.span);
+        Diagnostic::error(span, &self.message, &self.inline_message).with_phase(Phase::Runtime)
+    }
+}
+
+// Anything that interrupts the normal evaluation of an expression or statement
+pub enum ControlFlow<'a> {
+    Break,
+    Continue,
+    Return(Value<'a>),
+    // `?` found an empty optional value, making the enclosing optional context `None`
+    ShortCircuit,
+    Error(RuntimeError),
+}
+
+impl From<RuntimeError> for ControlFlow<'_> {
+    fn from(error: RuntimeError) -> Self {
+        ControlFlow::Error(error)
+    }
+}
+
+pub type Evaluation<'a> = Result<Value<'a>, ControlFlow<'a>>;
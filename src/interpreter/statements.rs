@@ -0,0 +1,165 @@
+use crate::{
+    checker::{NEXT_METHOD, SOME_VARIANT},
+    interpreter::{ControlFlow, Environment, Evaluation, Interpreter, RuntimeError, Value},
+    parser::{
+        BlockNode, ForLoopNode, IfStatementNode, Node, StatementNode, TokenSpan, WhileLoopNode,
+    },
+};
+
+impl<'a> Interpreter<'a> {
+    pub fn evaluate_block(
+        &self,
+        block: &'a BlockNode,
+        environment: &Environment<'a>,
+    ) -> Evaluation<'a> {
+        let mut environment = environment.clone();
+        for statement in block.statements.iter() {
+            if let StatementNode::BlockReturn(expression) = &statement.value {
+                return self.evaluate_optional(expression, expression.span, &environment);
+            }
+            environment = self.execute(statement, &environment)?;
+        }
+
+        Ok(Value::Void)
+    }
+
+    // Executes a statement, returning the environment for the statements that follow it
+    fn execute(
+        &self,
+        statement: &'a Node<StatementNode>,
+        environment: &Environment<'a>,
+    ) -> Result<Environment<'a>, ControlFlow<'a>> {
+        match &statement.value {
+            StatementNode::BlockReturn(expression) => {
+                self.evaluate_optional(expression, expression.span, environment)?;
+            }
+            StatementNode::Break => return Err(ControlFlow::Break),
+            StatementNode::Continue => return Err(ControlFlow::Continue),
+            StatementNode::Declaration(node) => {
+                let value = match &node.initializer {
+                    Some(initializer) => {
+                        self.evaluate_optional(initializer, initializer.span, environment)?
+                    }
+                    None => Value::Void,
+                };
+                return match self.match_pattern(&node.pattern, &value, environment)? {
+                    Some(environment) => Ok(environment),
+                    None => Err(RuntimeError::new(
+                        node.pattern.span,
+                        "Declaration pattern not matched",
+                        &format!("value `{value}` does not match the pattern"),
+                    )
+                    .into()),
+                };
+            }
+            StatementNode::Expression(expression) => {
+                self.evaluate(expression, statement.span, environment)?;
+            }
+            StatementNode::ForLoop(node) => self.execute_for(node, environment)?,
+            StatementNode::FunctionReturn(expression) => {
+                let value = match expression {
+                    Some(expression) => {
+                        self.evaluate_optional(expression, expression.span, environment)?
+                    }
+                    None => Value::Void,
+                };
+                return Err(ControlFlow::Return(value));
+            }
+            StatementNode::If(node) => self.execute_if(node, environment)?,
+            StatementNode::Match(node) => {
+                self.evaluate_match(node, environment)?;
+            }
+            StatementNode::TypeAlias(_) => {}
+            StatementNode::WhileLoop(node) => self.execute_while(node, environment)?,
+        }
+
+        Ok(environment.clone())
+    }
+
+    fn execute_if(
+        &self,
+        node: &'a IfStatementNode,
+        environment: &Environment<'a>,
+    ) -> Result<(), ControlFlow<'a>> {
+        for condition in node.conditions.iter() {
+            if self.evaluate_predicate(&condition.predicate, environment)? {
+                self.evaluate_block(&condition.body, environment)?;
+                return Ok(());
+            }
+        }
+
+        if let Some(else_branch) = &node.else_branch {
+            self.evaluate_block(else_branch, environment)?;
+        }
+        Ok(())
+    }
+
+    fn execute_while(
+        &self,
+        node: &'a WhileLoopNode,
+        environment: &Environment<'a>,
+    ) -> Result<(), ControlFlow<'a>> {
+        while self.evaluate_predicate(&node.predicate, environment)? {
+            match self.evaluate_block(&node.body, environment) {
+                Ok(_) | Err(ControlFlow::Continue) => {}
+                Err(ControlFlow::Break) => break,
+                Err(control_flow) => return Err(control_flow),
+            }
+        }
+        Ok(())
+    }
+
+    fn execute_for(
+        &self,
+        node: &'a ForLoopNode,
+        environment: &Environment<'a>,
+    ) -> Result<(), ControlFlow<'a>> {
+        let iterable = self.evaluate_node(&node.iterable, environment)?;
+        // The loop iterates over its own copy of the value, like any other value which is passed on,
+        // so arrays keep their elements and iterators are not consumed
+        let iterable = iterable.copy();
+        let mut elements = match &iterable {
+            Value::Array(elements) => Some(elements.borrow().clone().into_iter()),
+            _ => None,
+        };
+
+        loop {
+            let element = match elements.as_mut() {
+                Some(elements) => elements.next(),
+                None => self.next_element(&iterable, node.iterable.span)?,
+            };
+            let Some(element) = element else {
+                break;
+            };
+
+            let environment = environment.bind(&node.variable, element);
+            match self.evaluate_block(&node.body, &environment) {
+                Ok(_) | Err(ControlFlow::Continue) => {}
+                Err(ControlFlow::Break) => break,
+                Err(control_flow) => return Err(control_flow),
+            }
+        }
+        Ok(())
+    }
+
+    // Advances a value implementing `Iterator`, returning `None` once it is exhausted
+    fn next_element(
+        &self,
+        iterator: &Value<'a>,
+        span: TokenSpan,
+    ) -> Result<Option<Value<'a>>, ControlFlow<'a>> {
+        let Some(next) = self.get_method(iterator, NEXT_METHOD) else {
+            return Err(RuntimeError::new(
+                span,
+                "Cannot iterate over value",
+                &format!("value `{iterator}` is not iterable"),
+            )
+            .into());
+        };
+
+        match self.call(&next, vec![], span)? {
+            Value::Enum(element) if element.variant == SOME_VARIANT => Ok(element.value.clone()),
+            _ => Ok(None),
+        }
+    }
+}
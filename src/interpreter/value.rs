@@ -0,0 +1,261 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::{
+    interpreter::Environment,
+    parser::{
+        ClosureExpressionNode, EnumNode, FunctionNode, ImplementationNode, NameNode, Node,
+        StructNode,
+    },
+};
+
+#[derive(Clone)]
+pub enum Value<'a> {
+    Array(Rc<RefCell<Vec<Value<'a>>>>),
+    Bool(bool),
+    Char(char),
+    Enum(Rc<EnumValue<'a>>),
+    Float(f64),
+    Function(Rc<FunctionValue<'a>>),
+    Int(i128),
+    Struct(Rc<StructValue<'a>>),
+    Tuple(Rc<Vec<Value<'a>>>),
+    Void,
+}
+
+impl<'a> Value<'a> {
+    pub fn array(elements: Vec<Value<'a>>) -> Self {
+        Value::Array(Rc::new(RefCell::new(elements)))
+    }
+
+    pub fn function(function: FunctionValue<'a>) -> Self {
+        Value::Function(Rc::new(function))
+    }
+
+    // A value with its own storage, so that changing its fields or elements leaves this one as it
+    // is. Values are copied whenever they are stored in a binding, field or element.
+    pub fn copy(&self) -> Self {
+        match self {
+            Value::Array(elements) => {
+                Value::array(elements.borrow().iter().map(Value::copy).collect())
+            }
+            Value::Enum(enum_value) => Value::Enum(Rc::new(EnumValue {
+                node: enum_value.node,
+                variant: enum_value.variant,
+                value: enum_value.value.as_ref().map(Value::copy),
+            })),
+            Value::Struct(struct_value) => Value::Struct(Rc::new(struct_value.copy())),
+            Value::Tuple(elements) => {
+                Value::Tuple(Rc::new(elements.iter().map(Value::copy).collect()))
+            }
+            value => value.clone(),
+        }
+    }
+
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Value::Array(_) | Value::Function(_))
+    }
+
+    pub fn equals(&self, other: &Value<'a>) -> bool {
+        match (self, other) {
+            (Value::Array(left), Value::Array(right)) => {
+                Rc::ptr_eq(left, right) || values_equal(&left.borrow(), &right.borrow())
+            }
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::Char(left), Value::Char(right)) => left == right,
+            (Value::Enum(left), Value::Enum(right)) => {
+                std::ptr::eq(left.node, right.node)
+                    && left.variant == right.variant
+                    && match (&left.value, &right.value) {
+                        (Some(left), Some(right)) => left.equals(right),
+                        (None, None) => true,
+                        _ => false,
+                    }
+            }
+            (Value::Float(left), Value::Float(right)) => left == right,
+            (Value::Function(left), Value::Function(right)) => Rc::ptr_eq(left, right),
+            (Value::Int(left), Value::Int(right)) => left == right,
+            (Value::Struct(left), Value::Struct(right)) => {
+                std::ptr::eq(left.node, right.node)
+                    && values_equal(&left.fields.borrow(), &right.fields.borrow())
+            }
+            (Value::Tuple(left), Value::Tuple(right)) => values_equal(left, right),
+            (Value::Void, Value::Void) => true,
+            _ => false,
+        }
+    }
+}
+
+fn values_equal<'a>(left: &[Value<'a>], right: &[Value<'a>]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .all(|(left, right)| left.equals(right))
+}
+
+pub struct StructValue<'a> {
+    pub node: &'a StructNode,
+    pub fields: RefCell<Vec<Value<'a>>>,
+}
+
+impl<'a> StructValue<'a> {
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.node
+            .fields
+            .iter()
+            .position(|field| field.name.value == name)
+    }
+
+    // A separate instance with copies of the field values
+    pub fn copy(&self) -> Self {
+        Self {
+            node: self.node,
+            fields: RefCell::new(self.fields.borrow().iter().map(Value::copy).collect()),
+        }
+    }
+}
+
+pub struct EnumValue<'a> {
+    pub node: &'a EnumNode,
+    pub variant: &'a str,
+    pub value: Option<Value<'a>>,
+}
+
+pub enum Receiver<'a> {
+    // A free standing function
+    None,
+    // A method bound to an instance
+    Bound(Value<'a>),
+    // A static method, within the implementation of its type
+    Static(&'a Node<ImplementationNode>),
+}
+
+pub enum FunctionValue<'a> {
+    Closure {
+        node: &'a ClosureExpressionNode,
+        environment: Environment<'a>,
+    },
+    Constructor(&'a StructNode),
+    // The function `f.field(args)`, equivalent to `(...) -> f(...).field(args)`
+    Deferred {
+        function: Value<'a>,
+        field: &'a NameNode,
+        arguments: Option<Vec<Value<'a>>>,
+    },
+    Function {
+        node: &'a FunctionNode,
+        receiver: Receiver<'a>,
+    },
+    // The function `.field(args)`, equivalent to `x -> x.field(args)`
+    Member {
+        field: &'a NameNode,
+        arguments: Option<Vec<Value<'a>>>,
+    },
+    Partial {
+        function: Value<'a>,
+        arguments: Vec<Value<'a>>,
+    },
+    // The function `Type::field`, which takes its receiver as the first argument
+    StaticMember {
+        field: &'a NameNode,
+        arity: usize,
+    },
+    Variant {
+        node: &'a EnumNode,
+        variant: &'a str,
+    },
+}
+
+impl FunctionValue<'_> {
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::Closure { node, .. } => node.parameters.len() - usize::from(self.is_variadic()),
+            Self::Constructor(node) => node.fields.len(),
+            Self::Deferred { function, .. } => match function {
+                Value::Function(function) => function.arity(),
+                _ => 1,
+            },
+            Self::Function { node, .. } => node.signature.parameters.len(),
+            Self::Member { .. } | Self::Variant { .. } => 1,
+            Self::Partial {
+                function,
+                arguments,
+            } => match function {
+                Value::Function(function) => function.arity().saturating_sub(arguments.len()),
+                _ => 1,
+            },
+            Self::StaticMember { arity, .. } => *arity,
+        }
+    }
+
+    // Variadic functions collect any arguments beyond their arity into a tuple
+    pub fn is_variadic(&self) -> bool {
+        match self {
+            Self::Closure { node, .. } => node
+                .parameters
+                .last()
+                .is_some_and(|parameter| parameter.as_ref().is_some_and(|p| p.is_spread)),
+            Self::Deferred { function, .. } | Self::Partial { function, .. } => {
+                matches!(function, Value::Function(function) if function.is_variadic())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Array(elements) => {
+                let elements = elements.borrow();
+                if !elements.is_empty() && elements.iter().all(|e| matches!(e, Value::Char(_))) {
+                    let text = elements
+                        .iter()
+                        .filter_map(|e| match e {
+                            Value::Char(c) => Some(*c),
+                            _ => None,
+                        })
+                        .collect::<String>();
+                    return write!(f, "\"{text}\"");
+                }
+                write!(f, "[")?;
+                write_list(f, &elements)?;
+                write!(f, "]")
+            }
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Char(value) => write!(f, "'{value}'"),
+            Value::Enum(value) => {
+                write!(f, "{}.{}", value.node.name, value.variant)?;
+                if let Some(inner) = value.value.as_ref() {
+                    write!(f, "({inner})")?;
+                }
+                Ok(())
+            }
+            Value::Float(value) => write!(f, "{value:?}"),
+            Value::Function(_) => write!(f, "{{function}}"),
+            Value::Int(value) => write!(f, "{value}"),
+            Value::Struct(value) => {
+                write!(f, "{}(", value.node.name)?;
+                write_list(f, &value.fields.borrow())?;
+                write!(f, ")")
+            }
+            Value::Tuple(elements) => {
+                write!(f, "(")?;
+                write_list(f, elements)?;
+                write!(f, ")")
+            }
+            Value::Void => write!(f, "void"),
+        }
+    }
+}
+
+fn write_list(f: &mut std::fmt::Formatter<'_>, list: &[Value<'_>]) -> std::fmt::Result {
+    for (index, element) in list.iter().enumerate() {
+        write!(f, "{element}")?;
+        if index != list.len() - 1 {
+            write!(f, ", ")?;
+        }
+    }
+
+    Ok(())
+}
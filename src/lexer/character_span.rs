@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use crate::lexer::TokenWidth;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CharacterSpan {
     pub start: CharacterLocation,
     pub end: CharacterLocation,
@@ -14,7 +14,7 @@ impl Display for CharacterSpan {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CharacterLocation {
     pub line: usize,
     pub column: usize,
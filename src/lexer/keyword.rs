@@ -7,34 +7,50 @@ use crate::lexer::{EnumToken, Token, TokenMatch};
 #[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
 pub enum Keyword {
     And,
+    As,
     Bool,
     Break,
     Char,
     Continue,
     Else,
     Enum,
+    F32,
+    F64,
     False,
     Float,
     Fn,
     For,
+    I8,
+    I16,
+    I32,
+    I64,
     If,
     Impl,
+    Import,
+    In,
     Int,
     Interface,
     Let,
     Match,
     Mut,
     Not,
+    Operator,
     Or,
     Pub,
     Result,
     Return,
     SelfValue,
     SelfType,
+    Static,
     Struct,
     Then,
     True,
     Type,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
     Void,
     While,
 }
@@ -43,34 +59,50 @@ impl EnumToken for Keyword {
     fn as_str(&self) -> &str {
         match self {
             Self::And => "and",
+            Self::As => "as",
             Self::Bool => "bool",
             Self::Break => "break",
             Self::Char => "char",
             Self::Continue => "continue",
             Self::Else => "else",
             Self::Enum => "enum",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
             Self::False => "false",
             Self::Float => "float",
             Self::Fn => "fn",
             Self::For => "for",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
             Self::If => "if",
             Self::Impl => "impl",
+            Self::Import => "import",
+            Self::In => "in",
             Self::Int => "int",
             Self::Interface => "interface",
             Self::Let => "let",
             Self::Match => "match",
             Self::Mut => "mut",
             Self::Not => "not",
+            Self::Operator => "operator",
             Self::Or => "or",
             Self::Pub => "pub",
             Self::Result => "Result",
             Self::Return => "return",
             Self::SelfType => "Self",
             Self::SelfValue => "self",
+            Self::Static => "static",
             Self::Struct => "struct",
             Self::Then => "then",
             Self::True => "true",
             Self::Type => "type",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::Usize => "usize",
             Self::Void => "void",
             Self::While => "while",
         }
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::{
+    diagnostics::{Diagnostic, Label, Phase, Severity, SourceSpan},
+    lexer::{CharacterSpan, SourceCode},
+};
+
+#[derive(Clone, Copy)]
+pub enum LexicalError {
+    FloatOutOfRange,
+    IntegerOutOfRange,
+    InvalidNumericLiteral,
+    UnexpectedToken,
+}
+
+impl LexicalError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::FloatOutOfRange => "float literal out of range",
+            Self::IntegerOutOfRange => "integer literal out of range",
+            Self::InvalidNumericLiteral => "invalid numeric literal",
+            Self::UnexpectedToken => "unexpected token",
+        }
+    }
+
+    pub fn inline_message(&self) -> &'static str {
+        match self {
+            Self::FloatOutOfRange => "does not fit in a `float`",
+            Self::IntegerOutOfRange => "does not fit in a `u64`",
+            Self::InvalidNumericLiteral => "contains invalid digits or is missing digits",
+            Self::UnexpectedToken => "unexpected token",
+        }
+    }
+}
+
+pub struct LocatedLexicalError {
+    pub span: CharacterSpan,
+    pub error: LexicalError,
+}
+
+impl LocatedLexicalError {
+    pub fn to_diagnostic(&self, source: &Rc<SourceCode>) -> Diagnostic {
+        let span = SourceSpan::new(source.clone(), self.span);
+        let label = Label::new(span, self.error.inline_message());
+        Diagnostic::new(Severity::Error, Phase::Lexer, self.error.message(), label)
+    }
+}
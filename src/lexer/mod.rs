@@ -1,6 +1,8 @@
 mod character_span;
 mod keyword;
+mod lexical_error;
 mod source_code;
+mod source_map;
 mod symbol;
 mod token;
 mod token_match;
@@ -12,7 +14,9 @@ use tokenize::*;
 
 pub use character_span::*;
 pub use keyword::*;
+pub use lexical_error::*;
 pub use source_code::*;
+pub use source_map::*;
 pub use symbol::*;
 pub use token::*;
 pub use token_match::*;
@@ -0,0 +1,49 @@
+use std::{cell::RefCell, error::Error, rc::Rc};
+
+use crate::{lexer::SourceCode, parser::TokenSpan};
+
+// Every source file in the program, with token indices which are distinct across files
+#[derive(Default)]
+pub struct SourceMap {
+    files: RefCell<Vec<Rc<SourceCode>>>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        SourceMap::default()
+    }
+
+    pub fn read(&self, path: &str) -> Result<Rc<SourceCode>, Box<dyn Error>> {
+        let source = SourceCode::read(path, self.next_token_offset())?;
+        Ok(self.push(source))
+    }
+
+    // Adds a file which is not read from disk, like the prelude built into the compiler
+    pub fn add(&self, path: &str, source: &str) -> Rc<SourceCode> {
+        let source = SourceCode::new(path, source.to_owned(), self.next_token_offset());
+        self.push(source)
+    }
+
+    fn next_token_offset(&self) -> usize {
+        self.files
+            .borrow()
+            .last()
+            .map_or(0, |file| file.token_offset + file.tokens.len())
+    }
+
+    fn push(&self, source: SourceCode) -> Rc<SourceCode> {
+        let source = Rc::new(source);
+        self.files.borrow_mut().push(source.clone());
+        source
+    }
+
+    // Finds the file containing a span
+    pub fn get(&self, span: TokenSpan) -> Rc<SourceCode> {
+        self.files
+            .borrow()
+            .iter()
+            .find(|file| file.contains(span.start_index))
+            .cloned()
+            .expect("span should belong to a source file")
+    }
+}
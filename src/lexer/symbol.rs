@@ -14,6 +14,8 @@ pub enum Symbol {
     Comma,
     Dot,
     DoubleColon,
+    DoubleDot,
+    DoubleDotEqual,
     DoubleEqual,
     Ellipsis,
     Equal,
@@ -52,6 +54,8 @@ impl EnumToken for Symbol {
             Self::Comma => ",",
             Self::Dot => ".",
             Self::DoubleColon => "::",
+            Self::DoubleDot => "..",
+            Self::DoubleDotEqual => "..=",
             Self::DoubleEqual => "==",
             Self::Ellipsis => "...",
             Self::Equal => "=",
@@ -13,11 +13,12 @@ impl Display for LocatedToken {
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq)]
 pub enum Token {
     CharacterLiteral(String),
+    FloatLiteral(f64),
     Name(String),
-    IntegerLiteral(i64),
+    IntegerLiteral(i128),
     StringLiteral(String),
     Symbol(Symbol),
     Keyword(Keyword),
@@ -28,6 +29,7 @@ impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::CharacterLiteral(literal) => write!(f, "{literal}"),
+            Self::FloatLiteral(literal) => write!(f, "{literal:?}"),
             Self::Name(name) => write!(f, "{name}"),
             Self::IntegerLiteral(literal) => write!(f, "{literal}"),
             Self::StringLiteral(literal) => write!(f, "\"{literal}\""),
@@ -1,13 +1,13 @@
 use crate::lexer::{
-    CharacterLiteralTokenizer, CharacterLocation, CharacterSpan, IntegerLiteralTokenizer,
-    KeywordTokenizer, LocatedToken, MultiLineCommentTokenizer, NameTokenizer,
-    SingleLineCommentTokenizer, StringLiteralTokenizer, SymbolTokenizer, Token, TokenWidth,
-    Tokenizer, TryTokenizeResult, WhitespaceTokenizer,
+    CharacterLiteralTokenizer, CharacterLocation, CharacterSpan, KeywordTokenizer, LexicalError,
+    LocatedLexicalError, LocatedToken, MultiLineCommentTokenizer, NameTokenizer,
+    NumericLiteralTokenizer, SingleLineCommentTokenizer, StringLiteralTokenizer, Symbol,
+    SymbolTokenizer, Token, TokenWidth, Tokenizer, WhitespaceTokenizer,
 };
 
 pub struct TokenizerResult {
     pub tokens: Vec<LocatedToken>,
-    pub errors: Vec<CharacterSpan>,
+    pub errors: Vec<LocatedLexicalError>,
 }
 
 pub fn tokenize(mut text: &str) -> TokenizerResult {
@@ -18,7 +18,7 @@ pub fn tokenize(mut text: &str) -> TokenizerResult {
         Box::new(KeywordTokenizer),
         Box::new(StringLiteralTokenizer),
         Box::new(CharacterLiteralTokenizer),
-        Box::new(IntegerLiteralTokenizer),
+        Box::new(NumericLiteralTokenizer),
         Box::new(NameTokenizer),
         Box::new(WhitespaceTokenizer),
     ];
@@ -31,13 +31,21 @@ pub fn tokenize(mut text: &str) -> TokenizerResult {
     };
 
     while !text.is_empty() {
-        while let Some(token) = next_token(text, tokenizers) {
-            let NextToken { token, width, next } = token;
+        while let Some(token) = next_token(text, tokenizers, tokens.last()) {
+            let NextToken {
+                token,
+                width,
+                error,
+                next,
+            } = token;
             let end = start.add(&width);
+            let span = CharacterSpan { start, end };
             if let Some(token) = token {
-                let span = CharacterSpan { start, end };
                 tokens.push(LocatedToken { token, span });
             }
+            if let Some(error) = error {
+                errors.push(LocatedLexicalError { span, error });
+            }
             start = end;
             text = next;
         }
@@ -48,7 +56,7 @@ pub fn tokenize(mut text: &str) -> TokenizerResult {
             if slice.is_empty() {
                 break;
             }
-            let token = next_token(slice, tokenizers);
+            let token = next_token(slice, tokenizers, None);
             if token.is_some() {
                 break;
             }
@@ -58,7 +66,10 @@ pub fn tokenize(mut text: &str) -> TokenizerResult {
         let bad_token = &text[..bad_token_start];
         if !bad_token.is_empty() {
             let end = start.add(&TokenWidth::from(bad_token));
-            errors.push(CharacterSpan { start, end });
+            errors.push(LocatedLexicalError {
+                span: CharacterSpan { start, end },
+                error: LexicalError::UnexpectedToken,
+            });
             start = end;
             text = &text[bad_token_start..];
         }
@@ -77,11 +88,32 @@ pub fn tokenize(mut text: &str) -> TokenizerResult {
 struct NextToken<'a> {
     pub token: Option<Token>,
     pub width: TokenWidth,
+    pub error: Option<LexicalError>,
     pub next: &'a str,
 }
 
-fn next_token<'a>(text: &'a str, tokenizers: &[Box<dyn Tokenizer>]) -> Option<NextToken<'a>> {
-    let mut selected_token: Option<TryTokenizeResult> = None;
+fn next_token<'a>(
+    text: &'a str,
+    tokenizers: &[Box<dyn Tokenizer>],
+    previous: Option<&LocatedToken>,
+) -> Option<NextToken<'a>> {
+    // Digits following `.` are a tuple index, whichever token would otherwise be longest
+    let is_member = matches!(
+        previous,
+        Some(LocatedToken {
+            token: Token::Symbol(Symbol::Dot),
+            ..
+        })
+    );
+    let mut selected_token = is_member
+        .then(|| NumericLiteralTokenizer::try_tokenize_index(text))
+        .flatten();
+    let tokenizers = if selected_token.is_some() {
+        &[]
+    } else {
+        tokenizers
+    };
+
     for tokenizer in tokenizers {
         let maybe_result = tokenizer.try_tokenize(text);
         if let Some(result) = maybe_result.as_ref() {
@@ -100,6 +132,7 @@ fn next_token<'a>(text: &'a str, tokenizers: &[Box<dyn Tokenizer>]) -> Option<Ne
         NextToken {
             token: result.token,
             width: result.width,
+            error: result.error,
             next,
         }
     })
@@ -31,12 +31,14 @@ impl Tokenizer for CharacterLiteralTokenizer {
             return None;
         }
 
-        let character = text[..width.bytes].to_string();
+        // Width includes the opening quote which has already been stripped from the text
+        let character = text[..width.bytes - SINGLE_QUOTE.len_utf8()].to_string();
         width.add_char(SINGLE_QUOTE);
 
         Some(TryTokenizeResult {
             token: Some(Token::CharacterLiteral(character)),
             width,
+            error: None,
         })
     }
 }
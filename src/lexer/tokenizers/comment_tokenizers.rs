@@ -18,7 +18,11 @@ impl Tokenizer for SingleLineCommentTokenizer {
             }
         }
 
-        Some(TryTokenizeResult { token: None, width })
+        Some(TryTokenizeResult {
+            token: None,
+            width,
+            error: None,
+        })
     }
 }
 
@@ -43,6 +47,10 @@ impl Tokenizer for MultiLineCommentTokenizer {
             width.add_char(character);
         }
 
-        Some(TryTokenizeResult { token: None, width })
+        Some(TryTokenizeResult {
+            token: None,
+            width,
+            error: None,
+        })
     }
 }
@@ -22,5 +22,6 @@ pub fn try_tokenize_enum<T: EnumToken + Copy>(
     found_token.map(|token| TryTokenizeResult {
         token: Some(make_token(token)),
         width: TokenWidth::from(token.as_str()),
+        error: None,
     })
 }
@@ -1,9 +1,9 @@
 mod character_literal_tokenizer;
 mod comment_tokenizers;
 mod enum_tokenizer;
-mod integer_literal_tokenizer;
 mod keyword_tokenizer;
 mod name_tokenizer;
+mod numeric_literal_tokenizer;
 mod string_literal_tokenizer;
 mod symbol_tokenizer;
 mod tokenizer;
@@ -12,9 +12,9 @@ mod whitespace_tokenizer;
 pub use character_literal_tokenizer::*;
 pub use comment_tokenizers::*;
 pub use enum_tokenizer::*;
-pub use integer_literal_tokenizer::*;
 pub use keyword_tokenizer::*;
 pub use name_tokenizer::*;
+pub use numeric_literal_tokenizer::*;
 pub use string_literal_tokenizer::*;
 pub use symbol_tokenizer::*;
 pub use tokenizer::*;
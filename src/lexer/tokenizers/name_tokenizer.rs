@@ -23,6 +23,7 @@ impl Tokenizer for NameTokenizer {
         Some(TryTokenizeResult {
             token: Some(Token::Name(name)),
             width,
+            error: None,
         })
     }
 }
@@ -0,0 +1,135 @@
+use crate::lexer::{LexicalError, Token, TokenWidth, Tokenizer, TryTokenizeResult};
+
+const SEPARATOR: char = '_';
+
+pub struct NumericLiteralTokenizer;
+
+impl Tokenizer for NumericLiteralTokenizer {
+    fn try_tokenize(&self, text: &str) -> Option<TryTokenizeResult> {
+        if !text.starts_with(|character: char| character.is_ascii_digit()) {
+            return None;
+        }
+
+        let radix = match text.get(..2) {
+            Some("0x" | "0X") => 16,
+            Some("0o" | "0O") => 8,
+            Some("0b" | "0B") => 2,
+            _ => 10,
+        };
+
+        let (length, result) = if radix == 10 {
+            decimal_literal(text)
+        } else {
+            // Any trailing name characters are consumed so they are reported as invalid digits
+            let length = 2 + scan(&text[2..], |character| {
+                character.is_alphanumeric() || character == SEPARATOR
+            });
+            (length, radix_literal(&text[2..length], radix))
+        };
+
+        let (token, error) = match result {
+            Ok(token) => (token, None),
+            // Invalid literals still produce a token so that parsing can continue
+            Err(LexicalError::FloatOutOfRange) => (
+                Token::FloatLiteral(0.0),
+                Some(LexicalError::FloatOutOfRange),
+            ),
+            Err(error) => (Token::IntegerLiteral(0), Some(error)),
+        };
+        Some(TryTokenizeResult {
+            token: Some(token),
+            width: TokenWidth::from(&text[..length]),
+            error,
+        })
+    }
+}
+
+impl NumericLiteralTokenizer {
+    // Digits following `.` index a tuple, so `pair.0.1` is not read as the float `0.1`
+    pub fn try_tokenize_index(text: &str) -> Option<TryTokenizeResult> {
+        let length = scan(text, |character| character.is_ascii_digit());
+        if length == 0 {
+            return None;
+        }
+
+        let (token, error) = match text[..length].parse::<u64>() {
+            Ok(index) => (Token::IntegerLiteral(index.into()), None),
+            Err(_) => (
+                Token::IntegerLiteral(0),
+                Some(LexicalError::IntegerOutOfRange),
+            ),
+        };
+        Some(TryTokenizeResult {
+            token: Some(token),
+            width: TokenWidth::from(&text[..length]),
+            error,
+        })
+    }
+}
+
+fn decimal_literal(text: &str) -> (usize, Result<Token, LexicalError>) {
+    let mut length = scan_digits(text, 10);
+    let mut is_float = false;
+
+    // A fractional part requires a digit after the point, leaving `1.method()` and `1..2` intact
+    let rest = &text[length..];
+    if rest.starts_with('.') && rest[1..].starts_with(|character: char| character.is_ascii_digit())
+    {
+        length += 1 + scan_digits(&rest[1..], 10);
+        is_float = true;
+    }
+
+    let rest = &text[length..];
+    if rest.starts_with(['e', 'E']) {
+        let sign = usize::from(rest[1..].starts_with(['+', '-']));
+        let exponent = &rest[1 + sign..];
+        if exponent.starts_with(|character: char| character.is_ascii_digit()) {
+            length += 1 + sign + scan_digits(exponent, 10);
+            is_float = true;
+        }
+    }
+
+    let suffix = scan(&text[length..], |character| {
+        character.is_alphanumeric() || character == SEPARATOR
+    });
+    if suffix > 0 {
+        return (length + suffix, Err(LexicalError::InvalidNumericLiteral));
+    }
+
+    let digits = text[..length].replace(SEPARATOR, "");
+    let result = if is_float {
+        match digits.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Token::FloatLiteral(value)),
+            _ => Err(LexicalError::FloatOutOfRange),
+        }
+    } else {
+        digits
+            .parse::<u64>()
+            .map(|value| Token::IntegerLiteral(value.into()))
+            .map_err(|_| LexicalError::IntegerOutOfRange)
+    };
+    (length, result)
+}
+
+fn radix_literal(text: &str, radix: u32) -> Result<Token, LexicalError> {
+    let digits = text.replace(SEPARATOR, "");
+    if digits.is_empty() || !digits.chars().all(|character| character.is_digit(radix)) {
+        return Err(LexicalError::InvalidNumericLiteral);
+    }
+
+    u64::from_str_radix(&digits, radix)
+        .map(|value| Token::IntegerLiteral(value.into()))
+        .map_err(|_| LexicalError::IntegerOutOfRange)
+}
+
+// The number of bytes of digits and separators at the start of the text
+fn scan_digits(text: &str, radix: u32) -> usize {
+    scan(text, |character| {
+        character.is_digit(radix) || character == SEPARATOR
+    })
+}
+
+fn scan(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+    text.find(|character| !predicate(character))
+        .unwrap_or(text.len())
+}
@@ -39,6 +39,7 @@ impl Tokenizer for StringLiteralTokenizer {
         Some(TryTokenizeResult {
             token: Some(Token::StringLiteral(string)),
             width,
+            error: None,
         })
     }
 }
@@ -1,4 +1,4 @@
-use crate::lexer::{Token, TokenWidth};
+use crate::lexer::{LexicalError, Token, TokenWidth};
 
 pub trait Tokenizer {
     fn try_tokenize(&self, text: &str) -> Option<TryTokenizeResult>;
@@ -7,4 +7,6 @@ pub trait Tokenizer {
 pub struct TryTokenizeResult {
     pub token: Option<Token>,
     pub width: TokenWidth,
+    // An error found within an otherwise recognized token
+    pub error: Option<LexicalError>,
 }
@@ -16,6 +16,10 @@ impl Tokenizer for WhitespaceTokenizer {
             return None;
         }
 
-        Some(TryTokenizeResult { token: None, width })
+        Some(TryTokenizeResult {
+            token: None,
+            width,
+            error: None,
+        })
     }
 }
@@ -1,59 +1,102 @@
-use std::{env, process::exit, rc::Rc};
+use std::{env, process::exit, rc::Rc, thread};
 
 use colored::Colorize;
 
 use crate::{
-    lexer::{Severity, SourceCode},
-    parser::{LocatedSyntaxError, program},
+    checker::{ModuleExports, Resolutions},
+    diagnostics::{DiagnosticSink, ErrorFormat, Phase},
+    interpreter::Interpreter,
+    lexer::SourceMap,
+    parser::ModuleLoader,
 };
 
 pub mod checker;
+pub mod diagnostics;
+pub mod interpreter;
 pub mod lexer;
 pub mod parser;
 
+const RUN_COMMAND: &str = "run";
+const ERROR_FORMAT_OPTION: &str = "--error-format=";
+// The interpreter evaluates calls recursively, so deep recursion in a program needs a large stack
+const STACK_SIZE: usize = 1 << 30;
+
 fn main() {
-    let args = env::args().collect::<Vec<_>>();
-    if args.len() == 1 {
-        println!("{} {} <source-file>", "Usage error:".bold().red(), args[0]);
-        exit(1);
+    let compiler = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(compile)
+        .expect("should be able to spawn the compiler thread");
+    if compiler.join().is_err() {
+        exit(101);
     }
+}
 
-    let source = SourceCode::read(args[1].as_str()).unwrap();
-    let mut tokens = source.token_stream();
-    let result = program(&mut tokens);
+fn compile() {
+    let (options, args) = env::args().partition::<Vec<_>, _>(|arg| arg.starts_with("--"));
+    let mut error_format = ErrorFormat::Human;
+    for option in &options {
+        error_format = match option.strip_prefix(ERROR_FORMAT_OPTION) {
+            Some("human") => ErrorFormat::Human,
+            Some("json") => ErrorFormat::Json,
+            _ => usage_error(&args[0]),
+        };
+    }
 
-    for error in &source.tokenizer_errors {
-        println!("{} unexpected token", "Lexer error:".red().bold());
-        source.print_character_span(*error, '^', "unexpected token", Severity::Error);
-        println!();
+    let (run, path) = match args.as_slice() {
+        [_, command, path] if command == RUN_COMMAND => (true, path),
+        [_, path] => (false, path),
+        _ => usage_error(&args[0]),
+    };
+
+    let diagnostics = Rc::new(DiagnosticSink::new(Rc::new(SourceMap::new())));
+    let mut modules = match ModuleLoader::new(&diagnostics).load_program(path) {
+        Ok(modules) => modules,
+        Err(error) => {
+            let message = format!("could not read `{path}`: {error}");
+            error_format.render_message(Phase::Loader, path, &message);
+            exit(1);
+        }
+    };
+
+    // Modules are checked after the modules they import, so that their exports are known
+    let panic_guard = diagnostics.render_on_panic(error_format);
+    let resolutions = Rc::new(Resolutions::default());
+    let mut exports: Vec<ModuleExports> = vec![];
+    for module in &mut modules {
+        let imports = module
+            .imports
+            .iter()
+            .map(|import| import.map(|index| &exports[index]))
+            .collect::<Vec<_>>();
+        let prelude = module.prelude.map(|index| &exports[index]);
+        let module_exports =
+            module
+                .program
+                .check(diagnostics.clone(), resolutions.clone(), prelude, &imports);
+        exports.push(module_exports);
     }
+    drop(panic_guard);
 
-    for error in &tokens.errors {
-        print_err(&source, error);
+    let has_errors = diagnostics.has_errors();
+    for diagnostic in diagnostics.take() {
+        error_format.render(&diagnostic);
+    }
+    if has_errors {
+        exit(1);
     }
 
-    match result {
-        Ok(mut program) => {
-            program.check(Rc::new(source));
-        }
-        Err(error) => {
-            print_err(&source, &error);
+    if run {
+        let interpreter = Interpreter::new(&modules, &resolutions);
+        if !interpreter.run(&diagnostics, error_format) {
+            exit(1);
         }
     }
 }
 
-fn print_err(source: &SourceCode, error: &LocatedSyntaxError) {
-    print!(
-        "{} {}",
-        "Syntax error:".red().bold(),
-        error.message(source.tokens.clone())
-    );
-    println!();
-    source.print_token_span(
-        error.span,
-        '^',
-        error.inline_message().to_string().as_str(),
-        Severity::Error,
+fn usage_error(program: &str) -> ! {
+    println!(
+        "{} {program} [{ERROR_FORMAT_OPTION}human|json] [{RUN_COMMAND}] <source-file>",
+        "Usage error:".bold().red(),
     );
-    println!();
+    exit(1);
 }
@@ -14,6 +14,7 @@ pub fn simple_closure(
             ClosureParameterExpressionNode {
                 name,
                 parameter_type: None,
+                is_spread: false,
             },
         )),
         _ => Err(LocatedSyntaxError {
@@ -32,16 +33,34 @@ pub fn closure(
     tokens: &mut TokenStream,
     parameters: Vec<Node<ExpressionNode>>,
 ) -> ParseResult<ExpressionNode> {
+    let parameter_count = parameters.len();
     let parameters = parameters
         .into_iter()
-        .map(|parameter| {
+        .enumerate()
+        .map(|(index, parameter)| {
+            let (parameter, is_spread) = match parameter.value {
+                ExpressionNode::Spread(spread) => (*spread.expression, true),
+                _ => (parameter, false),
+            };
+            if is_spread && index != parameter_count - 1 {
+                tokens.errors.push(LocatedSyntaxError {
+                    span: parameter.span,
+                    error: SyntaxError::UnexpectedSpreadParameter,
+                });
+            }
+
             if let ExpressionNode::Name(name) = parameter.value {
                 Some(parameter.span.wrap(ClosureParameterExpressionNode {
                     name,
                     parameter_type: None,
+                    is_spread,
                 }))
             } else if let ExpressionNode::ClosureParameter(param) = parameter.value {
-                Some(parameter.span.wrap(param))
+                Some(
+                    parameter
+                        .span
+                        .wrap(ClosureParameterExpressionNode { is_spread, ..param }),
+                )
             } else {
                 tokens.errors.push(LocatedSyntaxError {
                     span: parameter.span,
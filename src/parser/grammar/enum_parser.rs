@@ -2,16 +2,22 @@ use crate::{
     lexer::{Symbol, Token},
     parser::{
         EnumNode, EnumVariantNode, NameType, Node, ParseResult, SyntaxError, TokenStream,
-        grammar::{comma_separated_list, implementation, type_definition},
+        grammar::{comma_separated_list, implementation, type_definition, type_parameter_list},
     },
 };
 
 pub fn enumeration(tokens: &mut TokenStream) -> ParseResult<EnumNode> {
     tokens.next();
     let name = tokens.name(NameType::Variant)?;
+    let type_parameters = type_parameter_list(tokens)?;
     let variants = tokens.located(enum_variants)?;
     let implementation = implementation(tokens)?;
-    Ok(EnumNode::new(name, variants, implementation))
+    Ok(EnumNode::new(
+        name,
+        type_parameters,
+        variants,
+        implementation,
+    ))
 }
 
 fn enum_variants(tokens: &mut TokenStream) -> ParseResult<Vec<Node<EnumVariantNode>>> {
@@ -3,10 +3,11 @@ use crate::{
     parser::{
         ArrayExpressionNode, Associativity, BinaryOpExpressionNode, BinaryOperator, BlockNode,
         DeferredMemberExpressionNode, ExpressionNode, IfExpressionNode, LocatedSyntaxError,
-        NameType, Node, Operator, ParseResult, PostfixOpExpressionNode, PostfixOperator,
-        PrefixOpExpressionNode, PrefixOperator, SpreadNode, StatementNode, StatementType,
-        SyntaxError, TokenSpan, TokenStream, TupleExpressionNode,
-        grammar::{SpecialOperator, closure, match_expression, statement},
+        NameNode, NameType, NamedArgumentNode, Node, Operator, ParseResult,
+        PostfixOpExpressionNode, PostfixOperator, PrefixOpExpressionNode, PrefixOperator,
+        SpreadNode, StatementNode, StatementType, SyntaxError, TokenSpan, TokenStream,
+        TupleExpressionNode,
+        grammar::{SpecialOperator, closure, match_expression, member_name, statement},
     },
 };
 
@@ -14,6 +15,8 @@ use crate::{
 struct ExpressionContext {
     pub min_precedence: i32,
     pub allow_commas: bool,
+    // Within function arguments, `name: value` names the argument rather than declaring a type
+    pub allow_named_arguments: bool,
 }
 
 impl ExpressionContext {
@@ -21,6 +24,7 @@ impl ExpressionContext {
         Self {
             min_precedence: 0,
             allow_commas: true,
+            allow_named_arguments: false,
         }
     }
 
@@ -28,6 +32,14 @@ impl ExpressionContext {
         Self {
             min_precedence: 0,
             allow_commas: true,
+            allow_named_arguments: false,
+        }
+    }
+
+    pub fn arguments() -> Self {
+        Self {
+            allow_named_arguments: true,
+            ..Self::parentheses()
         }
     }
 
@@ -73,6 +85,12 @@ fn sub_expression(
                 break;
             }
 
+            // A trailing comma is left for the enclosing parentheses, where it marks a tuple
+            if operator == BinaryOperator::Comma && Symbol::CloseParen.matches(tokens.peek_ahead(1))
+            {
+                break;
+            }
+
             let operator = TokenSpan::singleton(tokens).wrap(operator);
             tokens.next();
 
@@ -113,19 +131,46 @@ fn binary_op_expression(
     })))
 }
 
+fn named_argument(tokens: &mut TokenStream) -> ParseResult<ExpressionNode> {
+    let name = tokens.name(NameType::Parameter)?;
+    tokens.next();
+    // The value ends at the next comma, and may not itself be named
+    let context = ExpressionContext {
+        min_precedence: BinaryOperator::Comma.precedence() + 1,
+        allow_commas: false,
+        allow_named_arguments: false,
+    };
+    let value = tokens.located_with(sub_expression, context)?;
+    Ok(ExpressionNode::NamedArgument(NamedArgumentNode {
+        name,
+        value: Box::new(value),
+    }))
+}
+
 pub fn function_arguments(tokens: &mut TokenStream) -> ParseResult<Vec<Node<ExpressionNode>>> {
     tokens.next();
 
     if tokens.accept(Symbol::CloseParen) {
         Ok(vec![])
     } else {
-        let context = ExpressionContext::parentheses();
-        let right = tokens.located_with(sub_expression, context)?;
+        let context = ExpressionContext::arguments();
+        let right = delimited_expression(tokens, context, Symbol::CloseParen);
         tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCloseParen)?;
         Ok(flatten_commas(right))
     }
 }
 
+// Parses the expression within brackets, replacing it with an error up to the closing bracket if
+// it is invalid
+fn delimited_expression(
+    tokens: &mut TokenStream,
+    context: ExpressionContext,
+    close_symbol: Symbol,
+) -> Node<ExpressionNode> {
+    let parse = |tokens: &mut TokenStream| sub_expression(tokens, context);
+    tokens.located_or(parse, &[close_symbol], ExpressionNode::Error)
+}
+
 fn expression_atom(
     tokens: &mut TokenStream,
     context: ExpressionContext,
@@ -160,6 +205,11 @@ fn expression_atom(
             tokens.next();
             Ok(ExpressionNode::SelfValue(span))
         }
+        Token::Name(_)
+            if context.allow_named_arguments && Symbol::Colon.matches(tokens.peek_ahead(1)) =>
+        {
+            named_argument(tokens)
+        }
         Token::Name(name) => {
             let span = TokenSpan::singleton(tokens);
             let name = span.wrap(name.clone());
@@ -172,8 +222,13 @@ fn expression_atom(
             tokens.next();
             Ok(ExpressionNode::CharacterLiteral(literal))
         }
+        Token::FloatLiteral(literal) => {
+            let literal = TokenSpan::singleton(tokens).wrap(*literal);
+            tokens.next();
+            Ok(ExpressionNode::FloatLiteral(literal))
+        }
         Token::IntegerLiteral(literal) => {
-            let literal = *literal;
+            let literal = TokenSpan::singleton(tokens).wrap(*literal);
             tokens.next();
             Ok(ExpressionNode::IntegerLiteral(literal))
         }
@@ -223,7 +278,32 @@ pub fn block(tokens: &mut TokenStream, block_type: BlockType) -> ParseResult<Blo
     tokens.expect(Symbol::OpenBrace, SyntaxError::ExpectedBlock)?;
     let mut statements = vec![];
     while !tokens.accept(Symbol::CloseBrace) {
-        statements.push(tokens.located_with(statement, block_type)?);
+        if tokens.is_done() {
+            tokens.push_error(SyntaxError::ExpectedCloseBrace);
+            break;
+        }
+
+        let start_index = tokens.index();
+        match tokens.located_with(statement, block_type) {
+            Ok(statement) => statements.push(statement),
+            Err(error) => {
+                // Invalid statements are skipped up to their end, and replaced by an error
+                tokens.recover(error, &[Symbol::Semicolon]);
+                // Stray closing brackets are skipped, up to the end of the statement or block
+                tokens.skip_stray_closes();
+                tokens.accept(Symbol::Semicolon);
+                let span = TokenSpan {
+                    start_index,
+                    end_index: tokens.index() - 1,
+                };
+                statements.push(span.wrap(StatementNode::Expression(ExpressionNode::Error)));
+            }
+        }
+
+        // Statements recovering from an error at a stray closing delimiter may not consume it
+        if tokens.index() == start_index {
+            tokens.next();
+        }
     }
 
     if matches!(block_type, BlockType::Expression) {
@@ -249,7 +329,7 @@ pub fn block(tokens: &mut TokenStream, block_type: BlockType) -> ParseResult<Blo
 
 fn deferred_member(tokens: &mut TokenStream) -> ParseResult<ExpressionNode> {
     tokens.next();
-    let field = tokens.name(NameType::Field)?;
+    let field = tokens.located(member_name)?;
     let arguments = if Symbol::OpenParen.matches(tokens.peek()) {
         Some(tokens.located(function_arguments)?)
     } else {
@@ -269,13 +349,56 @@ fn closure_or_tuple(tokens: &mut TokenStream) -> ParseResult<ExpressionNode> {
     }
 
     let context = ExpressionContext::parentheses();
-    let expression = tokens.located_with(sub_expression, context)?;
+    let expression = delimited_expression(tokens, context, Symbol::CloseParen);
+    let has_trailing_comma = tokens.accept(Symbol::Comma);
     tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCloseParen)?;
     let expressions = flatten_commas(expression);
     if tokens.accept(Symbol::SkinnyArrow) {
-        closure(tokens, expressions)
-    } else {
-        Ok(ExpressionNode::Tuple(TupleExpressionNode { expressions }))
+        return closure(tokens, expressions);
+    }
+
+    // A single element only groups its expression, unless it is written as `(value,)`, so `(x = 5)`
+    // is an assignment and `(x = 5,)` is a named tuple
+    let is_grouping = expressions.len() == 1
+        && !has_trailing_comma
+        && !matches!(expressions[0].value, ExpressionNode::Spread(_));
+    if is_grouping {
+        return Ok(expressions.into_iter().next().unwrap().value);
+    }
+
+    let (names, expressions): (Vec<_>, Vec<_>) = expressions.into_iter().map(tuple_element).unzip();
+
+    Ok(ExpressionNode::Tuple(TupleExpressionNode {
+        expressions,
+        names,
+    }))
+}
+
+// Tuple elements written as `name = value` are named, rather than assignments
+fn tuple_element(expression: Node<ExpressionNode>) -> (Option<NameNode>, Node<ExpressionNode>) {
+    let Node { value, span } = expression;
+    let ExpressionNode::BinaryOp(BinaryOpExpressionNode {
+        left,
+        operator,
+        right,
+    }) = value
+    else {
+        return (None, span.wrap(value));
+    };
+
+    match *left {
+        Node {
+            value: ExpressionNode::Name(name),
+            ..
+        } if operator.value == BinaryOperator::Assign => (Some(name), *right),
+        left => {
+            let node = BinaryOpExpressionNode {
+                left: Box::new(left),
+                operator,
+                right,
+            };
+            (None, span.wrap(ExpressionNode::BinaryOp(node)))
+        }
     }
 }
 
@@ -290,7 +413,7 @@ fn array_elements(tokens: &mut TokenStream) -> ParseResult<Vec<Node<ExpressionNo
     if tokens.accept(Symbol::CloseBracket) {
         Ok(vec![])
     } else {
-        let expression = tokens.located_with(sub_expression, context)?;
+        let expression = delimited_expression(tokens, context, Symbol::CloseBracket);
         tokens.expect(Symbol::CloseBracket, SyntaxError::ExpectedCloseBracket)?;
         Ok(flatten_commas(expression))
     }
@@ -1,4 +1,5 @@
 use crate::{
+    checker::CALL_OPERATOR,
     lexer::{Keyword, Symbol, Token, TokenMatch},
     parser::{
         ExpressionNode, FunctionBodyNode, FunctionNode, FunctionSignatureNode,
@@ -6,7 +7,7 @@ use crate::{
         ParseResult, SyntaxError, TokenStream,
         grammar::{
             BlockType, block, comma_separated_list, end_statement, expression,
-            interface_implementation, type_definition,
+            interface_implementation, type_definition, type_parameter_list,
         },
     },
 };
@@ -37,10 +38,14 @@ fn implementation_impl(tokens: &mut TokenStream) -> ParseResult<ImplementationNo
 }
 
 fn method(tokens: &mut TokenStream) -> ParseResult<ImplementationEntryNode> {
+    // Qualifiers may be given in either order, as in `static pub` or `pub static`
+    let mut is_static = tokens.accept(Keyword::Static);
     let public = tokens.accept(Keyword::Pub);
+    is_static = is_static || tokens.accept(Keyword::Static);
     let function = tokens.located(nested_function)?;
     Ok(ImplementationEntryNode::Method(Box::new(MethodNode {
         public,
+        is_static,
         function,
     })))
 }
@@ -71,7 +76,13 @@ pub fn function_signature(
     tokens: &mut TokenStream,
     name_type: NameType,
 ) -> ParseResult<FunctionSignatureNode> {
-    let name = tokens.name(name_type)?;
+    let is_method = !matches!(name_type, NameType::Function);
+    let name = if is_method && Keyword::Operator.matches(tokens.peek()) {
+        tokens.located(call_operator)?
+    } else {
+        tokens.name(name_type)?
+    };
+    let type_parameters = type_parameter_list(tokens)?;
     let parameters = tokens.located(parameters)?;
     let return_type = if tokens.accept(Symbol::Colon) {
         Some(tokens.located(type_definition)?)
@@ -79,7 +90,20 @@ pub fn function_signature(
         None
     };
 
-    Ok(FunctionSignatureNode::new(name, parameters, return_type))
+    Ok(FunctionSignatureNode::new(
+        name,
+        type_parameters,
+        parameters,
+        return_type,
+    ))
+}
+
+// The call operator is declared as a method named `operator()`
+fn call_operator(tokens: &mut TokenStream) -> ParseResult<String> {
+    tokens.next();
+    tokens.expect(Symbol::OpenParen, SyntaxError::ExpectedCallOperator)?;
+    tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCallOperator)?;
+    Ok(CALL_OPERATOR.to_owned())
 }
 
 fn function_body(tokens: &mut TokenStream) -> ParseResult<FunctionBodyNode> {
@@ -119,17 +143,18 @@ pub fn parameters(tokens: &mut TokenStream) -> ParseResult<Vec<Node<ParameterNod
 }
 
 fn parameter(tokens: &mut TokenStream) -> ParseResult<ParameterNode> {
+    let mutable = tokens.accept(Keyword::Mut);
     let name = tokens.name(NameType::Parameter)?;
     let error = SyntaxError::ExpectedType;
     match tokens.peek() {
         Token::Symbol(Symbol::Colon) => {
             tokens.next();
             let type_def = Some(tokens.located(type_definition)?);
-            Ok(ParameterNode::new(name, type_def))
+            Ok(ParameterNode::new(mutable, name, type_def))
         }
         Token::Symbol(Symbol::Comma | Symbol::CloseParen) => {
             tokens.push_error(error);
-            Ok(ParameterNode::new(name, None))
+            Ok(ParameterNode::new(mutable, name, None))
         }
         _ => Err(tokens.make_error(error)),
     }
@@ -1,8 +1,9 @@
 use crate::{
     lexer::{Symbol, TokenMatch},
     parser::{
-        NameType, Node, ParseResult, TokenStream, TypeParameterListNode, TypeParameterNode,
-        grammar::comma_separated_list,
+        NameType, Node, ParseResult, TokenStream, TypeNode, TypeParameterListNode,
+        TypeParameterNode,
+        grammar::{comma_separated_list, type_definition},
     },
 };
 
@@ -23,18 +24,25 @@ fn type_parameter_list_impl(tokens: &mut TokenStream) -> ParseResult<TypeParamet
 }
 
 fn type_parameter(tokens: &mut TokenStream) -> ParseResult<TypeParameterNode> {
+    let is_variadic = tokens.accept(Symbol::Ellipsis);
     let name = tokens.name(NameType::TypeParameter)?;
-    Ok(TypeParameterNode { name })
+    let bounds = if tokens.accept(Symbol::Colon) {
+        type_bounds(tokens)?
+    } else {
+        vec![]
+    };
+    Ok(TypeParameterNode {
+        name,
+        is_variadic,
+        bounds,
+    })
 }
 
-// fn type_bounds(tokens: &mut TokenStream) -> ParseResult<Vec<Node<UserDefinedTypeNode>>> {
-//     let mut bounds = vec![];
-//     while !Symbol::CloseBracket.matches(tokens.peek()) && !Symbol::Comma.matches(tokens.peek()) {
-//         bounds.push(tokens.located(user_defined_type)?);
-//         if tokens.accept(Symbol::Plus) {
-//             continue;
-//         }
-//     }
+fn type_bounds(tokens: &mut TokenStream) -> ParseResult<Vec<Node<TypeNode>>> {
+    let mut bounds = vec![tokens.located(type_definition)?];
+    while tokens.accept(Symbol::Plus) {
+        bounds.push(tokens.located(type_definition)?);
+    }
 
-//     Ok(bounds)
-// }
+    Ok(bounds)
+}
@@ -3,16 +3,19 @@ use crate::{
     parser::{
         FunctionSignatureNode, ImplementationEntryNode, InterfaceImplementationNode, InterfaceNode,
         NameType, Node, ParseResult, SyntaxError, TokenStream,
-        grammar::{end_statement, function_signature, nested_function},
+        grammar::{
+            end_statement, function_signature, named_type, nested_function, type_parameter_list,
+        },
     },
 };
 
 pub fn interface(tokens: &mut TokenStream) -> ParseResult<InterfaceNode> {
     tokens.next();
     let name = tokens.name(NameType::Interface)?;
+    let type_parameters = type_parameter_list(tokens)?;
     let method_signatures = tokens.located(method_signatures)?;
 
-    Ok(InterfaceNode::new(name, method_signatures))
+    Ok(InterfaceNode::new(name, type_parameters, method_signatures))
 }
 
 pub fn method_signatures(
@@ -41,11 +44,11 @@ pub fn method_signature(tokens: &mut TokenStream) -> ParseResult<FunctionSignatu
 }
 
 pub fn interface_implementation(tokens: &mut TokenStream) -> ParseResult<ImplementationEntryNode> {
-    let name = tokens.name(NameType::Interface)?;
+    let interface = tokens.located(|tokens| named_type(tokens, NameType::Interface))?;
     if tokens.accept(Symbol::Semicolon) {
         Ok(ImplementationEntryNode::Interface(
             InterfaceImplementationNode {
-                name,
+                interface,
                 methods: None,
             },
         ))
@@ -57,7 +60,7 @@ pub fn interface_implementation(tokens: &mut TokenStream) -> ParseResult<Impleme
         }
         Ok(ImplementationEntryNode::Interface(
             InterfaceImplementationNode {
-                name,
+                interface,
                 methods: Some(methods),
             },
         ))
@@ -67,10 +70,10 @@ pub fn interface_implementation(tokens: &mut TokenStream) -> ParseResult<Impleme
 }
 
 fn no_qualifiers(tokens: &mut TokenStream) {
-    if Keyword::Pub.matches(tokens.peek()) {
-        tokens.push_error(SyntaxError::UnexpectedMethodSignatureQualifier(
-            Keyword::Pub,
-        ));
-        tokens.next();
+    for keyword in [Keyword::Static, Keyword::Pub] {
+        if keyword.matches(tokens.peek()) {
+            tokens.push_error(SyntaxError::UnexpectedMethodSignatureQualifier(keyword));
+            tokens.next();
+        }
     }
 }
@@ -1,8 +1,9 @@
 use crate::{
     lexer::{Keyword, Symbol, Token, TokenMatch},
     parser::{
-        ExpressionNode, MatchCaseNode, MatchNode, MatchPatternNode, NameType, ParseResult,
-        StatementNode, SyntaxError, TokenStream, VariantMatchPattern,
+        ExpressionNode, MatchCaseNode, MatchNode, MatchPatternNode, NameType, NamedFieldPattern,
+        NamedStructMatchPattern, Node, ParseResult, StatementNode, StructMatchPattern, SyntaxError,
+        TokenStream, VariantMatchPattern,
         grammar::{end_statement, expression},
     },
 };
@@ -37,7 +38,7 @@ fn match_case(tokens: &mut TokenStream) -> ParseResult<MatchCaseNode> {
         tokens.next();
         pattern
     } else {
-        tokens.located_with(match_pattern, true)?
+        tokens.located_with(match_pattern, PatternContext::MatchCase)?
     };
     tokens.expect(Symbol::SkinnyArrow, SyntaxError::ExpectedMatchExpression)?;
     let expect_semicolon = !Symbol::OpenBrace.matches(tokens.peek());
@@ -60,33 +61,151 @@ fn match_case(tokens: &mut TokenStream) -> ParseResult<MatchCaseNode> {
 //     Ok(patterns)
 // }
 
-fn match_pattern(tokens: &mut TokenStream, top_level: bool) -> ParseResult<MatchPatternNode> {
+// Where a pattern appears, which decides how a bare name is parsed
+#[derive(Clone, Copy, PartialEq)]
+enum PatternContext {
+    MatchCase,
+    Nested,
+    // Declarations bind bare names, as they cannot match variants without payloads
+    Declaration,
+}
+
+// Parses the pattern following `let` or `mut`, such as `(left, right)` or `Point(x: a, y: _)`
+pub fn declaration_pattern(tokens: &mut TokenStream) -> ParseResult<MatchPatternNode> {
+    match_pattern(tokens, PatternContext::Declaration)
+}
+
+fn match_pattern(
+    tokens: &mut TokenStream,
+    context: PatternContext,
+) -> ParseResult<MatchPatternNode> {
+    let inner_context = match context {
+        PatternContext::MatchCase => PatternContext::Nested,
+        context => context,
+    };
     match tokens.peek() {
+        Token::Name(name) if name == "_" => {
+            tokens.next();
+            Ok(MatchPatternNode::Wildcard)
+        }
+        Token::Name(_)
+            if context == PatternContext::Declaration
+                && !Symbol::OpenParen.matches(tokens.peek_ahead(1)) =>
+        {
+            let name = tokens.name(NameType::PatternBinding)?;
+            Ok(MatchPatternNode::Binding(name))
+        }
         Token::Name(name) => {
             let name = tokens.current_span().wrap(name.clone());
             tokens.next();
-            if tokens.accept(Symbol::OpenParen) {
-                let inner_pattern = tokens.located_with(match_pattern, false)?;
-                tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCloseParen)?;
+            if !Symbol::OpenParen.matches(tokens.peek()) {
+                return Ok(MatchPatternNode::Variant(VariantMatchPattern {
+                    name,
+                    inner_pattern: None,
+                }));
+            }
+
+            if matches!(tokens.peek_ahead(1), Token::Name(_))
+                && Symbol::Colon.matches(tokens.peek_ahead(2))
+            {
+                let fields = named_field_patterns(tokens, inner_context)?;
+                return Ok(MatchPatternNode::NamedStruct(NamedStructMatchPattern {
+                    name,
+                    fields,
+                }));
+            }
+
+            let mut patterns = pattern_list(tokens, inner_context)?;
+            if patterns.len() == 1 {
                 Ok(MatchPatternNode::Variant(VariantMatchPattern {
                     name,
-                    inner_pattern: Some(Box::new(inner_pattern)),
+                    inner_pattern: patterns.pop().map(Box::new),
                 }))
             } else {
-                Ok(MatchPatternNode::Variant(VariantMatchPattern {
+                Ok(MatchPatternNode::Struct(StructMatchPattern {
                     name,
-                    inner_pattern: None,
+                    fields: patterns,
                 }))
             }
         }
+        Token::Symbol(Symbol::OpenParen) => {
+            let mut patterns = pattern_list(tokens, inner_context)?;
+            if patterns.len() == 1 {
+                // A single parenthesized pattern is not a tuple
+                return Ok(patterns.pop().unwrap().value);
+            }
+            Ok(MatchPatternNode::Tuple(patterns))
+        }
         Token::Keyword(Keyword::Let) => {
-            if top_level {
+            if context == PatternContext::MatchCase {
                 tokens.push_error(SyntaxError::UnexpectedBindingPattern);
             }
             tokens.next();
             let name = tokens.name(NameType::PatternBinding)?;
             Ok(MatchPatternNode::Binding(name))
         }
-        _ => Err(tokens.make_error(SyntaxError::ExpectedMatchPattern)),
+        Token::Symbol(Symbol::Minus) => {
+            let span = tokens.current_span();
+            tokens.next();
+            let Token::IntegerLiteral(literal) = tokens.peek() else {
+                return Err(tokens.make_error(SyntaxError::ExpectedMatchPattern));
+            };
+            let literal = span.expand_to(tokens).wrap(-*literal);
+            tokens.next();
+            Ok(MatchPatternNode::Literal(ExpressionNode::IntegerLiteral(
+                literal,
+            )))
+        }
+        token => {
+            let span = tokens.current_span();
+            let literal = match token {
+                Token::CharacterLiteral(literal) => {
+                    ExpressionNode::CharacterLiteral(literal.clone())
+                }
+                Token::IntegerLiteral(literal) => {
+                    ExpressionNode::IntegerLiteral(span.wrap(*literal))
+                }
+                Token::StringLiteral(literal) => ExpressionNode::StringLiteral(literal.clone()),
+                Token::Keyword(Keyword::True) => ExpressionNode::BooleanLiteral(true),
+                Token::Keyword(Keyword::False) => ExpressionNode::BooleanLiteral(false),
+                _ => return Err(tokens.make_error(SyntaxError::ExpectedMatchPattern)),
+            };
+            tokens.next();
+            Ok(MatchPatternNode::Literal(literal))
+        }
+    }
+}
+
+// Parses a parenthesized, comma separated list of patterns
+fn pattern_list(
+    tokens: &mut TokenStream,
+    context: PatternContext,
+) -> ParseResult<Vec<Node<MatchPatternNode>>> {
+    tokens.next();
+    let mut patterns = vec![tokens.located_with(match_pattern, context)?];
+    while tokens.accept(Symbol::Comma) {
+        patterns.push(tokens.located_with(match_pattern, context)?);
+    }
+    tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCloseParen)?;
+    Ok(patterns)
+}
+
+// Parses a parenthesized, comma separated list of `field: pattern` pairs
+fn named_field_patterns(
+    tokens: &mut TokenStream,
+    context: PatternContext,
+) -> ParseResult<Vec<NamedFieldPattern>> {
+    tokens.next();
+    let mut fields = vec![];
+    loop {
+        let name = tokens.name(NameType::Field)?;
+        tokens.expect(Symbol::Colon, SyntaxError::ExpectedFieldPattern)?;
+        let pattern = tokens.located_with(match_pattern, context)?;
+        fields.push(NamedFieldPattern { name, pattern });
+        if !tokens.accept(Symbol::Comma) {
+            break;
+        }
     }
+    tokens.expect(Symbol::CloseParen, SyntaxError::ExpectedCloseParen)?;
+    Ok(fields)
 }
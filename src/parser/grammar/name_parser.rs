@@ -20,3 +20,13 @@ pub fn name(tokens: &mut TokenStream, name_type: NameType) -> ParseResult<String
         _ => Err(tokens.make_error(SyntaxError::ExpectedName(name_type))),
     }
 }
+
+// The member following `.`, which for tuples can be the index of an element, as in `pair.0`
+pub fn member_name(tokens: &mut TokenStream) -> ParseResult<String> {
+    if let Token::IntegerLiteral(index) = tokens.peek() {
+        let index = index.to_string();
+        tokens.next();
+        return Ok(index);
+    }
+    name(tokens, NameType::Field)
+}
@@ -1,21 +1,49 @@
 use std::rc::Rc;
 
 use crate::{
-    lexer::{Keyword, Token},
+    lexer::{Keyword, Token, TokenMatch},
     parser::{
-        ExportableModuleDefinitionNode, ModuleDefinitionNode, ParseResult, ProgramNode,
-        SyntaxError, TokenStream,
-        grammar::{enumeration, interface, structure, top_level_function, type_alias},
+        ExportableModuleDefinitionNode, ImportNode, ModuleDefinitionNode, ParseResult, ProgramNode,
+        SyntaxError, TokenSpan, TokenStream,
+        grammar::{
+            end_statement, enumeration, interface, structure, top_level_function, type_alias,
+        },
     },
 };
 
-pub fn program(tokens: &mut TokenStream) -> ParseResult<ProgramNode> {
+pub fn program(tokens: &mut TokenStream) -> ProgramNode {
+    let mut imports = vec![];
     let mut definitions = vec![];
     while !tokens.is_done() {
-        let definition = tokens.located(exportable_module_definition)?;
-        definitions.push(definition);
+        let start_index = tokens.index();
+        let result = if Keyword::Import.matches(tokens.peek()) {
+            tokens.located(import).map(|node| imports.push(node))
+        } else {
+            tokens
+                .located(exportable_module_definition)
+                .map(|node| definitions.push(node))
+        };
+
+        // Invalid definitions are left out, so the rest of the program can still be checked
+        if let Err(error) = result {
+            tokens.recover_definition(error, start_index);
+        }
+    }
+    ProgramNode {
+        imports,
+        definitions,
     }
-    Ok(ProgramNode { definitions })
+}
+
+fn import(tokens: &mut TokenStream) -> ParseResult<ImportNode> {
+    tokens.next();
+    let Token::StringLiteral(path) = tokens.peek() else {
+        return Err(tokens.make_error(SyntaxError::ExpectedImportPath));
+    };
+    let path = TokenSpan::singleton(tokens).wrap(path.clone());
+    tokens.next();
+    end_statement(tokens);
+    Ok(ImportNode { path })
 }
 
 fn exportable_module_definition(
@@ -1,17 +1,21 @@
 use strum_macros::EnumIter;
 
 use crate::{
-    lexer::{Symbol, Token, TokenMatch},
+    lexer::{Keyword, Symbol, Token, TokenMatch},
     parser::{
-        ClosureParameterExpressionNode, ExpressionNode, FunctionCallExpressionNode,
-        LocatedSyntaxError, MemberTypeExpressionNode, MemberValueExpressionNode, NameType, Node,
-        Operator, ParseResult, SyntaxError, TokenStream, TypeBindingExpressionNode,
-        grammar::{bound_type_parameters, function_arguments, simple_closure, type_definition},
+        CastExpressionNode, ClosureParameterExpressionNode, ExpressionNode,
+        FunctionCallExpressionNode, LocatedSyntaxError, MemberTypeExpressionNode,
+        MemberValueExpressionNode, NameType, Node, Operator, ParseResult, SyntaxError, TokenStream,
+        TypeBindingExpressionNode,
+        grammar::{
+            bound_type_parameters, function_arguments, member_name, simple_closure, type_definition,
+        },
     },
 };
 
 #[derive(Clone, Copy, Debug, EnumIter)]
 pub enum SpecialOperator {
+    Cast,             // value as type
     Closure,          // param -> expr
     ClosureParameter, // value : type
     FunctionCall,     // fn ( params )
@@ -27,6 +31,7 @@ impl SpecialOperator {
         left: Node<ExpressionNode>,
     ) -> ParseResult<Node<ExpressionNode>> {
         match self {
+            Self::Cast => cast(tokens, left),
             Self::Closure => simple_closure(tokens, left),
             Self::ClosureParameter => closure_parameter(tokens, left),
             Self::FunctionCall => function_call(tokens, left),
@@ -41,6 +46,7 @@ impl Operator for SpecialOperator {
     fn as_token(&self) -> crate::lexer::Token {
         use Symbol as S;
         match self {
+            Self::Cast => Token::Keyword(Keyword::As),
             Self::Closure => Token::Symbol(S::SkinnyArrow),
             Self::ClosureParameter => Token::Symbol(S::Colon),
             Self::FunctionCall => Token::Symbol(S::OpenParen),
@@ -52,13 +58,26 @@ impl Operator for SpecialOperator {
 
     fn precedence(&self) -> i32 {
         match self {
-            Self::MemberType | Self::TypeBinding => 10,
-            Self::Closure | Self::FunctionCall | Self::MemberValue => 9,
+            Self::MemberType | Self::TypeBinding => 11,
+            Self::Closure | Self::FunctionCall | Self::MemberValue => 10,
+            // Casts bind more tightly than arithmetic, but less tightly than prefix operators
+            Self::Cast => 8,
             Self::ClosureParameter => 0,
         }
     }
 }
 
+fn cast(tokens: &mut TokenStream, left: Node<ExpressionNode>) -> ParseResult<Node<ExpressionNode>> {
+    tokens.next();
+    let target = tokens.located(type_definition)?;
+    let span = left.span.expand_to(tokens);
+    let result = ExpressionNode::Cast(CastExpressionNode {
+        expression: Box::new(left),
+        target,
+    });
+    Ok(span.wrap(result))
+}
+
 fn closure_parameter(
     tokens: &mut TokenStream,
     left: Node<ExpressionNode>,
@@ -71,6 +90,7 @@ fn closure_parameter(
             ClosureParameterExpressionNode {
                 name,
                 parameter_type,
+                is_spread: false,
             },
         )));
     }
@@ -119,7 +139,7 @@ fn member_value(
     left: Node<ExpressionNode>,
 ) -> ParseResult<Node<ExpressionNode>> {
     tokens.next();
-    let field = tokens.name(NameType::Field)?;
+    let field = tokens.located(member_name)?;
     let arguments = if Symbol::OpenParen.matches(tokens.peek()) {
         Some(tokens.located(function_arguments)?)
     } else {
@@ -1,9 +1,13 @@
 use crate::{
     lexer::{Keyword, Symbol, Token, TokenMatch},
     parser::{
-        DeclarationNode, ExpressionNode, IfStatementConditionNode, IfStatementNode, NameType, Node,
-        ParseResult, StatementNode, StatementType, SyntaxError, TokenStream, WhileLoopNode,
-        grammar::{BlockType, block, expression, match_statement, type_alias, type_definition},
+        DeclarationNode, ExpressionNode, ForLoopNode, IfStatementConditionNode, IfStatementNode,
+        MatchPatternNode, NameType, Node, ParseResult, StatementNode, StatementType, SyntaxError,
+        TokenStream, WhileLoopNode,
+        grammar::{
+            BlockType, block, declaration_pattern, expression, match_statement, type_alias,
+            type_definition,
+        },
     },
 };
 
@@ -17,6 +21,7 @@ pub fn statement(tokens: &mut TokenStream, block_type: BlockType) -> ParseResult
         Token::Keyword(K::Break) => Ok(break_statement(tokens)),
         Token::Keyword(K::Continue) => Ok(continue_statement(tokens)),
         Token::Keyword(K::While) => while_loop(tokens),
+        Token::Keyword(K::For) => for_loop(tokens),
         Token::Keyword(K::If) => if_statement(tokens),
         Token::Keyword(K::Match) => match_statement(tokens),
         Token::Keyword(K::Type) => type_alias_statement(tokens),
@@ -28,29 +33,51 @@ pub fn statement(tokens: &mut TokenStream, block_type: BlockType) -> ParseResult
 
 fn declaration(tokens: &mut TokenStream, mutable: bool) -> ParseResult<StatementNode> {
     tokens.next();
-    let name = tokens.name(NameType::Variable)?;
+    let pattern = Box::new(declaration_target(tokens)?);
     let type_def = if tokens.accept(Symbol::Colon) {
         Some(tokens.located(type_definition)?)
     } else {
         None
     };
 
-    let initializer = initializer(tokens)?;
+    // Declarations of a single name with a type annotation can be assigned later
+    let is_binding = matches!(pattern.value, MatchPatternNode::Binding(_));
+    let initializer =
+        if is_binding && type_def.is_some() && Symbol::Semicolon.matches(tokens.peek()) {
+            None
+        } else {
+            initializer(tokens)?
+        };
     end_statement(tokens);
     Ok(StatementNode::Declaration(DeclarationNode {
         mutable,
-        name,
+        pattern,
         type_def,
         initializer,
     }))
 }
 
+// Parses the name being declared, or a pattern such as `(left, right)` destructuring the initializer
+fn declaration_target(tokens: &mut TokenStream) -> ParseResult<Node<MatchPatternNode>> {
+    let is_pattern = match tokens.peek() {
+        Token::Symbol(Symbol::OpenParen) => true,
+        Token::Name(_) => Symbol::OpenParen.matches(tokens.peek_ahead(1)),
+        _ => false,
+    };
+    if is_pattern {
+        return tokens.located(declaration_pattern);
+    }
+
+    let name = tokens.name(NameType::Variable)?;
+    Ok(name.span.wrap(MatchPatternNode::Binding(name)))
+}
+
 fn initializer(tokens: &mut TokenStream) -> ParseResult<Option<Node<ExpressionNode>>> {
     let error = SyntaxError::ExpectedInitializer;
     match tokens.peek() {
         Token::Symbol(Symbol::Equal) => {
             tokens.next();
-            Ok(Some(tokens.located(expression)?))
+            Ok(Some(statement_expression(tokens)))
         }
         Token::Symbol(Symbol::Semicolon) => {
             tokens.push_error(error);
@@ -65,7 +92,7 @@ fn function_return(tokens: &mut TokenStream) -> ParseResult<StatementNode> {
     if tokens.accept(Symbol::Semicolon) {
         Ok(StatementNode::FunctionReturn(None))
     } else {
-        let expression = tokens.located(expression)?;
+        let expression = statement_expression(tokens);
         end_statement(tokens);
         Ok(StatementNode::FunctionReturn(Some(expression)))
     }
@@ -91,6 +118,22 @@ fn while_loop(tokens: &mut TokenStream) -> ParseResult<StatementNode> {
     Ok(StatementNode::WhileLoop(WhileLoopNode { predicate, body }))
 }
 
+fn for_loop(tokens: &mut TokenStream) -> ParseResult<StatementNode> {
+    tokens.next();
+    let mutable = tokens.accept(Keyword::Mut);
+    let variable = tokens.name(NameType::Variable)?;
+    tokens.expect(Keyword::In, SyntaxError::ExpectedIn)?;
+    let iterable = tokens.located(expression)?;
+    let block_type = BlockType::Statement(StatementType::ForLoop);
+    let body = tokens.located_with(block, block_type)?;
+    Ok(StatementNode::ForLoop(ForLoopNode {
+        mutable,
+        variable,
+        iterable,
+        body,
+    }))
+}
+
 fn if_statement(tokens: &mut TokenStream) -> ParseResult<StatementNode> {
     let mut conditions = vec![tokens.located(if_condition)?];
     let mut else_branch = None;
@@ -129,7 +172,7 @@ fn block_return(tokens: &mut TokenStream, block_type: BlockType) -> ParseResult<
     }
 
     tokens.next();
-    let expression = tokens.located(expression)?;
+    let expression = statement_expression(tokens);
     end_statement(tokens);
     match block_type {
         BlockType::Expression => Ok(StatementNode::BlockReturn(expression)),
@@ -143,11 +186,20 @@ fn type_alias_statement(tokens: &mut TokenStream) -> ParseResult<StatementNode>
 }
 
 fn expression_statement(tokens: &mut TokenStream) -> ParseResult<StatementNode> {
-    let expression = tokens.located(expression)?;
+    let expression = statement_expression(tokens);
     end_statement(tokens);
     Ok(StatementNode::Expression(expression.value))
 }
 
+// Parses the expression ending a statement, replacing it with an error if it is invalid
+fn statement_expression(tokens: &mut TokenStream) -> Node<ExpressionNode> {
+    let expression = tokens.located_or(expression, &[Symbol::Semicolon], ExpressionNode::Error);
+    if matches!(expression.value, ExpressionNode::Error) {
+        tokens.skip_stray_closes();
+    }
+    expression
+}
+
 pub fn end_statement(tokens: &mut TokenStream) {
     if !tokens.accept(Symbol::Semicolon) {
         tokens.push_error(SyntaxError::ExpectedEndStatement);
@@ -2,16 +2,22 @@ use crate::{
     lexer::{Keyword, Symbol, Token},
     parser::{
         NameType, Node, ParseResult, StructFieldNode, StructNode, SyntaxError, TokenStream,
-        grammar::{comma_separated_list, implementation, type_definition},
+        grammar::{comma_separated_list, implementation, type_definition, type_parameter_list},
     },
 };
 
 pub fn structure(tokens: &mut TokenStream) -> ParseResult<StructNode> {
     tokens.next();
     let name = tokens.name(NameType::Struct)?;
+    let type_parameters = type_parameter_list(tokens)?;
     let fields = tokens.located(fields)?;
     let implementation = implementation(tokens)?;
-    Ok(StructNode::new(name, fields, implementation))
+    Ok(StructNode::new(
+        name,
+        type_parameters,
+        fields,
+        implementation,
+    ))
 }
 
 fn fields(tokens: &mut TokenStream) -> ParseResult<Vec<Node<StructFieldNode>>> {
@@ -29,18 +35,22 @@ fn fields(tokens: &mut TokenStream) -> ParseResult<Vec<Node<StructFieldNode>>> {
 }
 
 fn field(tokens: &mut TokenStream) -> ParseResult<StructFieldNode> {
+    let is_spread = tokens.accept(Symbol::Ellipsis);
     let public = tokens.accept(Keyword::Pub);
+    let mutable = tokens.accept(Keyword::Mut);
     let name = tokens.name(NameType::Field)?;
     let error = SyntaxError::ExpectedType;
     match tokens.peek() {
         Token::Symbol(Symbol::Colon) => {
             tokens.next();
             let type_def = Some(tokens.located(type_definition)?);
-            Ok(StructFieldNode::new(public, name, type_def))
+            Ok(StructFieldNode::new(
+                is_spread, public, mutable, name, type_def,
+            ))
         }
         Token::Symbol(Symbol::Comma | Symbol::CloseParen) => {
             tokens.push_error(error);
-            Ok(StructFieldNode::new(public, name, None))
+            Ok(StructFieldNode::new(is_spread, public, mutable, name, None))
         }
         _ => Err(tokens.make_error(error)),
     }
@@ -1,5 +1,5 @@
 use crate::{
-    lexer::{Keyword, Symbol, Token},
+    lexer::{Keyword, Symbol, Token, TokenMatch},
     parser::{
         FunctionTypeNode, NameType, Node, ParseResult, PrimitiveType, SyntaxError, TokenStream,
         TupleTypeNode, TypeListElementNode, TypeListNode, TypeNode, UserDefinedTypeNode,
@@ -7,11 +7,14 @@ use crate::{
     },
 };
 
+const INFER: &str = "infer";
+
 pub fn type_definition(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
-    let inner_type = tokens.located(type_definition_impl)?;
+    let inner_type = tokens.located(optional_type)?;
     if tokens.accept(Symbol::ThickArrow) {
         let return_type = tokens.located(type_definition)?;
         let parameter = inner_type.span.wrap(TypeListElementNode {
+            name: None,
             is_spread: false,
             inner_type,
         });
@@ -27,9 +30,23 @@ pub fn type_definition(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
     }
 }
 
+// A type followed by `?` is sugar for the built-in `Option` of that type
+fn optional_type(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
+    let mut inner_type = type_definition_impl(tokens)?;
+    while tokens.accept(Symbol::QuestionMark) {
+        inner_type = TypeNode::Optional(Box::new(inner_type));
+    }
+    Ok(inner_type)
+}
+
 pub fn type_definition_impl(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
     let token = tokens.peek();
     match token {
+        // `infer` is only a keyword when it precedes the name it declares
+        Token::Name(name) if name == INFER && matches!(tokens.peek_ahead(1), Token::Name(_)) => {
+            tokens.next();
+            Ok(TypeNode::Infer(tokens.name(NameType::TypeParameter)?))
+        }
         Token::Name(_) => Ok(TypeNode::UserDefined(user_defined_type(tokens)?)),
         Token::Keyword(Keyword::Result) => {
             let span = tokens.current_span();
@@ -60,7 +77,14 @@ pub fn type_definition_impl(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
 }
 
 pub fn user_defined_type(tokens: &mut TokenStream) -> ParseResult<UserDefinedTypeNode> {
-    let name = tokens.name(NameType::Type)?;
+    named_type(tokens, NameType::Type)
+}
+
+pub fn named_type(
+    tokens: &mut TokenStream,
+    name_type: NameType,
+) -> ParseResult<UserDefinedTypeNode> {
+    let name = tokens.name(name_type)?;
     let bound_type_parameters = if tokens.accept(Symbol::OpenBracket) {
         Some(tokens.located(bound_type_parameters)?)
     } else {
@@ -83,7 +107,25 @@ fn function_or_tuple_type(tokens: &mut TokenStream) -> ParseResult<TypeNode> {
             Box::new(return_type),
         )))
     } else {
-        Ok(TypeNode::Tuple(TupleTypeNode::new(type_list)))
+        // A single unnamed type is only grouped, unless it is written as `(T,)`, in which case a
+        // comma comes between it and the closing parenthesis
+        let has_trailing_comma = type_list
+            .elements
+            .last()
+            .is_some_and(|element| tokens.index() > element.span.end_index + 2);
+        match type_list.elements.as_slice() {
+            [element] if !has_trailing_comma && element.name.is_none() && !element.is_spread => {
+                Ok(type_list
+                    .elements
+                    .into_iter()
+                    .next()
+                    .unwrap()
+                    .value
+                    .inner_type
+                    .value)
+            }
+            _ => Ok(TypeNode::Tuple(TupleTypeNode::new(type_list))),
+        }
     }
 }
 
@@ -100,9 +142,18 @@ fn type_list(tokens: &mut TokenStream) -> ParseResult<TypeListNode> {
 }
 
 fn type_list_element(tokens: &mut TokenStream) -> ParseResult<TypeListElementNode> {
+    let name = match tokens.peek() {
+        Token::Name(_) if Symbol::Colon.matches(tokens.peek_ahead(1)) => {
+            let name = tokens.name(NameType::Field)?;
+            tokens.next();
+            Some(name)
+        }
+        _ => None,
+    };
     let is_spread = tokens.accept(Symbol::Ellipsis);
     let inner_type = tokens.located(type_definition)?;
     Ok(TypeListElementNode {
+        name,
         is_spread,
         inner_type,
     })
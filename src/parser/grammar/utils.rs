@@ -1,5 +1,5 @@
 use crate::{
-    lexer::Symbol,
+    lexer::{Symbol, TokenMatch},
     parser::{Node, ParseResult, SyntaxError, TokenStream},
 };
 
@@ -10,11 +10,29 @@ pub fn comma_separated_list<T>(
 ) -> ParseResult<Vec<Node<T>>> {
     let mut entries = vec![];
     while !tokens.accept(close_symbol) {
-        entries.push(tokens.located(&parse_entry)?);
+        match tokens.located(&parse_entry) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                // Invalid entries are left out, unless the rest of the list cannot be found
+                tokens.recover(error, &[Symbol::Comma, close_symbol]);
+                if !Symbol::Comma.matches(tokens.peek()) && !close_symbol.matches(tokens.peek()) {
+                    break;
+                }
+            }
+        }
         if tokens.accept(close_symbol) {
             break;
         }
         if !tokens.accept(Symbol::Comma) {
+            // A list which is never closed ends before the next definition
+            if tokens.at_definition() {
+                let error = match close_symbol {
+                    Symbol::CloseParen => SyntaxError::ExpectedCloseParen,
+                    Symbol::CloseBracket => SyntaxError::ExpectedCloseBracket,
+                    _ => SyntaxError::ExpectedComma,
+                };
+                return Err(tokens.make_error(error));
+            }
             tokens.push_error(SyntaxError::ExpectedComma);
         }
     }
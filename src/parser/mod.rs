@@ -1,9 +1,11 @@
 mod grammar;
+mod module_loader;
 mod nodes;
 mod token_span;
 mod token_stream;
 
 pub use grammar::*;
+pub use module_loader::*;
 pub use nodes::*;
 pub use token_span::*;
 pub use token_stream::*;
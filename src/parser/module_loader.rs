@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    fs::canonicalize,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    diagnostics::{Diagnostic, DiagnosticSink, Phase},
+    lexer::SourceCode,
+    parser::{ProgramNode, TokenSpan, program},
+};
+
+// The definitions available in every module without importing them
+const PRELUDE_PATH: &str = "<prelude>";
+const PRELUDE_SOURCE: &str = include_str!("../prelude.txt");
+pub const PRELUDE_MODULE: usize = 0;
+
+// A parsed source file, with the indices of the modules it imports
+pub struct Module {
+    pub source: Rc<SourceCode>,
+    pub program: ProgramNode,
+    // Parallel to the program's imports, with `None` where the import could not be loaded
+    pub imports: Vec<Option<usize>>,
+    // The prelude is implicitly imported by every module other than itself
+    pub prelude: Option<usize>,
+}
+
+enum ModuleState {
+    Loading,
+    Loaded(usize),
+}
+
+// Reads and parses a source file and every file it imports, directly or indirectly
+pub struct ModuleLoader<'a> {
+    diagnostics: &'a DiagnosticSink,
+    // Modules are ordered so that each module comes after the modules it imports
+    modules: Vec<Module>,
+    states: HashMap<PathBuf, ModuleState>,
+    // The files currently being loaded, each importing the next
+    stack: Vec<PathBuf>,
+}
+
+impl<'a> ModuleLoader<'a> {
+    pub fn new(diagnostics: &'a DiagnosticSink) -> Self {
+        Self {
+            diagnostics,
+            modules: vec![],
+            states: HashMap::new(),
+            stack: vec![],
+        }
+    }
+
+    // Loads the main file of a program, which will be the last of the modules, after the prelude
+    pub fn load_program(mut self, path: &str) -> Result<Vec<Module>, String> {
+        let canonical_path = canonicalize(path).map_err(|error| error.to_string())?;
+        self.load_prelude();
+        self.load(path, canonical_path)?;
+        Ok(self.modules)
+    }
+
+    fn load_prelude(&mut self) {
+        let source = self.diagnostics.sources.add(PRELUDE_PATH, PRELUDE_SOURCE);
+        let program = self.parse(&source);
+        self.modules.push(Module {
+            source,
+            program,
+            imports: vec![],
+            prelude: None,
+        });
+    }
+
+    fn load(&mut self, path: &str, canonical_path: PathBuf) -> Result<usize, String> {
+        let source = self
+            .diagnostics
+            .sources
+            .read(path)
+            .map_err(|error| error.to_string())?;
+        let program = self.parse(&source);
+
+        self.states
+            .insert(canonical_path.clone(), ModuleState::Loading);
+        self.stack.push(canonical_path.clone());
+        let imports = program
+            .imports
+            .iter()
+            .map(|import| self.load_import(path, &import.path.value, import.path.span))
+            .collect();
+        self.stack.pop();
+
+        let index = self.modules.len();
+        self.modules.push(Module {
+            source,
+            program,
+            imports,
+            prelude: Some(PRELUDE_MODULE),
+        });
+        self.states
+            .insert(canonical_path, ModuleState::Loaded(index));
+        Ok(index)
+    }
+
+    fn parse(&self, source: &Rc<SourceCode>) -> ProgramNode {
+        for error in &source.tokenizer_errors {
+            self.diagnostics.report(error.to_diagnostic(source));
+        }
+
+        let mut tokens = source.token_stream();
+        let program = program(&mut tokens);
+        for error in &tokens.errors {
+            self.diagnostics.report(error.to_diagnostic(source));
+        }
+        program
+    }
+
+    fn report_error(&self, span: TokenSpan, message: &str, label: &str) {
+        let span = self.diagnostics.span(span);
+        let diagnostic = Diagnostic::error(span, message, label).with_phase(Phase::Loader);
+        self.diagnostics.report(diagnostic);
+    }
+
+    fn load_import(&mut self, importer: &str, path: &str, span: TokenSpan) -> Option<usize> {
+        let directory = Path::new(importer).parent().unwrap_or(Path::new(""));
+        let path = directory.join(path).to_string_lossy().into_owned();
+        let Ok(canonical_path) = canonicalize(&path) else {
+            self.report_error(
+                span,
+                &format!("Could not find imported file `{path}`"),
+                "no such file",
+            );
+            return None;
+        };
+
+        match self.states.get(&canonical_path) {
+            Some(ModuleState::Loaded(index)) => Some(*index),
+            Some(ModuleState::Loading) => {
+                let cycle_start = self
+                    .stack
+                    .iter()
+                    .position(|loading| *loading == canonical_path)
+                    .unwrap_or(0);
+                let cycle = self.stack[cycle_start..]
+                    .iter()
+                    .chain([&canonical_path])
+                    .map(|path| format!("`{}`", file_name(path)))
+                    .collect::<Vec<_>>();
+                self.report_error(
+                    span,
+                    "Import cycle",
+                    &format!("imports form a cycle: {}", cycle.join(" -> ")),
+                );
+                None
+            }
+            None => match self.load(&path, canonical_path) {
+                Ok(index) => Some(index),
+                Err(error) => {
+                    self.report_error(
+                        span,
+                        &format!("Could not read imported file `{path}`"),
+                        &error,
+                    );
+                    None
+                }
+            },
+        }
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
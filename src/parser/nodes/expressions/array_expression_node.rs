@@ -17,7 +17,9 @@ impl ArrayExpressionNode {
         let mut resolved_type = None;
 
         for node in self.elements.iter() {
-            let (new_scope, element_type) = node.check_expected(scope, expected_element_type);
+            // Later elements are expected to have the type of the earlier ones
+            let expected = expected_element_type.or(resolved_type.as_ref()).cloned();
+            let (new_scope, element_type) = node.check_expected(scope, expected.as_ref());
             scope = new_scope;
 
             if let Some(t) = resolved_type.as_ref() {
@@ -26,7 +28,7 @@ impl ArrayExpressionNode {
                 } else if t.is_assignable_to(&element_type, &scope) {
                     resolved_type = Some(element_type);
                 } else {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         node.span,
                         "Mismatching array types",
                         &format!(
@@ -44,7 +46,7 @@ impl ArrayExpressionNode {
         } else if let Some(expected_element_type) = expected_element_type {
             (scope, Type::Array(Box::new(expected_element_type.clone())))
         } else {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.elements.span,
                 "Empty array is ambiguous",
                 "could not infer type of empty array",
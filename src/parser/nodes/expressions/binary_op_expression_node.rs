@@ -1,6 +1,15 @@
+use std::rc::Rc;
+
 use crate::{
-    checker::{Scope, Type},
-    parser::{BinaryOperator, ExpressionNode, Node, Operator, PrimitiveType, TokenSpan},
+    checker::{
+        Assignment, Assignments, LoopAssignment, OperatorInterface, RANGE_TYPE, Scope, ScopeType,
+        StructMemberType, StructType, Type, Types, is_overloadable,
+    },
+    diagnostics::Diagnostic,
+    parser::{
+        BinaryOperator, ExpressionNode, NameNode, Node, Operator, PrimitiveType, TokenSpan,
+        check_function_call, get_field,
+    },
 };
 
 pub struct BinaryOpExpressionNode {
@@ -13,23 +22,20 @@ impl BinaryOpExpressionNode {
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         use BinaryOperator as O;
         match *self.operator {
-            O::Add
-            | O::AddAssign
-            | O::Subtract
+            O::Add | O::Subtract | O::Multiply | O::Divide | O::Mod => {
+                self.check_arithmetic_op(scope, expected_type)
+            }
+            O::AddAssign
             | O::SubtractAssign
-            | O::Multiply
             | O::MultiplyAssign
-            | O::Divide
             | O::DivideAssign
-            | O::Mod
-            | O::ModAssign
-            | O::Assign
-            | O::Equal
-            | O::NotEqual
-            | O::LessThan
-            | O::LessThanOrEqual
-            | O::GreaterThan
-            | O::GreaterThanOrEqual => todo_unimplemented_operator(scope),
+            | O::ModAssign => self.check_compound_assignment(scope),
+            O::Assign => self.check_assignment(scope),
+            O::Equal | O::NotEqual => self.check_equality_op(scope),
+            O::LessThan | O::LessThanOrEqual | O::GreaterThan | O::GreaterThanOrEqual => {
+                self.check_comparison_op(scope)
+            }
+            O::Range | O::RangeInclusive => self.check_range_op(scope),
             O::FunctionApplication => self.check_function_application(scope, expected_type),
             // TODO can we remove this panic somehow?
             O::Comma => panic!("ERROR: How did we get here?"),
@@ -49,7 +55,7 @@ impl BinaryOpExpressionNode {
 
         if let Some(function_type) = function_type {
             if function_type.parameters.len() != 1 {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.right.span,
                     "Applied function must take only one parameter",
                     &format!("type: `{}`", Type::Function(function_type.clone())),
@@ -59,7 +65,7 @@ impl BinaryOpExpressionNode {
             if !function_type.parameters.is_empty()
                 && !left_type.is_assignable_to(&function_type.parameters[0], &scope)
             {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.left.span,
                     "Function application argument does not match parameter type",
                     &format!(
@@ -72,7 +78,7 @@ impl BinaryOpExpressionNode {
             (scope, *function_type.return_type.clone())
         } else {
             if !right_type.is_error() {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.right.span,
                     "Cannot apply function",
                     &format!("type `{right_type}` is not callable"),
@@ -90,14 +96,379 @@ impl BinaryOpExpressionNode {
             self.print_operand_error(&scope, self.left.span, &bool_type, &left_type);
         }
 
-        let (scope, right_type) = self.right.check(scope);
+        // The right operand is only evaluated when the left one does not decide the result
+        let before = scope.get_assignments();
+        let (mut scope, right_type) = self.right.check(scope);
         if !right_type.is_primitive(PrimitiveType::Bool) {
             self.print_operand_error(&scope, self.right.span, &bool_type, &right_type);
         }
+        let mut paths = vec![before];
+        if !self.right.diverges() {
+            paths.push(scope.get_assignments());
+        }
+        scope.set_assignments(&Assignments::join(paths));
+
+        (scope, Type::Primitive(PrimitiveType::Bool))
+    }
+
+    // Ranges of integers are values of the `Range` struct from the prelude
+    fn check_range_op(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let int_type = Type::Primitive(PrimitiveType::Int);
+
+        let (scope, left_type) = self.left.check_expected(scope, Some(&int_type));
+        if !left_type.is_primitive(PrimitiveType::Int) {
+            self.print_operand_error(&scope, self.left.span, &int_type, &left_type);
+        }
+
+        let (scope, right_type) = self.right.check_expected(scope, Some(&int_type));
+        if !right_type.is_primitive(PrimitiveType::Int) {
+            self.print_operand_error(&scope, self.right.span, &int_type, &right_type);
+        }
+
+        let range_type = scope.get_prelude_type(RANGE_TYPE).unwrap_or(Type::Error);
+        (scope, range_type)
+    }
+
+    fn check_arithmetic_op(
+        &self,
+        scope: Box<Scope>,
+        expected_type: Option<&Type>,
+    ) -> (Box<Scope>, Type) {
+        let expected_type = expected_type.filter(|t| t.is_numeric());
+        let (scope, left_type) = self.left.check_expected(scope, expected_type);
+        if is_overloadable(&left_type) {
+            return self.check_overloaded_op(scope, &left_type);
+        }
+
+        let (scope, right_type) = self.right.check_expected(scope, Some(&left_type));
+        let result_type = self.check_numeric_operands(&scope, &left_type, &right_type);
+        self.record_integer_type(&scope, &result_type);
+        (scope, result_type)
+    }
+
+    fn check_compound_assignment(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (scope, left_type) = self.check_place(scope);
+        if is_overloadable(&left_type) {
+            let (scope, result_type) = self.check_overloaded_op(scope, &left_type);
+            if !result_type.is_assignable_to(&left_type, &scope) {
+                scope.diagnostics.error(
+                    self.span(),
+                    &format!("Value not assignable to type `{left_type}`"),
+                    &format!("operator results in type: `{result_type}`"),
+                );
+            }
+            return (self.assign_name(scope), left_type);
+        }
+
+        let (scope, right_type) = self.right.check_expected(scope, Some(&left_type));
+        let result_type = self.check_numeric_operands(&scope, &left_type, &right_type);
+        self.record_integer_type(&scope, &result_type);
+        (self.assign_name(scope), left_type)
+    }
+
+    // Sized integer arithmetic is evaluated at the width of its operands
+    fn record_integer_type(&self, scope: &Scope, result_type: &Type) {
+        if let Type::Primitive(primitive) = result_type
+            && primitive.is_integer()
+        {
+            scope
+                .resolutions
+                .record_integer_type(self.operator.span, *primitive);
+        }
+    }
+
+    // Resolves the operator through the method of the left operand's operator interface
+    fn check_overloaded_op(&self, scope: Box<Scope>, left_type: &Type) -> (Box<Scope>, Type) {
+        let operator = OperatorInterface::from_binary(*self.operator)
+            .expect("Only operators with an interface are overloaded");
+        let token = self.operator.as_token();
+        let Some(method) = operator.expect_method(&scope, left_type, self.left.span, token) else {
+            let (scope, _) = self.right.check(scope);
+            return (scope, Type::Error);
+        };
+
+        let parameter_type = method.parameters.first();
+        let (scope, right_type) = self.right.check_expected(scope, parameter_type);
+        if let Some(parameter_type) = parameter_type
+            && !right_type.is_assignable_to(parameter_type, &scope)
+        {
+            scope.diagnostics.error(
+                self.right.span,
+                &format!("Operand not assignable to type `{parameter_type}`"),
+                &format!("found type: `{right_type}`"),
+            );
+        }
+
+        (scope, *method.return_type.clone())
+    }
+
+    fn span(&self) -> TokenSpan {
+        TokenSpan {
+            start_index: self.left.span.start_index,
+            end_index: self.right.span.end_index,
+        }
+    }
+
+    fn check_assignment(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (scope, left_type) = self.check_place(scope);
+        let (scope, right_type) =
+            self.right
+                .check_optional(scope, self.right.span, Some(&left_type));
+        if !right_type.is_assignable_to(&left_type, &scope) {
+            scope.diagnostics.error(
+                self.right.span,
+                &format!("Value not assignable to type `{left_type}`"),
+                &format!("found type: `{right_type}`"),
+            );
+        }
+
+        (self.assign_name(scope), left_type)
+    }
+
+    fn check_equality_op(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (scope, left_type) = self.left.check(scope);
+        // Types without an implementation of `Equal` are compared by value
+        if OperatorInterface::Equal
+            .get_method(&scope, &left_type)
+            .is_some()
+        {
+            let (scope, _) = self.check_overloaded_op(scope, &left_type);
+            return (scope, Type::Primitive(PrimitiveType::Bool));
+        }
+
+        let (scope, right_type) = self.right.check_expected(scope, Some(&left_type));
+        if !left_type.is_assignable_to(&right_type, &scope)
+            && !right_type.is_assignable_to(&left_type, &scope)
+        {
+            self.print_mismatched_operands_error(&scope, &left_type, &right_type);
+        }
 
         (scope, Type::Primitive(PrimitiveType::Bool))
     }
 
+    fn check_comparison_op(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (scope, left_type) = self.left.check(scope);
+        if is_overloadable(&left_type) {
+            let (scope, _) = self.check_overloaded_op(scope, &left_type);
+            return (scope, Type::Primitive(PrimitiveType::Bool));
+        }
+
+        let (scope, right_type) = self.right.check_expected(scope, Some(&left_type));
+        if !left_type.is_ordered() {
+            self.print_ordered_operand_error(&scope, self.left.span, &left_type);
+        } else if !right_type.is_ordered() {
+            self.print_ordered_operand_error(&scope, self.right.span, &right_type);
+        } else if !left_type.is_equivalent_to(&right_type, &scope) {
+            self.print_mismatched_operands_error(&scope, &left_type, &right_type);
+        }
+
+        (scope, Type::Primitive(PrimitiveType::Bool))
+    }
+
+    fn check_numeric_operands(&self, scope: &Scope, left_type: &Type, right_type: &Type) -> Type {
+        if !left_type.is_numeric() {
+            self.print_numeric_operand_error(scope, self.left.span, left_type);
+            Type::Error
+        } else if !right_type.is_numeric() {
+            self.print_numeric_operand_error(scope, self.right.span, right_type);
+            Type::Error
+        } else if !left_type.is_equivalent_to(right_type, scope) {
+            self.print_mismatched_operands_error(scope, left_type, right_type);
+            Type::Error
+        } else if left_type.is_error() {
+            right_type.clone()
+        } else {
+            left_type.clone()
+        }
+    }
+
+    fn check_place(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        match &self.left.value {
+            // The target of a plain assignment is not read, so it may be unassigned
+            ExpressionNode::Name(name) if *self.operator == BinaryOperator::Assign => {
+                name.resolve(scope, None)
+            }
+            ExpressionNode::Name(_) => self.left.check(scope),
+            ExpressionNode::SelfRef(name) => {
+                let (scope, place_type) = self.left.check(scope);
+                if let Some(Type::Struct(struct_type)) = scope.get_self_type() {
+                    self.check_mutable_field(&scope, &struct_type, name);
+                }
+                (scope, place_type)
+            }
+            ExpressionNode::Error => self.left.check(scope),
+            ExpressionNode::MemberValue(node) if node.arguments.is_none() => {
+                let (mut scope, left_type) = node.left.check(scope);
+                let field_type = get_field(&left_type, node.left.span, &node.field, &scope);
+                match &left_type {
+                    Type::Struct(struct_type) => {
+                        self.check_mutable_field(&scope, struct_type, &node.field);
+                    }
+                    Type::Tuple(_) if !field_type.is_error() => scope.diagnostics.error(
+                        self.left.span,
+                        &format!("Cannot assign to tuple element `{}`", node.field),
+                        "the elements of a tuple cannot be reassigned",
+                    ),
+                    _ => {}
+                }
+                self.check_mutable_root(&mut scope);
+                (scope, field_type)
+            }
+            // Arrays are indexed by calling them, so an element of an array is assignable
+            ExpressionNode::FunctionCall(node) => {
+                let (scope, function_type) = node.function.check(scope);
+                let (mut scope, element_type) = check_function_call(
+                    scope,
+                    node.function.span,
+                    &function_type,
+                    &node.arguments,
+                    None,
+                );
+                if matches!(function_type, Type::Array(_) | Type::Error) {
+                    self.check_mutable_root(&mut scope);
+                    (scope, element_type)
+                } else {
+                    self.print_invalid_place_error(&scope);
+                    (scope, Type::Error)
+                }
+            }
+            _ => {
+                let (scope, _) = self.left.check(scope);
+                self.print_invalid_place_error(&scope);
+                (scope, Type::Error)
+            }
+        }
+    }
+
+    // Records the assignment of a value named by the left hand side
+    fn assign_name(&self, mut scope: Box<Scope>) -> Box<Scope> {
+        if let ExpressionNode::Name(name) = &self.left.value {
+            self.check_mutable_value(&mut scope, name);
+            scope.assign_value(name);
+        }
+        scope
+    }
+
+    fn check_mutable_value(&self, scope: &mut Scope, name: &NameNode) {
+        let Some(entry) = scope.get_value_entry(name) else {
+            return;
+        };
+        if entry.mutable || entry.value.is_error() {
+            return;
+        }
+
+        // Immutable values without an initializer may be assigned exactly once. A closure may
+        // be called any number of times, while a loop only repeats the assignment if it can run
+        // the body again after it, which is checked when the loop ends.
+        let declaration = entry.span;
+        let repeated = match entry.assignment {
+            Assignment::Unassigned => {
+                if scope.is_declared_outside(name, |scope_type| {
+                    matches!(scope_type, ScopeType::Closure)
+                }) {
+                    true
+                } else {
+                    if scope.is_declared_outside(name, |scope_type| {
+                        matches!(scope_type, ScopeType::Loop)
+                    }) && let Some(depth) = scope.get_value_depth(name)
+                    {
+                        scope.record_loop_assignment(LoopAssignment {
+                            depth,
+                            name: name.to_string(),
+                            span: self.left.span,
+                            declaration,
+                        });
+                    }
+                    return;
+                }
+            }
+            Assignment::MaybeAssigned => true,
+            Assignment::Assigned => false,
+        };
+        report_assign_to_immutable(scope, self.left.span, name, declaration, repeated);
+    }
+
+    // Assigning to a field or element modifies the value of the binding which holds it
+    fn check_mutable_root(&self, scope: &mut Scope) {
+        if let Some(name) = get_place_root(scope, &self.left) {
+            self.check_mutable_value(scope, name);
+        }
+    }
+
+    fn check_mutable_field(&self, scope: &Scope, struct_type: &Rc<StructType>, name: &NameNode) {
+        // Promoted fields keep the mutability declared in their own struct
+        match struct_type.get_member(scope, name) {
+            Some(member) if matches!(member.member_type, StructMemberType::Field(_)) => {
+                if member.mutable {
+                    return;
+                }
+                let label = format!(
+                    "field `{name}` of struct `{}` is not declared as mutable",
+                    struct_type.name(),
+                );
+                let diagnostic = Diagnostic::error(
+                    scope.diagnostics.span(self.left.span),
+                    &format!("Cannot assign to immutable field `{name}`"),
+                    &label,
+                )
+                .with_code("assign-to-immutable")
+                .with_label(scope.diagnostics.span(member.span), "declared here")
+                .with_help(&format!("consider declaring it as `mut {name}`"));
+                scope.diagnostics.report(diagnostic);
+            }
+            Some(_) => {
+                scope.diagnostics.error(
+                    self.left.span,
+                    &format!("Cannot assign to method `{name}`"),
+                    "methods cannot be reassigned",
+                );
+            }
+            None => {}
+        }
+    }
+
+    fn print_invalid_place_error(&self, scope: &Scope) {
+        scope.diagnostics.error(
+            self.left.span,
+            &format!("Invalid left hand side of `{}`", self.operator.as_token()),
+            "cannot assign to this expression",
+        );
+    }
+
+    fn print_numeric_operand_error(&self, scope: &Scope, span: TokenSpan, found_type: &Type) {
+        scope.diagnostics.error(
+            span,
+            &format!(
+                "Operands of `{}` should be of a numeric type",
+                self.operator.as_token(),
+            ),
+            &format!("found type: `{found_type}`"),
+        );
+    }
+
+    fn print_ordered_operand_error(&self, scope: &Scope, span: TokenSpan, found_type: &Type) {
+        scope.diagnostics.error(
+            span,
+            &format!(
+                "Operands of `{}` should be of a numeric type or `{}`",
+                self.operator.as_token(),
+                PrimitiveType::Char,
+            ),
+            &format!("found type: `{found_type}`"),
+        );
+    }
+
+    fn print_mismatched_operands_error(&self, scope: &Scope, left_type: &Type, right_type: &Type) {
+        scope.diagnostics.error(
+            self.right.span,
+            &format!(
+                "Operands of `{}` should have matching types",
+                self.operator.as_token(),
+            ),
+            &format!("expected type `{left_type}`, found type: `{right_type}`"),
+        );
+    }
+
     fn print_operand_error(
         &self,
         scope: &Scope,
@@ -105,7 +476,7 @@ impl BinaryOpExpressionNode {
         expected_type: &Type,
         found_type: &Type,
     ) {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             &format!(
                 "Operands of `{}` should be of type `{expected_type}`",
@@ -116,6 +487,50 @@ impl BinaryOpExpressionNode {
     }
 }
 
-fn todo_unimplemented_operator(scope: Box<Scope>) -> (Box<Scope>, Type) {
-    (scope, Type::Error)
+// Reports an assignment to an immutable value, which is `repeated` when the value is declared
+// without an initializer and may already have been assigned
+pub fn report_assign_to_immutable(
+    scope: &Scope,
+    span: TokenSpan,
+    name: &str,
+    declaration: Option<TokenSpan>,
+    repeated: bool,
+) {
+    let (message, label) = match repeated {
+        true => (
+            format!("Cannot assign to immutable binding `{name}` more than once"),
+            format!("`{name}` may already have been assigned"),
+        ),
+        false => (
+            format!("Cannot assign to immutable binding `{name}`"),
+            format!("`{name}` is not declared as mutable"),
+        ),
+    };
+    let mut diagnostic = Diagnostic::error(scope.diagnostics.span(span), &message, &label)
+        .with_code("assign-to-immutable");
+    if let Some(declaration) = declaration {
+        diagnostic = diagnostic
+            .with_label(scope.diagnostics.span(declaration), "declared here")
+            .with_help(&format!("consider declaring it as `mut {name}`"));
+    }
+    scope.diagnostics.report(diagnostic);
+}
+
+// The binding holding a field or element, or `None` if it belongs to a temporary value
+fn get_place_root<'a>(scope: &Scope, place: &'a ExpressionNode) -> Option<&'a NameNode> {
+    match place {
+        ExpressionNode::Name(name) => Some(name),
+        ExpressionNode::MemberValue(node) if node.arguments.is_none() => {
+            get_place_root(scope, &node.left)
+        }
+        // Calling a function rather than indexing an array creates a new value
+        ExpressionNode::FunctionCall(node) => match &node.function.value {
+            ExpressionNode::Name(name) => scope
+                .get_value_entry(name)
+                .filter(|entry| matches!(entry.value, Type::Array(_)))
+                .map(|_| name),
+            function => get_place_root(scope, function),
+        },
+        _ => None,
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{
+    checker::{Scope, Type},
+    parser::{ExpressionNode, Node, PrimitiveType, TypeNode},
+};
+
+pub struct CastExpressionNode {
+    pub expression: Box<Node<ExpressionNode>>,
+    pub target: Node<TypeNode>,
+}
+
+impl CastExpressionNode {
+    pub fn check(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        self.target.check(&scope);
+        let target_type = self.target.get_type(&*scope, None, None);
+        let (scope, resolved_type) = self.expression.check(scope);
+        if !is_valid_cast(&scope, &resolved_type, &target_type) {
+            scope.diagnostics.error(
+                self.target.span,
+                &format!("Invalid cast from `{resolved_type}` to `{target_type}`"),
+                &cast_hint(&resolved_type, &target_type),
+            );
+        }
+
+        (scope, target_type)
+    }
+}
+
+// Values can be cast between numeric types, from `bool` and `char` to integers, and from `u8` to
+// `char`, as well as to any type they are already assignable to
+fn is_valid_cast(scope: &Scope, from: &Type, to: &Type) -> bool {
+    if from.is_error() || to.is_error() || from.is_assignable_to(to, scope) {
+        return true;
+    }
+
+    let (Type::Primitive(from), Type::Primitive(to)) = (from, to) else {
+        return false;
+    };
+
+    match (from, to) {
+        (PrimitiveType::Bool | PrimitiveType::Char, to) => to.is_integer(),
+        (PrimitiveType::U8, PrimitiveType::Char) => true,
+        (from, to) => from.is_numeric() && to.is_numeric(),
+    }
+}
+
+fn cast_hint(from: &Type, to: &Type) -> String {
+    match (from, to) {
+        (Type::Primitive(from), Type::Primitive(PrimitiveType::Char)) if from.is_integer() => {
+            format!("only `{}` can be cast to `{to}`", PrimitiveType::U8)
+        }
+        _ => format!("cannot convert `{from}` to `{to}`"),
+    }
+}
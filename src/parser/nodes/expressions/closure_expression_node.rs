@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    checker::{FunctionType, Scope, ScopeType, Type},
+    checker::{FunctionType, Scope, ScopeType, TupleType, Type},
     parser::{ClosureParameterExpressionNode, ExpressionNode, Node},
 };
 
@@ -13,12 +13,14 @@ pub struct ClosureExpressionNode {
 impl ClosureExpressionNode {
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         let function_type = get_expected_type(expected_type);
-        scope.nest_with(ScopeType::Closure, |scope| {
+        // The body runs when the closure is called, so its assignments are not definite afterwards
+        let before = scope.get_assignments();
+        let (mut scope, result_type) = scope.nest_with(ScopeType::Closure, |scope| {
             let (scope, parameters) = self.check_parameters(function_type.as_ref(), scope);
             let expected_return_type = function_type.map(|t| t.return_type.clone());
-            let (scope, return_type) = self
-                .body
-                .check_expected(scope, expected_return_type.as_deref());
+            let (scope, return_type) =
+                self.body
+                    .check_optional(scope, self.body.span, expected_return_type.as_deref());
 
             let some_parameter_is_error_type = parameters.iter().any(Type::is_error);
             if some_parameter_is_error_type || return_type.is_error() {
@@ -27,7 +29,9 @@ impl ClosureExpressionNode {
 
             let result_type = Type::Function(FunctionType::new(parameters, return_type));
             (scope, result_type)
-        })
+        });
+        scope.set_assignments(&before);
+        (scope, result_type)
     }
 
     fn check_parameters(
@@ -35,27 +39,41 @@ impl ClosureExpressionNode {
         expected_type: Option<&Rc<FunctionType>>,
         mut scope: Box<Scope>,
     ) -> (Box<Scope>, Vec<Type>) {
-        let parameter_types = self
-            .parameters
-            .iter()
-            .enumerate()
-            .map(|(index, parameter)| {
-                if let Some(parameter) = parameter {
-                    let parameter_type =
-                        get_parameter_type(parameter, index, expected_type, &scope);
-                    scope.add_value_or(&parameter.name, parameter_type.clone(), |scope| {
-                        scope.source.print_error(
-                            parameter.name.span,
-                            &format!("Duplicate parameter name `{}`", parameter.name),
-                            "closure already contains a parameter with this name",
-                        );
-                    });
-                    parameter_type
-                } else {
-                    Type::Error
+        let mut parameter_types = vec![];
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            let Some(parameter) = parameter else {
+                parameter_types.push(Type::Error);
+                continue;
+            };
+
+            let parameter_type = get_parameter_type(parameter, index, expected_type, &scope);
+            scope.add_value_or(&parameter.name, parameter_type.clone(), |scope| {
+                scope.diagnostics.error(
+                    parameter.name.span,
+                    &format!("Duplicate parameter name `{}`", parameter.name),
+                    "closure already contains a parameter with this name",
+                );
+            });
+
+            // A spread parameter takes the place of the parameters its tuple holds
+            if parameter.is_spread {
+                match parameter_type.spread_elements() {
+                    Some(elements) => parameter_types.extend(elements),
+                    None => {
+                        if !parameter_type.is_error() {
+                            scope.diagnostics.error(
+                                parameter.span,
+                                "Spread parameter should be a tuple",
+                                &format!("found type `{parameter_type}`"),
+                            );
+                        }
+                        parameter_types.push(Type::Error);
+                    }
                 }
-            })
-            .collect::<Vec<_>>();
+            } else {
+                parameter_types.push(parameter_type);
+            }
+        }
 
         (scope, parameter_types)
     }
@@ -67,13 +85,15 @@ fn get_parameter_type(
     expected_type: Option<&Rc<FunctionType>>,
     scope: &Scope,
 ) -> Type {
-    let expected_type = expected_type.and_then(|ft| ft.parameters.get(index).cloned());
+    let expected_type =
+        expected_type.and_then(|ft| get_expected_parameter_type(ft, index, parameter.is_spread));
     if let Some(given_type) = parameter.parameter_type.as_ref() {
+        given_type.check(scope);
         given_type.get_type(scope, None, None)
     } else if let Some(expected_type) = expected_type {
-        expected_type.clone()
+        expected_type
     } else {
-        scope.source.print_error(
+        scope.diagnostics.error(
             parameter.span,
             "Parameter type is ambiguous",
             "could not infer type of parameter",
@@ -82,6 +102,33 @@ fn get_parameter_type(
     }
 }
 
+// Positions after a spread variadic parameter are unknown, except to a spread parameter which
+// collects the remaining parameters
+fn get_expected_parameter_type(
+    function_type: &FunctionType,
+    index: usize,
+    is_spread: bool,
+) -> Option<Type> {
+    let spread_index = function_type
+        .parameters
+        .iter()
+        .position(|parameter_type| matches!(parameter_type, Type::Spread(_)));
+    if !is_spread {
+        return function_type
+            .parameters
+            .get(index)
+            .filter(|_| spread_index.is_none_or(|spread_index| index < spread_index))
+            .cloned();
+    }
+
+    let remaining = function_type.parameters.get(index..)?;
+    match remaining {
+        [Type::Spread(type_parameter)] => Some(Type::TypeParameter(type_parameter.clone())),
+        _ if spread_index.is_none() => Some(Type::Tuple(TupleType::new(remaining.to_vec()))),
+        _ => None,
+    }
+}
+
 fn get_expected_type(t: Option<&Type>) -> Option<Rc<FunctionType>> {
     match t {
         Some(Type::Function(function_type)) => Some(function_type.clone()),
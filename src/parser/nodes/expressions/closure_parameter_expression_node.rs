@@ -6,6 +6,8 @@ use crate::{
 pub struct ClosureParameterExpressionNode {
     pub name: NameNode,
     pub parameter_type: Option<Node<TypeNode>>,
+    // Declared as `...name`, collecting the remaining arguments into a tuple
+    pub is_spread: bool,
 }
 
 impl ClosureParameterExpressionNode {
@@ -14,7 +16,7 @@ impl ClosureParameterExpressionNode {
             .parameter_type
             .as_ref()
             .map_or(self.name.span, |node| node.span);
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             "unexpected type declaration",
             "type declarations should only appear in closure parameter lists",
@@ -13,7 +13,7 @@ impl DeferredMemberExpressionNode {
         let function_type = expected_type.and_then(|t| t.to_function());
         if let Some(function_type) = function_type {
             if function_type.parameters.len() != 1 {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     // TODO this span should cover the whole node...
                     self.field.span,
                     "Deferred member expression must be a single parameter function",
@@ -32,7 +32,7 @@ impl DeferredMemberExpressionNode {
                 &scope,
             );
             let (scope, result_type) = if let Some(arguments) = self.arguments.as_ref() {
-                check_function_call(scope, self.field.span, &field_type, arguments)
+                check_function_call(scope, self.field.span, &field_type, arguments, None)
             } else {
                 (scope, field_type)
             };
@@ -40,7 +40,7 @@ impl DeferredMemberExpressionNode {
             let function_type = FunctionType::simple(parameter_type, result_type);
             (scope, Type::Function(function_type))
         } else {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.field.span.before(),
                 "Deferred member type is ambiguous",
                 "could not infer type of implicit parameter",
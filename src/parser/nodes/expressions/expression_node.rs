@@ -1,12 +1,13 @@
 use crate::{
-    checker::{Scope, ScopeType, Type, Types},
+    checker::{Scope, ScopeType, Type, Types, make_optional, unwrap_optional},
     lexer::{EnumToken, Keyword},
     parser::{
-        ArrayExpressionNode, BinaryOpExpressionNode, BlockNode, ClosureExpressionNode,
-        ClosureParameterExpressionNode, DeferredMemberExpressionNode, FunctionCallExpressionNode,
-        IfExpressionNode, MatchNode, MemberTypeExpressionNode, MemberValueExpressionNode, NameNode,
-        PostfixOpExpressionNode, PrefixOpExpressionNode, PrimitiveType, SpreadNode, TokenSpan,
-        TupleExpressionNode, TypeBindingExpressionNode,
+        ArrayExpressionNode, BinaryOpExpressionNode, BlockNode, CastExpressionNode,
+        ClosureExpressionNode, ClosureParameterExpressionNode, DeferredMemberExpressionNode,
+        FunctionCallExpressionNode, IfExpressionNode, MatchNode, MemberTypeExpressionNode,
+        MemberValueExpressionNode, NameNode, NamedArgumentNode, Node, PostfixOpExpressionNode,
+        PrefixOpExpressionNode, PrimitiveType, SpreadNode, TokenSpan, TupleExpressionNode,
+        TypeBindingExpressionNode,
     },
 };
 
@@ -15,17 +16,20 @@ pub enum ExpressionNode {
     BinaryOp(BinaryOpExpressionNode),
     Block(BlockNode),
     BooleanLiteral(bool),
+    Cast(CastExpressionNode),
     CharacterLiteral(String),
     Closure(ClosureExpressionNode),
     ClosureParameter(ClosureParameterExpressionNode),
     DeferredMember(DeferredMemberExpressionNode),
+    FloatLiteral(Node<f64>),
     FunctionCall(FunctionCallExpressionNode),
     IfExpression(IfExpressionNode),
-    IntegerLiteral(i64),
+    IntegerLiteral(Node<i128>),
     Match(MatchNode),
     MemberType(MemberTypeExpressionNode),
     MemberValue(MemberValueExpressionNode),
     Name(NameNode),
+    NamedArgument(NamedArgumentNode),
     PostfixOp(PostfixOpExpressionNode),
     PrefixOp(PrefixOpExpressionNode),
     SelfRef(NameNode),
@@ -55,19 +59,29 @@ impl ExpressionNode {
                 (scope, resolved_type.unwrap_or(Type::Void))
             }
             Self::BooleanLiteral(_) => (scope, Type::Primitive(PrimitiveType::Bool)),
+            Self::Cast(node) => node.check(scope),
             Self::CharacterLiteral(_) => (scope, Type::Primitive(PrimitiveType::Char)),
             Self::Closure(node) => node.check(scope, expected_type),
             Self::ClosureParameter(node) => node.check(scope),
             Self::DeferredMember(node) => node.check(scope, expected_type),
+            Self::FloatLiteral(literal) => {
+                let resolved_type = check_float_literal(&scope, literal, expected_type);
+                (scope, resolved_type)
+            }
             Self::FunctionCall(node) => node.check(scope, expected_type),
             Self::IfExpression(node) => node.check(scope, expected_type),
-            Self::IntegerLiteral(_) => (scope, Type::Primitive(PrimitiveType::Int)),
+            Self::IntegerLiteral(literal) => {
+                let resolved_type =
+                    check_integer_literal(&scope, literal.span, literal.value, expected_type);
+                (scope, resolved_type)
+            }
             Self::Match(node) => node.check(scope, expected_type),
-            Self::MemberType(node) => node.check(scope),
+            Self::MemberType(node) => node.check(scope, expected_type),
             Self::MemberValue(node) => node.check(scope, expected_type),
             Self::Name(node) => node.check(scope, expected_type),
+            Self::NamedArgument(node) => node.check_invalid(scope, expected_type),
             Self::PostfixOp(node) => node.check(scope),
-            Self::PrefixOp(node) => node.check(scope),
+            Self::PrefixOp(node) => node.check(scope, expected_type),
             Self::SelfRef(name) => check_self_ref(scope, name),
             Self::SelfValue(span) => check_self_value(scope, *span),
             Self::Spread(node) => node.check_invalid(scope, expected_type),
@@ -84,7 +98,7 @@ impl ExpressionNode {
     pub fn check_type(&self, scope: Box<Scope>, span: TokenSpan) -> (Box<Scope>, Type) {
         let ExpressionNode::Name(name) = self else {
             let (scope, _) = self.check(scope);
-            scope.source.print_error(
+            scope.diagnostics.error(
                 span,
                 "Cannot use type member operator on an expression",
                 "must be a type",
@@ -99,23 +113,96 @@ impl ExpressionNode {
 
         (scope, result_type)
     }
+
+    // Whether evaluating the expression always leaves the enclosing loop or function
+    pub fn diverges(&self) -> bool {
+        matches!(self, Self::Block(block) if block.diverges())
+    }
+
+    // Checks an expression within which `?` can short circuit, making its type optional if it does
+    pub fn check_optional(
+        &self,
+        scope: Box<Scope>,
+        span: TokenSpan,
+        expected_type: Option<&Type>,
+    ) -> (Box<Scope>, Type) {
+        let (scope, (resolved_type, short_circuited)) =
+            scope.nest_with(ScopeType::ShortCircuit, |scope| {
+                let (scope, resolved_type) = self.check_expected(scope, expected_type);
+                let short_circuited = scope.is_short_circuited();
+                (scope, (resolved_type, short_circuited))
+            });
+
+        // An expression which is already optional yields `None` itself when short circuited
+        let is_optional = unwrap_optional(&*scope, &resolved_type).is_some();
+        if !short_circuited || is_optional || resolved_type.is_error() {
+            return (scope, resolved_type);
+        }
+
+        scope.resolutions.record_short_circuit(span);
+        let optional_type = make_optional(&*scope, resolved_type);
+        (scope, optional_type)
+    }
+}
+
+// Integer literals take on the expected integer type, and must fit within its range
+pub fn check_integer_literal(
+    scope: &Scope,
+    span: TokenSpan,
+    value: i128,
+    expected_type: Option<&Type>,
+) -> Type {
+    let literal_type = match expected_type {
+        Some(Type::Primitive(primitive)) if primitive.is_integer() => *primitive,
+        _ => PrimitiveType::Int,
+    };
+
+    if let Some((min, max)) = literal_type.get_range()
+        && (value < min || value > max)
+    {
+        scope.diagnostics.error(
+            span,
+            &format!("Integer literal out of range for `{literal_type}`"),
+            &format!("`{literal_type}` values range from `{min}` to `{max}`"),
+        );
+    }
+
+    Type::Primitive(literal_type)
+}
+
+// Float literals take on the expected float type, and must fit within its range
+fn check_float_literal(scope: &Scope, literal: &Node<f64>, expected_type: Option<&Type>) -> Type {
+    let literal_type = match expected_type {
+        Some(Type::Primitive(primitive)) if primitive.is_float() => *primitive,
+        _ => PrimitiveType::Float,
+    };
+
+    if literal_type == PrimitiveType::F32 && (literal.value as f32).is_infinite() {
+        scope.diagnostics.error(
+            literal.span,
+            &format!("Float literal out of range for `{literal_type}`"),
+            &format!("`{literal_type}` values cannot exceed `{:e}`", f32::MAX),
+        );
+    }
+
+    Type::Primitive(literal_type)
 }
 
 fn print_unknown_type_error(scope: &Scope, span: TokenSpan, name: &str) {
     if name == Keyword::Result.as_str() {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             "`Result` type not available outside of function bodies",
             "cannot use type `Result` here",
         );
     } else if name == Keyword::SelfType.as_str() {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             "`Self` type not available outside of struct or enum",
             "cannot use type `Self` here",
         );
     } else {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             &format!("Unknown type `{name}`"),
             "could not find a type with this name",
@@ -124,6 +211,10 @@ fn print_unknown_type_error(scope: &Scope, span: TokenSpan, name: &str) {
 }
 
 fn check_self_ref(scope: Box<Scope>, name: &NameNode) -> (Box<Scope>, Type) {
+    if is_static_method(&scope, name.span.before()) {
+        return (scope, Type::Error);
+    }
+
     // TODO maybe replace with using get_self_type and get_field from member_value_expression_node.rs
     let self_scope = scope.find_scope(|scope_type| matches!(scope_type, ScopeType::Struct(_)));
     if let Some(self_scope) = self_scope {
@@ -131,13 +222,13 @@ fn check_self_ref(scope: Box<Scope>, name: &NameNode) -> (Box<Scope>, Type) {
         if let Some(resolved_type) = resolved_type {
             return (scope, resolved_type);
         }
-        scope.source.print_error(
+        scope.diagnostics.error(
             name.span,
             &format!("Could not find member `{name}`"),
             "self type does not contain a member with this name",
         );
     } else {
-        scope.source.print_error(
+        scope.diagnostics.error(
             name.span.before(),
             "Self reference outside of struct or enum",
             "operator invalid outside of struct or enum",
@@ -148,11 +239,15 @@ fn check_self_ref(scope: Box<Scope>, name: &NameNode) -> (Box<Scope>, Type) {
 }
 
 fn check_self_value(scope: Box<Scope>, span: TokenSpan) -> (Box<Scope>, Type) {
+    if is_static_method(&scope, span) {
+        return (scope, Type::Error);
+    }
+
     let self_type = scope.get_self_type();
     if let Some(self_type) = self_type {
         (scope, self_type)
     } else {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             "Invalid `self` outside of struct or enum",
             "`self` value only available inside of struct or enum",
@@ -160,3 +255,16 @@ fn check_self_value(scope: Box<Scope>, span: TokenSpan) -> (Box<Scope>, Type) {
         (scope, Type::Error)
     }
 }
+
+// Reports use of the receiver within a static method
+fn is_static_method(scope: &Scope, span: TokenSpan) -> bool {
+    let is_static = scope.within(|scope_type| matches!(scope_type, ScopeType::StaticMethod));
+    if is_static {
+        scope.diagnostics.error(
+            span,
+            "Invalid `self` in static method",
+            "static methods have no `self` value",
+        );
+    }
+    is_static
+}
@@ -1,7 +1,7 @@
 use std::{cmp::min, rc::Rc};
 
 use crate::{
-    checker::{FunctionType, Scope, Type},
+    checker::{FunctionType, OperatorInterface, Scope, StructType, TupleType, Type, TypeInference},
     parser::{ExpressionNode, Node, NodeVec, SpreadNode, TokenSpan},
 };
 
@@ -13,7 +13,13 @@ pub struct FunctionCallExpressionNode {
 impl FunctionCallExpressionNode {
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         let (scope, function_type) = self.function.check_expected(scope, expected_type);
-        check_function_call(scope, self.function.span, &function_type, &self.arguments)
+        check_function_call(
+            scope,
+            self.function.span,
+            &function_type,
+            &self.arguments,
+            expected_type,
+        )
     }
 }
 
@@ -22,10 +28,49 @@ pub fn check_function_call(
     function_span: TokenSpan,
     left_type: &Type,
     arguments: &NodeVec<ExpressionNode>,
+    expected_type: Option<&Type>,
 ) -> (Box<Scope>, Type) {
-    let function_type = left_type.to_function();
+    // Arguments which could not be parsed have already been reported, and make the result unknown
+    let has_invalid_argument = arguments
+        .iter()
+        .any(|argument| matches!(argument.value, ExpressionNode::Error));
+    let left_type = if has_invalid_argument {
+        &Type::Error
+    } else {
+        left_type
+    };
+
+    // Values of other types may be called through their call operator
+    let function_type = left_type
+        .to_function()
+        .or_else(|| OperatorInterface::Call.get_method(&scope, left_type));
     match function_type {
-        Some(function_type) => check_valid_function_call(scope, &function_type, arguments),
+        Some(function_type) if has_field_arguments(arguments) => {
+            match get_constructed_struct(&scope, &function_type) {
+                Some(struct_type) => check_constructor_call(
+                    scope,
+                    function_span,
+                    &struct_type,
+                    &function_type,
+                    arguments,
+                    expected_type,
+                ),
+                None => check_valid_function_call(
+                    scope,
+                    function_span,
+                    &function_type,
+                    arguments,
+                    expected_type,
+                ),
+            }
+        }
+        Some(function_type) => check_valid_function_call(
+            scope,
+            function_span,
+            &function_type,
+            arguments,
+            expected_type,
+        ),
         None => check_invalid_function_call(scope, function_span, left_type, arguments),
     }
 }
@@ -37,7 +82,7 @@ fn check_invalid_function_call(
     arguments: &NodeVec<ExpressionNode>,
 ) -> (Box<Scope>, Type) {
     if !left_type.is_error() {
-        scope.source.print_error(
+        scope.diagnostics.error(
             function_span,
             "Cannot use value as a function",
             &format!("type `{left_type}` is not usable as a function"),
@@ -58,9 +103,12 @@ fn check_invalid_function_call(
 
 fn check_valid_function_call(
     mut scope: Box<Scope>,
+    function_span: TokenSpan,
     function_type: &Rc<FunctionType>,
     arguments: &NodeVec<ExpressionNode>,
+    expected_type: Option<&Type>,
 ) -> (Box<Scope>, Type) {
+    let mut inference = TypeInference::new(function_type);
     let mut argument_count = 0;
     for argument in arguments.iter() {
         if let ExpressionNode::Spread(spread_node) = &argument.value {
@@ -70,29 +118,17 @@ fn check_valid_function_call(
                 argument.span,
                 function_type,
                 argument_count,
+                &mut inference,
             );
         } else {
             let parameter_type = function_type.parameters.get(argument_count);
-            let (new_scope, argument_type) = argument.check_expected(scope, parameter_type);
-            scope = new_scope;
+            scope = check_argument(scope, argument, parameter_type, &mut inference);
             argument_count += 1;
-
-            let Some(parameter_type) = parameter_type else {
-                continue;
-            };
-
-            if !argument_type.is_assignable_to(parameter_type, &scope) {
-                scope.source.print_error(
-                    argument.span,
-                    "Argument not assignable to parameter type",
-                    &format!("expected type `{parameter_type}`, found type `{argument_type}`"),
-                );
-            }
         }
     }
 
     if argument_count > function_type.parameters.len() {
-        scope.source.print_error(
+        scope.diagnostics.error(
             arguments.span,
             "Too many arguments",
             &format!(
@@ -107,9 +143,30 @@ fn check_valid_function_call(
         );
     }
 
+    let function_type = if !function_type.is_generic() {
+        function_type.clone()
+    } else if argument_count < function_type.parameters.len() {
+        // Parameters not solved by the arguments given so far stay generic
+        let type_parameters = inference.finish_partial(&scope, function_span);
+        let partial_type = function_type.instantiate(&*scope, inference.get_bindings());
+        FunctionType::generic(
+            type_parameters,
+            partial_type.parameters.clone(),
+            *partial_type.return_type.clone(),
+        )
+    } else {
+        if let Some(expected_type) = expected_type {
+            inference.unify(&function_type.return_type, expected_type);
+        }
+        let bindings = inference.finish(&scope, function_span);
+        function_type.instantiate(&*scope, &bindings)
+    };
+
     if argument_count < function_type.parameters.len() {
+        scope.resolutions.record_partial_application(function_span);
         let remaining_parameters = &function_type.parameters[argument_count..];
-        let result_type = Type::Function(FunctionType::new(
+        let result_type = Type::Function(FunctionType::generic(
+            function_type.type_parameters.clone(),
             remaining_parameters.to_vec(),
             *function_type.return_type.clone(),
         ));
@@ -119,39 +176,300 @@ fn check_valid_function_call(
     }
 }
 
+// Checks an argument against its parameter type, which is missing for excess arguments
+fn check_argument(
+    scope: Box<Scope>,
+    argument: &Node<ExpressionNode>,
+    parameter_type: Option<&Type>,
+    inference: &mut TypeInference,
+) -> Box<Scope> {
+    let parameter_type =
+        parameter_type.map(|parameter_type| inference.bind(&scope, parameter_type));
+    let (scope, argument_type) = argument.check_expected(scope, parameter_type.as_ref());
+    let Some(parameter_type) = parameter_type else {
+        return scope;
+    };
+
+    inference.unify(&parameter_type, &argument_type);
+    let parameter_type = inference.bind(&scope, &parameter_type);
+    if !argument_type.is_assignable_to(&parameter_type, &scope) {
+        scope.diagnostics.error(
+            argument.span,
+            "Argument not assignable to parameter type",
+            &format!("expected type `{parameter_type}`, found type `{argument_type}`"),
+        );
+    }
+    scope
+}
+
+pub fn has_named_arguments(arguments: &[Node<ExpressionNode>]) -> bool {
+    arguments
+        .iter()
+        .any(|argument| matches!(argument.value, ExpressionNode::NamedArgument(_)))
+}
+
+// Whether the arguments of a constructor call may fill fields by name or from a leading spread of
+// the constructed struct, rather than only by position
+pub fn has_field_arguments(arguments: &[Node<ExpressionNode>]) -> bool {
+    has_named_arguments(arguments)
+        || arguments
+            .first()
+            .is_some_and(|argument| matches!(argument.value, ExpressionNode::Spread(_)))
+}
+
+// The struct built by a function, if the function is that struct's constructor
+fn get_constructed_struct(
+    scope: &Scope,
+    function_type: &Rc<FunctionType>,
+) -> Option<Rc<StructType>> {
+    match function_type.return_type.as_ref() {
+        Type::Struct(struct_type)
+            if Rc::ptr_eq(&struct_type.get_constructor(scope), function_type) =>
+        {
+            Some(struct_type.clone())
+        }
+        _ => None,
+    }
+}
+
+// A constructor call with named arguments or a leading spread. Named arguments fill fields by name
+// after any positional arguments, and a leading spread struct gives the value of every other field
+fn check_constructor_call(
+    mut scope: Box<Scope>,
+    function_span: TokenSpan,
+    struct_type: &Rc<StructType>,
+    function_type: &Rc<FunctionType>,
+    arguments: &NodeVec<ExpressionNode>,
+    expected_type: Option<&Type>,
+) -> (Box<Scope>, Type) {
+    let mut inference = TypeInference::new(function_type);
+    let mut is_assigned = vec![false; function_type.parameters.len()];
+    let mut has_base = false;
+    let mut position = 0;
+    let mut is_named = false;
+    for (index, argument) in arguments.iter().enumerate() {
+        let field_index = match &argument.value {
+            ExpressionNode::Spread(spread_node) if index == 0 => {
+                let element_count;
+                (scope, element_count) = check_leading_spread(
+                    scope,
+                    spread_node,
+                    argument.span,
+                    function_type,
+                    &mut inference,
+                );
+                match element_count {
+                    Some(element_count) if element_count > is_assigned.len() => {
+                        scope.diagnostics.error(
+                            argument.span,
+                            "Too many arguments",
+                            &format!(
+                                "struct `{}` has {} fields, but the tuple has {element_count}",
+                                struct_type.name(),
+                                is_assigned.len(),
+                            ),
+                        );
+                        is_assigned.fill(true);
+                        position = element_count;
+                    }
+                    Some(element_count) => {
+                        for is_assigned in is_assigned.iter_mut().take(element_count) {
+                            *is_assigned = true;
+                        }
+                        position = element_count;
+                    }
+                    None => has_base = true,
+                }
+                continue;
+            }
+            ExpressionNode::Spread(spread_node) => {
+                scope.diagnostics.error(
+                    argument.span,
+                    "Unexpected spread argument",
+                    "the struct to copy fields from must be the first argument",
+                );
+                scope = spread_node.expression.check(scope).0;
+                continue;
+            }
+            ExpressionNode::NamedArgument(named_argument) => {
+                is_named = true;
+                let field_index = struct_type.get_field_index(&named_argument.name.value);
+                if field_index.is_none() {
+                    scope.diagnostics.error(
+                        named_argument.name.span,
+                        &format!("Could not find field `{}`", named_argument.name.value),
+                        &format!("struct `{}` has no such field", struct_type.name()),
+                    );
+                }
+                let parameter_type =
+                    field_index.and_then(|field_index| function_type.parameters.get(field_index));
+                scope =
+                    check_argument(scope, &named_argument.value, parameter_type, &mut inference);
+                field_index
+            }
+            _ => {
+                if is_named {
+                    scope.diagnostics.error(
+                        argument.span,
+                        "Positional argument after named argument",
+                        "positional arguments must come before named arguments",
+                    );
+                }
+                let parameter_type = function_type.parameters.get(position);
+                if parameter_type.is_none() {
+                    scope.diagnostics.error(
+                        argument.span,
+                        "Too many arguments",
+                        &format!(
+                            "struct `{}` has no field at this position",
+                            struct_type.name()
+                        ),
+                    );
+                }
+                scope = check_argument(scope, argument, parameter_type, &mut inference);
+                position += 1;
+                parameter_type.map(|_| position - 1)
+            }
+        };
+
+        if let Some(field_index) = field_index
+            && std::mem::replace(&mut is_assigned[field_index], true)
+        {
+            scope.diagnostics.error(
+                argument.span,
+                "Duplicate field argument",
+                "a value for this field is given by an earlier argument",
+            );
+        }
+    }
+
+    if !has_base {
+        let missing_fields = struct_type
+            .get_field_names()
+            .into_iter()
+            .zip(is_assigned)
+            .filter(|(_, is_assigned)| !is_assigned)
+            .map(|(name, _)| format!("`{name}`"))
+            .collect::<Vec<_>>();
+        if !missing_fields.is_empty() {
+            scope.diagnostics.error(
+                arguments.span,
+                "Missing field arguments",
+                &format!("no value given for {}", missing_fields.join(", ")),
+            );
+        }
+    }
+
+    if let Some(expected_type) = expected_type {
+        inference.unify(&function_type.return_type, expected_type);
+    }
+    let bindings = inference.finish(&scope, function_span);
+    let function_type = function_type.instantiate(&*scope, &bindings);
+    (scope, *function_type.return_type.clone())
+}
+
+// The leading spread of a constructor call, which is either a value of the constructed struct
+// giving the value of every other field, or a tuple of positional arguments. Returns the number of
+// positional arguments, or `None` for a struct.
+fn check_leading_spread(
+    scope: Box<Scope>,
+    node: &SpreadNode,
+    span: TokenSpan,
+    function_type: &Rc<FunctionType>,
+    inference: &mut TypeInference,
+) -> (Box<Scope>, Option<usize>) {
+    let expected_type = match &node.expression.value {
+        ExpressionNode::Tuple(_) => Type::Tuple(TupleType::new(
+            function_type
+                .parameters
+                .iter()
+                .map(|parameter_type| inference.bind(&scope, parameter_type))
+                .collect(),
+        )),
+        _ => inference.bind(&scope, &function_type.return_type),
+    };
+    let (scope, base_type) = node.expression.check_expected(scope, Some(&expected_type));
+    if let Some(elements) = base_type.spread_elements() {
+        let spread_type = TupleType::new(elements);
+        let (scope, element_count) =
+            check_spread_elements(scope, span, function_type, 0, &spread_type, inference);
+        return (scope, Some(element_count));
+    }
+
+    inference.unify(&function_type.return_type, &base_type);
+    let expected_type = inference.bind(&scope, &function_type.return_type);
+    if !base_type.is_assignable_to(&expected_type, &scope) {
+        scope.diagnostics.error(
+            span,
+            "Spread argument not assignable to constructed type",
+            &format!("expected type `{expected_type}`, found type `{base_type}`"),
+        );
+    }
+    (scope, None)
+}
+
 fn check_spread_arg(
     scope: Box<Scope>,
     node: &SpreadNode,
     span: TokenSpan,
     function_type: &Rc<FunctionType>,
     parameter_index: usize,
+    inference: &mut TypeInference,
 ) -> (Box<Scope>, usize) {
     let expected_types = function_type
         .parameters
         .iter()
         .skip(parameter_index)
-        .cloned()
+        .map(|parameter_type| inference.bind(&scope, parameter_type))
         .collect::<Vec<_>>();
-    let expected_type = Some(Type::Tuple(Rc::new(expected_types)));
+    let expected_type = Some(Type::Tuple(TupleType::new(expected_types)));
 
     let (scope, spread_type) = node.check_valid(scope, expected_type.as_ref());
-    for (offset, argument_type) in spread_type.iter().enumerate() {
+    check_spread_elements(
+        scope,
+        span,
+        function_type,
+        parameter_index,
+        &spread_type,
+        inference,
+    )
+}
+
+// Checks the elements of a spread tuple against the parameters from the given index, returning
+// the index of the parameter after them
+fn check_spread_elements(
+    scope: Box<Scope>,
+    span: TokenSpan,
+    function_type: &Rc<FunctionType>,
+    parameter_index: usize,
+    spread_type: &Rc<TupleType>,
+    inference: &mut TypeInference,
+) -> (Box<Scope>, usize) {
+    let remaining_parameters = function_type.parameters.iter().skip(parameter_index);
+    for (parameter_type, argument_type) in remaining_parameters.zip(spread_type.elements.iter()) {
+        inference.unify(parameter_type, argument_type);
+    }
+
+    for (offset, argument_type) in spread_type.elements.iter().enumerate() {
         let parameter_type = function_type.parameters.get(parameter_index + offset);
 
         let Some(parameter_type) = parameter_type else {
             break;
         };
 
-        if !argument_type.is_assignable_to(parameter_type, &scope) {
+        if !argument_type.is_assignable_to(&inference.bind(&scope, parameter_type), &scope) {
             let end_index = min(
-                parameter_index + spread_type.len(),
+                parameter_index + spread_type.elements.len(),
                 function_type.parameters.len(),
             );
-            let expected_type = Type::Tuple(Rc::new(
-                function_type.parameters[parameter_index..end_index].to_vec(),
+            let expected_type = Type::Tuple(TupleType::new(
+                function_type.parameters[parameter_index..end_index]
+                    .iter()
+                    .map(|parameter_type| inference.bind(&scope, parameter_type))
+                    .collect(),
             ));
 
-            scope.source.print_error(
+            scope.diagnostics.error(
                 span,
                 "Spread argument not assignable to parameter types",
                 &format!(
@@ -164,5 +482,5 @@ fn check_spread_arg(
         }
     }
 
-    (scope, parameter_index + spread_type.len())
+    (scope, parameter_index + spread_type.elements.len())
 }
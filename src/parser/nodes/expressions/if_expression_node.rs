@@ -1,5 +1,5 @@
 use crate::{
-    checker::{Scope, Type},
+    checker::{Assignments, Scope, Type},
     parser::{ExpressionNode, Node, PrimitiveType},
 };
 
@@ -13,23 +13,34 @@ impl IfExpressionNode {
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         let (scope, predicate_type) = self.predicate.check(scope);
         if !predicate_type.is_primitive(PrimitiveType::Bool) {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.predicate.span,
                 "If expression predicate expected to be bool",
                 &format!("found type: `{predicate_type}`"),
             );
         }
 
-        let (scope, true_type) = self.if_true.check_expected(scope, expected_type);
+        let before = scope.get_assignments();
+        let mut paths = vec![];
+        let (mut scope, true_type) = self.if_true.check_expected(scope, expected_type);
+        if !self.if_true.diverges() {
+            paths.push(scope.get_assignments());
+        }
+        scope.set_assignments(&before);
+
         let expected_type = expected_type.or(Some(&true_type));
-        let (scope, false_type) = self.if_false.check_expected(scope, expected_type);
+        let (mut scope, false_type) = self.if_false.check_expected(scope, expected_type);
+        if !self.if_false.diverges() {
+            paths.push(scope.get_assignments());
+        }
+        scope.set_assignments(&Assignments::join(paths));
 
         if true_type.is_assignable_to(&false_type, &scope) {
             (scope, false_type)
         } else if false_type.is_assignable_to(&true_type, &scope) {
             (scope, true_type)
         } else {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.if_false.span,
                 "If expression branch types don't match",
                 &format!("true branch type `{true_type}` not compatible with false branch type `{false_type}`"),
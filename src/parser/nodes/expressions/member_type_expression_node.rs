@@ -1,6 +1,6 @@
 use crate::{
     checker::{Scope, Type},
-    parser::{ExpressionNode, NameNode, Node, check_private_member},
+    parser::{ExpressionNode, NameNode, Node, check_private_member, check_private_struct_member},
 };
 
 pub struct MemberTypeExpressionNode {
@@ -9,31 +9,66 @@ pub struct MemberTypeExpressionNode {
 }
 
 impl MemberTypeExpressionNode {
-    pub fn check(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+    pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         if let ExpressionNode::TypeBinding(binding) = &self.left.value {
-            let (scope, receiver_type) = binding.check(scope);
-            let resolved_type = self.get_static_field(&scope, &receiver_type);
+            let (scope, receiver_type) = binding.check_type(scope);
+            let resolved_type = self.get_static_field(&scope, &receiver_type, expected_type);
             return (scope, resolved_type);
         }
 
         let (scope, receiver_type) = self.left.check_type(scope, self.left.span);
-        let resolved_type = self.get_static_field(&scope, &receiver_type);
+        let resolved_type = self.get_static_field(&scope, &receiver_type, expected_type);
         (scope, resolved_type)
     }
 
-    fn get_static_field(&self, scope: &Scope, receiver_type: &Type) -> Type {
+    fn get_static_field(
+        &self,
+        scope: &Scope,
+        receiver_type: &Type,
+        expected_type: Option<&Type>,
+    ) -> Type {
+        let resolved_type = self.get_static_field_type(scope, receiver_type, expected_type);
+        scope
+            .resolutions
+            .record(self.left.span, receiver_type.clone());
+        scope
+            .resolutions
+            .record(self.field.span, resolved_type.clone());
+        resolved_type
+    }
+
+    fn get_static_field_type(
+        &self,
+        scope: &Scope,
+        receiver_type: &Type,
+        expected_type: Option<&Type>,
+    ) -> Type {
         match receiver_type {
             Type::Enum(enum_type) => {
-                if let Some(variant_type) = enum_type.get_variant(&self.field.value) {
-                    variant_type
+                if let Some(variant_type) = enum_type.get_variant(scope, &self.field.value) {
+                    // A variant without a value has nothing to infer the type arguments of a
+                    // generic enum from, so they are taken from the expected type
+                    match (&variant_type, expected_type) {
+                        (Type::Enum(_), Some(Type::Enum(expected_enum)))
+                            if enum_type.is_unbound()
+                                && expected_enum.name() == enum_type.name() =>
+                        {
+                            Type::Enum(expected_enum.clone())
+                        }
+                        _ => variant_type,
+                    }
                 } else if let Some(method) = enum_type.get_method(scope, &self.field) {
                     let receiver_type = Type::Enum(enum_type.clone());
                     if !method.public {
-                        check_private_member(scope, &receiver_type, &self.field);
+                        check_private_member(scope, &receiver_type, &self.field, method.span);
+                    }
+                    if method.is_static {
+                        Type::Function(method.function_type.clone())
+                    } else {
+                        method.function_type.clone().as_static_method(receiver_type)
                     }
-                    method.function_type.clone().as_static_method(receiver_type)
                 } else {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         self.field.span,
                         &format!("Could not find field `{}`", self.field),
                         &format!(
@@ -50,11 +85,11 @@ impl MemberTypeExpressionNode {
                 if let Some(member) = member {
                     let receiver_type = Type::Struct(struct_type.clone());
                     if !member.public {
-                        check_private_member(scope, &receiver_type, &self.field);
+                        check_private_struct_member(scope, &receiver_type, &self.field, member);
                     }
                     member.member_type.as_static_type(receiver_type)
                 } else {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         self.field.span,
                         &format!("Could not find field `{}`", self.field),
                         &format!(
@@ -69,7 +104,7 @@ impl MemberTypeExpressionNode {
             Type::Generic(_) => todo!("Implement member type operator for generic types"),
             Type::Error => Type::Error,
             _ => {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.left.span,
                     "Cannot access properties of this type",
                     &format!("type `{receiver_type}` does not have accessible properties"),
@@ -1,7 +1,8 @@
 use std::rc::Rc;
 
 use crate::{
-    checker::{FunctionType, Scope, Type, Types},
+    checker::{FunctionType, Scope, StructMember, StructMemberType, StructType, Type, Types},
+    diagnostics::Diagnostic,
     parser::{ExpressionNode, NameNode, Node, NodeVec, TokenSpan, check_function_call},
 };
 
@@ -22,7 +23,13 @@ impl MemberValueExpressionNode {
 
         let field_type = get_field(&left_type, self.left.span, &self.field, &scope);
         if let Some(arguments) = self.arguments.as_ref() {
-            check_function_call(scope, self.field.span, &field_type, arguments)
+            check_function_call(
+                scope,
+                self.field.span,
+                &field_type,
+                arguments,
+                expected_type,
+            )
         } else {
             (scope, field_type)
         }
@@ -40,7 +47,7 @@ impl MemberValueExpressionNode {
             &scope,
         );
         let (scope, result_type) = if let Some(arguments) = self.arguments.as_ref() {
-            check_function_call(scope, self.field.span, &field_type, arguments)
+            check_function_call(scope, self.field.span, &field_type, arguments, None)
         } else {
             (scope, field_type)
         };
@@ -64,8 +71,8 @@ pub fn get_field(
     scope: &Scope,
 ) -> Type {
     match input_type {
-        Type::Array(_) | Type::Void => {
-            scope.source.print_error(
+        Type::Array(_) | Type::Spread(_) | Type::Void => {
+            scope.diagnostics.error(
                 field.span.before(),
                 "Value member operator is not valid for this type",
                 &format!("type: `{input_type}`"),
@@ -76,11 +83,14 @@ pub fn get_field(
             let method = enum_type.get_method(scope, field);
             if let Some(method) = method {
                 if !method.public {
-                    check_private_member(scope, input_type, field);
+                    check_private_member(scope, input_type, field, method.span);
+                }
+                if method.is_static {
+                    check_static_member(scope, input_type, field);
                 }
                 Type::Function(method.function_type.clone())
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     field.span,
                     &format!("Could not find field `{field}`"),
                     &format!("enum `{}` has no such method `{field}`", enum_type.name()),
@@ -89,7 +99,7 @@ pub fn get_field(
             }
         }
         Type::Function(_) => {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 input_span,
                 "Cannot use value member operator on a function which returns another function",
                 &format!("returns type: `{input_type}`"),
@@ -102,7 +112,7 @@ pub fn get_field(
             if let Some(method) = method {
                 Type::Function(method.clone())
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     field.span,
                     &format!("Could not find method `{field}`"),
                     &format!(
@@ -118,11 +128,14 @@ pub fn get_field(
             let member = struct_type.get_member(scope, field);
             if let Some(member) = member {
                 if !member.public {
-                    check_private_member(scope, input_type, field);
+                    check_private_struct_member(scope, input_type, field, member);
+                }
+                if let StructMemberType::StaticMethod(_) = member.member_type {
+                    check_static_member(scope, input_type, field);
                 }
                 member.member_type.get_type()
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     field.span,
                     &format!("Could not find field `{field}`"),
                     &format!(
@@ -133,19 +146,122 @@ pub fn get_field(
                 Type::Error
             }
         }
-        Type::Tuple(_) => todo!("Implement value member operator for tuples"),
-        Type::TypeParameter(_) => todo!("Implement value member operator for type parameters"),
+        Type::Tuple(tuple_type) => match tuple_type.get_index(&field.value) {
+            Some(index) => {
+                scope.resolutions.record_tuple_index(field.span, index);
+                tuple_type.elements[index].clone()
+            }
+            None => {
+                scope.diagnostics.error(
+                    field.span,
+                    &format!("Could not find element `{field}`"),
+                    &format!("tuple type `{input_type}` has no such element"),
+                );
+                Type::Error
+            }
+        },
+        Type::TypeParameter(type_parameter) => {
+            let method = type_parameter
+                .get_bounds()
+                .iter()
+                .find_map(|bound| bound.methods.get(&field.value));
+            if let Some(method) = method {
+                Type::Function(method.clone())
+            } else {
+                scope.diagnostics.error(
+                    field.span,
+                    &format!("Could not find method `{field}`"),
+                    &format!(
+                        "no bound of type parameter `{type_parameter}` has a method `{field}`"
+                    ),
+                );
+                Type::Error
+            }
+        }
         Type::Error => Type::Error,
     }
 }
 
-pub fn check_private_member(scope: &Scope, receiver_type: &Type, field: &NameNode) {
+// Reports access to a member which is not `pub` from outside the implementation of its type
+pub fn check_private_member(
+    scope: &Scope,
+    receiver_type: &Type,
+    field: &NameNode,
+    declaration: TokenSpan,
+) {
+    let label = format!("`{field}` is not declared as `pub`");
+    print_private_member_error(scope, receiver_type, field, declaration, &label);
+}
+
+// A promoted member is private when the spread field it is promoted through is not `pub`
+pub fn check_private_struct_member(
+    scope: &Scope,
+    receiver_type: &Type,
+    field: &NameNode,
+    member: &StructMember,
+) {
+    match member.spread_field.as_ref() {
+        Some(spread_field) => {
+            let label = format!(
+                "`{field}` is promoted through `{}`, which is not declared as `pub`",
+                spread_field.name
+            );
+            print_private_member_error(scope, receiver_type, field, spread_field.span, &label);
+        }
+        None => check_private_member(scope, receiver_type, field, member.span),
+    }
+}
+
+fn print_private_member_error(
+    scope: &Scope,
+    receiver_type: &Type,
+    field: &NameNode,
+    declaration: TokenSpan,
+    label: &str,
+) {
     if is_external_private_access(scope, receiver_type) {
-        scope.source.print_error(
-            field.span,
+        let diagnostic = Diagnostic::error(
+            scope.diagnostics.span(field.span),
             &format!("Cannot access private member `{field}`"),
             &format!("this member is private to `{receiver_type}`"),
+        )
+        .with_code("private-access")
+        .with_label(scope.diagnostics.span(declaration), label);
+        scope.diagnostics.report(diagnostic);
+    }
+}
+
+// Reports access to a static method through a value rather than through its type
+fn check_static_member(scope: &Scope, receiver_type: &Type, field: &NameNode) {
+    let diagnostic = Diagnostic::error(
+        scope.diagnostics.span(field.span),
+        &format!("Cannot access static method `{field}` on a value"),
+        "static methods have no `self` value",
+    )
+    .with_help(&format!(
+        "call it through the type instead: `{receiver_type}::{field}`"
+    ));
+    scope.diagnostics.report(diagnostic);
+}
+
+// Reports use of the constructor of a struct with private fields from outside its implementation
+pub fn check_private_constructor(scope: &Scope, struct_type: &Rc<StructType>, name: &NameNode) {
+    let Some(field) = struct_type.get_private_field_node() else {
+        return;
+    };
+
+    if is_external_private_access(scope, &Type::Struct(struct_type.clone())) {
+        let diagnostic = Diagnostic::error(
+            scope.diagnostics.span(name.span),
+            &format!("Cannot use private constructor of `{}`", struct_type.name()),
+            &format!("struct `{}` has private fields", struct_type.name()),
+        )
+        .with_code("private-access")
+        .with_label(
+            scope.diagnostics.span(field.name.span),
+            &format!("`{}` is not declared as `pub`", field.name),
         );
+        scope.diagnostics.report(diagnostic);
     }
 }
 
@@ -1,5 +1,6 @@
 mod array_expression_node;
 mod binary_op_expression_node;
+mod cast_expression_node;
 mod closure_expression_node;
 mod closure_parameter_expression_node;
 mod deferred_member_expression_node;
@@ -8,6 +9,7 @@ mod function_call_expression_node;
 mod if_expression_node;
 mod member_type_expression_node;
 mod member_value_expression_node;
+mod named_argument_node;
 mod postfix_op_expression_node;
 mod prefix_op_expression_node;
 mod spread_node;
@@ -16,6 +18,7 @@ mod type_binding_expression_node;
 
 pub use array_expression_node::*;
 pub use binary_op_expression_node::*;
+pub use cast_expression_node::*;
 pub use closure_expression_node::*;
 pub use closure_parameter_expression_node::*;
 pub use deferred_member_expression_node::*;
@@ -24,6 +27,7 @@ pub use function_call_expression_node::*;
 pub use if_expression_node::*;
 pub use member_type_expression_node::*;
 pub use member_value_expression_node::*;
+pub use named_argument_node::*;
 pub use postfix_op_expression_node::*;
 pub use prefix_op_expression_node::*;
 pub use spread_node::*;
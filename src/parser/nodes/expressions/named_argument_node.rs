@@ -0,0 +1,26 @@
+use crate::{
+    checker::{Scope, Type},
+    parser::{ExpressionNode, NameNode, Node},
+};
+
+// An argument written as `name: value`, which initializes the constructor field of that name
+pub struct NamedArgumentNode {
+    pub name: NameNode,
+    pub value: Box<Node<ExpressionNode>>,
+}
+
+impl NamedArgumentNode {
+    pub fn check_invalid(
+        &self,
+        scope: Box<Scope>,
+        expected_type: Option<&Type>,
+    ) -> (Box<Scope>, Type) {
+        scope.diagnostics.error(
+            self.name.span,
+            "Unexpected named argument",
+            "named arguments must be arguments of a constructor call",
+        );
+
+        self.value.check_expected(scope, expected_type)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
-    checker::{Scope, Type},
-    parser::{ExpressionNode, Node, PostfixOperator},
+    checker::{Scope, Type, unwrap_optional},
+    diagnostics::Diagnostic,
+    parser::{ExpressionNode, Node, Operator, PostfixOperator},
 };
 
 pub struct PostfixOpExpressionNode {
@@ -10,7 +11,44 @@ pub struct PostfixOpExpressionNode {
 
 impl PostfixOpExpressionNode {
     pub fn check(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
-        // TODO implement type checking for postfix ops
-        (scope, Type::Error)
+        match *self.operator {
+            PostfixOperator::NullShortCircuit => self.check_null_short_circuit(scope),
+        }
+    }
+
+    // `?` unwraps an optional value, making the enclosing optional context `None` if it is empty
+    fn check_null_short_circuit(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (mut scope, operand_type) = self.expression.check(scope);
+        if !scope.short_circuit() {
+            let span = scope.diagnostics.span(self.operator.span);
+            let diagnostic = Diagnostic::error(
+                span,
+                &format!(
+                    "`{}` operator used outside of an optional context",
+                    self.operator.as_token()
+                ),
+                "there is no enclosing value which can be `None`",
+            )
+            .with_help("use `?` within a `let` initializer, assignment or returned value");
+            scope.diagnostics.report(diagnostic);
+        }
+
+        if operand_type.is_error() {
+            return (scope, Type::Error);
+        }
+        match unwrap_optional(&*scope, &operand_type) {
+            Some(inner_type) => (scope, inner_type),
+            None => {
+                scope.diagnostics.error(
+                    self.expression.span,
+                    &format!(
+                        "`{}` can only be applied to optional values",
+                        self.operator.as_token()
+                    ),
+                    &format!("found type: `{operand_type}`"),
+                );
+                (scope, Type::Error)
+            }
+        }
     }
 }
@@ -1,6 +1,9 @@
 use crate::{
-    checker::{Scope, Type},
-    parser::{ExpressionNode, Node, Operator, PrefixOperator, PrimitiveType},
+    checker::{OperatorInterface, Scope, Type, is_overloadable},
+    parser::{
+        ExpressionNode, Node, Operator, PrefixOperator, PrimitiveType, TokenSpan,
+        check_integer_literal,
+    },
 };
 
 pub struct PrefixOpExpressionNode {
@@ -9,17 +12,21 @@ pub struct PrefixOpExpressionNode {
 }
 
 impl PrefixOpExpressionNode {
-    pub fn check(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+    pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         match *self.operator {
             PrefixOperator::LogicalNot => self.check_logical_not(scope),
-            PrefixOperator::Negative => self.check_negative(scope),
+            PrefixOperator::Negative => self.check_negative(scope, expected_type),
         }
     }
 
     fn check_logical_not(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
         let (scope, resolved_type) = self.expression.check(scope);
+        if is_overloadable(&resolved_type) {
+            return self.check_overloaded_op(scope, &resolved_type);
+        }
+
         if !resolved_type.is_primitive(PrimitiveType::Bool) {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.expression.span,
                 &format!(
                     "Operand of `{}` should be of type `bool`",
@@ -32,15 +39,55 @@ impl PrefixOpExpressionNode {
         (scope, Type::Primitive(PrimitiveType::Bool))
     }
 
-    fn check_negative(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
-        let (scope, resolved_type) = self.expression.check(scope);
-        if resolved_type.is_primitive(PrimitiveType::Float)
-            || resolved_type.is_primitive(PrimitiveType::Int)
+    fn check_negative(
+        &self,
+        scope: Box<Scope>,
+        expected_type: Option<&Type>,
+    ) -> (Box<Scope>, Type) {
+        let expected_type = expected_type.filter(|t| t.is_numeric());
+        let (scope, resolved_type) = match &self.expression.value {
+            // Negative literals are range checked as a whole, so the minimum value can be written
+            ExpressionNode::IntegerLiteral(literal) => {
+                let value = -literal.value;
+                let span = TokenSpan {
+                    start_index: self.operator.span.start_index,
+                    end_index: self.expression.span.end_index,
+                };
+                let resolved_type = check_integer_literal(&scope, span, value, expected_type);
+                (scope, resolved_type)
+            }
+            _ => self.expression.check_expected(scope, expected_type),
+        };
+        if is_overloadable(&resolved_type) {
+            return self.check_overloaded_op(scope, &resolved_type);
+        }
+
+        // Negative literals of unsigned types are already reported as out of range
+        let is_literal = matches!(self.expression.value, ExpressionNode::IntegerLiteral(_));
+        if let Type::Primitive(primitive) = resolved_type
+            && !primitive.is_signed()
+            && !is_literal
         {
+            scope.diagnostics.error(
+                self.expression.span,
+                "Negation cannot be applied to unsigned types",
+                &format!("found type: `{resolved_type}`"),
+            );
+        }
+
+        if let Type::Primitive(primitive) = resolved_type
+            && primitive.is_integer()
+        {
+            scope
+                .resolutions
+                .record_integer_type(self.operator.span, primitive);
+        }
+
+        if resolved_type.is_numeric() {
             (scope, resolved_type)
         } else {
             if !resolved_type.is_error() {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.expression.span,
                     "Negation can only be applied to numeric types",
                     &format!("found type: `{resolved_type}`"),
@@ -49,4 +96,14 @@ impl PrefixOpExpressionNode {
             (scope, Type::Error)
         }
     }
+
+    // Resolves the operator through the method of the operand's operator interface
+    fn check_overloaded_op(&self, scope: Box<Scope>, operand_type: &Type) -> (Box<Scope>, Type) {
+        let operator = OperatorInterface::from_prefix(*self.operator);
+        let token = self.operator.as_token();
+        match operator.expect_method(&scope, operand_type, self.expression.span, token) {
+            Some(method) => (scope, *method.return_type.clone()),
+            None => (scope, Type::Error),
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    checker::{Scope, Type},
+    checker::{Scope, TupleType, Type},
     parser::{ExpressionNode, Node},
 };
 
@@ -14,14 +14,15 @@ impl SpreadNode {
         &self,
         scope: Box<Scope>,
         expected_type: Option<&Type>,
-    ) -> (Box<Scope>, Rc<Vec<Type>>) {
+    ) -> (Box<Scope>, Rc<TupleType>) {
         let (scope, result_type) = self.expression.check_expected(scope, expected_type);
-        if let Type::Tuple(types) = result_type {
-            (scope, types)
+        if let Type::Tuple(tuple_type) = result_type {
+            (scope, tuple_type)
+        } else if let Some(elements) = result_type.spread_elements() {
+            (scope, TupleType::new(elements))
         } else {
             self.print_non_tuple_error(&scope, &result_type);
-            let types = Rc::new(vec![result_type]);
-            (scope, types)
+            (scope, TupleType::new(vec![result_type]))
         }
     }
 
@@ -30,14 +31,14 @@ impl SpreadNode {
         scope: Box<Scope>,
         expected_type: Option<&Type>,
     ) -> (Box<Scope>, Type) {
-        scope.source.print_error(
+        scope.diagnostics.error(
             self.expression.span.before(),
             "Unexpected spread expression",
             "spread expression must be an argument or tuple element",
         );
 
         let (scope, result_type) = self.expression.check_expected(scope, expected_type);
-        if !matches!(result_type, Type::Tuple(_)) && !result_type.is_error() {
+        if result_type.spread_elements().is_none() && !result_type.is_error() {
             self.print_non_tuple_error(&scope, &result_type);
         }
 
@@ -45,7 +46,7 @@ impl SpreadNode {
     }
 
     fn print_non_tuple_error(&self, scope: &Scope, found_type: &Type) {
-        scope.source.print_error(
+        scope.diagnostics.error(
             self.expression.span,
             "Spread expression should be a tuple",
             &format!("found type `{found_type}`"),
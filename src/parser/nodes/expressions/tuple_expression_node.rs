@@ -1,40 +1,50 @@
-use std::rc::Rc;
-
 use crate::{
-    checker::{Scope, Type},
-    parser::{ExpressionNode, Node},
+    checker::{Scope, TupleElement, TupleType, Type},
+    parser::{ExpressionNode, NameNode, Node},
 };
 
 pub struct TupleExpressionNode {
     pub expressions: Vec<Node<ExpressionNode>>,
+    // Parallel to the expressions, with the names of elements written as `name = value`
+    pub names: Vec<Option<NameNode>>,
 }
 
 impl TupleExpressionNode {
     pub fn check(&self, mut scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
-        let expected_tuple_types = if let Some(Type::Tuple(types)) = expected_type {
-            types
+        let expected_tuple_types = if let Some(Type::Tuple(tuple_type)) = expected_type {
+            &tuple_type.elements
         } else {
             &vec![]
         };
 
-        let mut types = vec![];
-        let mut expected_index = 0;
-        for node in &self.expressions {
-            if let ExpressionNode::Spread(node) = &node.value {
+        let mut elements = vec![];
+        for (node, name) in self.expressions.iter().zip(self.names.iter()) {
+            if let ExpressionNode::Spread(spread) = &node.value {
                 // TODO pass expected type...
-                let (new_scope, spread_types) = node.check_valid(scope, None);
+                let (new_scope, spread_type) = spread.check_valid(scope, None);
                 scope = new_scope;
-                types.extend_from_slice(&spread_types);
-                expected_index += spread_types.len();
+                // Spreading a named tuple keeps the names of its elements
+                let names = spread_type.get_names();
+                for (name, element_type) in names.into_iter().zip(spread_type.elements.iter()) {
+                    elements.push(TupleElement {
+                        name,
+                        element_type: element_type.clone(),
+                        span: node.span,
+                    });
+                }
             } else {
-                let expected_type = expected_tuple_types.get(expected_index);
+                let expected_type = expected_tuple_types.get(elements.len());
                 let (new_scope, resolved_type) = node.check_expected(scope, expected_type);
                 scope = new_scope;
-                types.push(resolved_type);
-                expected_index += 1;
+                elements.push(TupleElement {
+                    name: name.as_ref().map(|name| name.value.clone()),
+                    element_type: resolved_type,
+                    span: name.as_ref().map_or(node.span, |name| name.span),
+                });
             }
         }
 
-        (scope, Type::Tuple(Rc::new(types)))
+        let tuple_type = TupleType::from_elements(&scope.diagnostics, elements);
+        (scope, Type::Tuple(tuple_type))
     }
 }
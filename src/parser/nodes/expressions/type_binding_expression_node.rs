@@ -1,6 +1,6 @@
 use crate::{
-    checker::{Scope, Type},
-    parser::{ExpressionNode, Node, NodeVec, TypeNode, bind_type},
+    checker::{Scope, Type, Types, check_bounds},
+    parser::{ExpressionNode, Node, NodeVec, TypeNode, bind_type, check_type_arguments},
 };
 
 pub struct TypeBindingExpressionNode {
@@ -10,14 +10,102 @@ pub struct TypeBindingExpressionNode {
 
 impl TypeBindingExpressionNode {
     pub fn check(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let is_type = match &self.left.value {
+            ExpressionNode::Name(name) => scope.get_type(name).is_some(),
+            _ => false,
+        };
+        if !is_type {
+            return self.check_function(scope);
+        }
+
+        let (scope, bound_type) = self.check_type(scope);
+        match bound_type {
+            Type::Struct(struct_type) => {
+                let constructor = struct_type.get_constructor(&*scope);
+                (scope, Type::Function(constructor))
+            }
+            Type::Error => (scope, Type::Error),
+            _ => {
+                scope.diagnostics.error(
+                    self.left.span,
+                    "Types cannot be used as values",
+                    &format!("cannot use type `{bound_type}` as a value"),
+                );
+                (scope, Type::Error)
+            }
+        }
+    }
+
+    // Explicitly instantiates a generic function with the given type arguments
+    fn check_function(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
+        let (scope, function_type) = self.left.check(scope);
+        let bound_types = self.get_bound_types(&scope);
+        let Type::Function(function_type) = function_type else {
+            if !function_type.is_error() {
+                scope.diagnostics.error(
+                    self.left.span,
+                    "Type arguments given to a non-generic value",
+                    &format!("type `{function_type}` does not take type parameters"),
+                );
+            }
+            return (scope, Type::Error);
+        };
+
+        if !function_type.is_generic() {
+            scope.diagnostics.error(
+                self.bound_type_parameters.span,
+                "Type arguments given to a non-generic function",
+                &format!(
+                    "type `{}` does not take type parameters",
+                    Type::Function(function_type.clone())
+                ),
+            );
+            return (scope, Type::Function(function_type));
+        }
+
+        let bindings = function_type.type_parameters.bind_types(
+            &*scope,
+            &self.bound_type_parameters,
+            &bound_types,
+        );
+        let arguments = function_type
+            .type_parameters
+            .iter()
+            .zip(bound_types.iter())
+            .zip(self.bound_type_parameters.iter());
+        for ((type_parameter, bound_type), node) in arguments {
+            check_bounds(&scope, node.span, type_parameter, bound_type, &bindings);
+        }
+
+        let bound_type = Type::Function(function_type.instantiate(&*scope, &bindings));
+        (scope, bound_type)
+    }
+
+    fn get_bound_types(&self, scope: &Scope) -> Vec<Type> {
+        self.bound_type_parameters
+            .iter()
+            .map(|node| {
+                node.check(scope);
+                node.get_type(scope, None, None)
+            })
+            .collect()
+    }
+
+    pub fn check_type(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
         // TODO left expression shouldn't necessarily be a type, as in the case of a generic function with bound type args
         let (scope, unbound_type) = self.left.check_type(scope, self.left.span);
+        let bound_types = self.get_bound_types(&scope);
         let bound_type = bind_type(
             &*scope,
             &unbound_type,
             &self.bound_type_parameters,
-            None,
-            None,
+            &bound_types,
+        );
+        check_type_arguments(
+            &scope,
+            &unbound_type,
+            &self.bound_type_parameters,
+            &bound_types,
         );
         (scope, bound_type)
     }
@@ -1,8 +1,8 @@
 use std::{collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{FunctionType, Scope, Type, Types},
-    parser::{ExpressionNode, FunctionBodyNode, FunctionSignatureNode, Node},
+    checker::{FunctionType, Scope, Type, TypeParameterMap, Types},
+    parser::{ExpressionNode, FunctionBodyNode, FunctionSignatureNode, Node, check_control_flow},
 };
 
 pub struct FunctionNode {
@@ -16,14 +16,23 @@ impl FunctionNode {
     }
 
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
-        let return_type = &self.get_type(&*scope).return_type;
-        scope.nest_fn(return_type, |scope| {
+        let return_type = &self.get_type(&*scope, None).return_type;
+        scope.nest_fn(return_type, |mut scope| {
+            if let Some(type_parameters) = self.signature.type_parameters.as_ref() {
+                scope = type_parameters.check_function(scope, type_parameters.span);
+            }
+            self.signature.check_types(&scope);
             let scope = self.check_params(scope);
             match &self.body.value {
                 FunctionBodyNode::Expression(expression) => {
                     self.check_expression_body(scope, return_type, expression)
                 }
-                FunctionBodyNode::Block(block) => block.check(scope, Some(return_type)).0,
+                FunctionBodyNode::Block(block) => {
+                    let (scope, _) = block.check(scope, Some(return_type));
+                    let name = &self.signature.name;
+                    check_control_flow(&scope, name, block, self.body.span, return_type);
+                    scope
+                }
             }
         })
     }
@@ -34,9 +43,9 @@ impl FunctionNode {
         return_type: &Type,
         body: &ExpressionNode,
     ) -> Box<Scope> {
-        let (scope, resolved_type) = body.check_expected(scope, Some(return_type));
+        let (scope, resolved_type) = body.check_optional(scope, self.body.span, Some(return_type));
         if !resolved_type.is_assignable_to(return_type, &scope) {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.body.span,
                 &format!("Function must return value of type `{return_type}`"),
                 &format!("found type: `{resolved_type}`"),
@@ -49,21 +58,26 @@ impl FunctionNode {
         let mut param_names = HashSet::new();
         for param in self.signature.parameters.iter() {
             if param_names.contains(&param.name.value) {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     param.name.span,
                     &format!("Duplicate parameter name `{}`", param.name),
                     "function already contains a parameter with this name",
                 );
             } else {
                 param_names.insert(param.name.clone());
-                scope.add_value(&param.name, param.get_type(&*scope).clone());
+                let param_type = param.get_type(&*scope, None).clone();
+                scope.declare_value(&param.name, param_type, param.mutable, param.name.span);
             }
         }
         scope
     }
 
-    pub fn get_type(&self, types: &impl Types) -> Rc<FunctionType> {
-        self.signature.get_type(types)
+    pub fn get_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Rc<FunctionType> {
+        self.signature.get_type(types, type_params)
     }
 
     pub fn name(&self) -> &String {
@@ -1,12 +1,13 @@
 use std::{cell::OnceCell, rc::Rc};
 
 use crate::{
-    checker::{FunctionType, Type, Types},
-    parser::{NameNode, Node, NodeVec, ParameterNode, TypeNode},
+    checker::{FunctionType, Scope, Type, TypeParameterList, TypeParameterMap, Types},
+    parser::{NameNode, Node, NodeVec, ParameterNode, TypeNode, TypeParameterListNode},
 };
 
 pub struct FunctionSignatureNode {
     pub name: NameNode,
+    pub type_parameters: Option<Node<TypeParameterListNode>>,
     pub parameters: NodeVec<ParameterNode>,
     pub return_type: Option<Node<TypeNode>>,
     resolved_type: OnceCell<Rc<FunctionType>>,
@@ -15,36 +16,84 @@ pub struct FunctionSignatureNode {
 impl FunctionSignatureNode {
     pub fn new(
         name: NameNode,
+        type_parameters: Option<Node<TypeParameterListNode>>,
         parameters: NodeVec<ParameterNode>,
         return_type: Option<Node<TypeNode>>,
     ) -> Self {
         Self {
             name,
+            type_parameters,
             parameters,
             return_type,
             resolved_type: OnceCell::new(),
         }
     }
 
-    pub fn get_type(&self, types: &impl Types) -> Rc<FunctionType> {
+    pub fn get_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Rc<FunctionType> {
         self.resolved_type
-            .get_or_init(|| self.init_type(types))
+            .get_or_init(|| self.init_type(types, type_params))
             .clone()
     }
 
-    fn init_type(&self, types: &impl Types) -> Rc<FunctionType> {
+    pub fn check_types(&self, scope: &Scope) {
+        for parameter in self.parameters.iter() {
+            if let Some(type_def) = parameter.type_def.as_ref() {
+                type_def.check(scope);
+            }
+        }
+
+        if let Some(return_type) = self.return_type.as_ref() {
+            return_type.check(scope);
+        }
+    }
+
+    fn init_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Rc<FunctionType> {
+        let Some(type_parameters) = self.type_parameters.as_ref() else {
+            return self.resolve_type(types, type_params, TypeParameterList::default());
+        };
+
+        // The function's own type parameters shadow those of the enclosing type
+        let mut combined_type_params = type_params.cloned().unwrap_or_default();
+        combined_type_params.extend(
+            type_parameters
+                .get_types_map()
+                .iter()
+                .map(|(name, type_parameter)| (name.clone(), type_parameter.clone())),
+        );
+        type_parameters.resolve_bounds(types);
+        self.resolve_type(
+            types,
+            Some(&combined_type_params),
+            type_parameters.get_function_types_list().clone(),
+        )
+    }
+
+    fn resolve_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+        type_parameters: TypeParameterList,
+    ) -> Rc<FunctionType> {
         let parameters = self
             .parameters
             .value
             .iter()
-            .map(|parameter| parameter.get_type(types))
+            .map(|parameter| parameter.get_type(types, type_params))
             .cloned()
             .collect();
 
         let return_type = self.return_type.as_ref().map_or(Type::Void, |return_type| {
-            return_type.get_type(types, None, None)
+            return_type.get_type(types, type_params, None)
         });
 
-        FunctionType::new(parameters, return_type)
+        FunctionType::generic(type_parameters, parameters, return_type)
     }
 }
@@ -1,15 +1,21 @@
 use crate::{
-    checker::Scope,
+    checker::{Scope, ScopeType},
     parser::{FunctionNode, Node},
 };
 
 pub struct MethodNode {
     pub public: bool,
+    // Static methods are called through their type and have no `self` value
+    pub is_static: bool,
     pub function: Node<FunctionNode>,
 }
 
 impl MethodNode {
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
-        self.function.check(scope)
+        if self.is_static {
+            scope.nest(ScopeType::StaticMethod, |scope| self.function.check(scope))
+        } else {
+            self.function.check(scope)
+        }
     }
 }
@@ -1,32 +1,35 @@
 use std::cell::OnceCell;
 
 use crate::{
-    checker::{Type, Types},
+    checker::{Type, TypeParameterMap, Types},
     parser::{NameNode, Node, TypeNode},
 };
 
 pub struct ParameterNode {
+    pub mutable: bool,
     pub name: NameNode,
     pub type_def: Option<Node<TypeNode>>,
     resolved_type: OnceCell<Type>,
 }
 
 impl ParameterNode {
-    pub fn new(name: NameNode, type_def: Option<Node<TypeNode>>) -> Self {
+    pub fn new(mutable: bool, name: NameNode, type_def: Option<Node<TypeNode>>) -> Self {
         Self {
+            mutable,
             name,
             type_def,
             resolved_type: OnceCell::new(),
         }
     }
 
-    pub fn get_type(&self, types: &impl Types) -> &Type {
-        self.resolved_type.get_or_init(|| self.init_type(types))
+    pub fn get_type(&self, types: &impl Types, type_params: Option<&TypeParameterMap>) -> &Type {
+        self.resolved_type
+            .get_or_init(|| self.init_type(types, type_params))
     }
 
-    fn init_type(&self, types: &impl Types) -> Type {
+    fn init_type(&self, types: &impl Types, type_params: Option<&TypeParameterMap>) -> Type {
         match self.type_def.as_ref() {
-            Some(type_def) => type_def.get_type(types, None, None),
+            Some(type_def) => type_def.get_type(types, type_params, None),
             None => Type::Error,
         }
     }
@@ -0,0 +1,6 @@
+use crate::parser::Node;
+
+// An import of the public definitions of another source file, relative to the importing file
+pub struct ImportNode {
+    pub path: Node<String>,
+}
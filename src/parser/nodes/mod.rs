@@ -1,5 +1,6 @@
 mod expressions;
 mod functions;
+mod import_node;
 mod module_definition_node;
 mod name_node;
 mod node;
@@ -12,6 +13,7 @@ mod types;
 
 pub use expressions::*;
 pub use functions::*;
+pub use import_node::*;
 pub use module_definition_node::*;
 pub use name_node::*;
 pub use node::*;
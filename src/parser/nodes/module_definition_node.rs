@@ -31,8 +31,8 @@ impl ModuleDefinitionNode {
 
     pub fn add_to_scope(&self, scope: &mut Scope) {
         let resolved_type = match self {
-            Self::Function(node) => Some(Type::Function(node.get_type(scope).clone())),
-            // TODO respect the privacy of the constructor
+            Self::Function(node) => Some(Type::Function(node.get_type(scope, None))),
+            // The privacy of the constructor is checked where it is used
             Self::Struct(node) => Some(Type::Function(node.get_type(scope).get_constructor(scope))),
             Self::Enum(_) | Self::Interface(_) | Self::TypeAlias(_) => None,
         };
@@ -1,14 +1,21 @@
-use std::fmt::Display;
+use std::{fmt::Display, rc::Rc};
 
 use crate::{
-    checker::{Scope, Type, Types},
-    parser::Node,
+    checker::{Assignment, Scope, ScopeType, StructMemberType, StructType, Type, Types},
+    diagnostics::Diagnostic,
+    parser::{Node, check_private_constructor},
 };
 
 pub type NameNode = Node<String>;
 
 impl NameNode {
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
+        let (scope, resolved_type) = self.resolve(scope, expected_type);
+        (self.check_assigned(scope), resolved_type)
+    }
+
+    // Resolves the type of the value without reading it, as when it is the target of an assignment
+    pub fn resolve(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
         let expected_enum_type = expected_type.and_then(|e| match e {
             Type::Enum(enum_type) => Some(enum_type),
             _ => None,
@@ -16,19 +23,32 @@ impl NameNode {
 
         let type_value = scope.get_type(self);
         if let Some(resolved_type) = scope.get_value(self) {
+            // Values like `Self` and `Result` are the constructors of the type with the same name
+            if let Some(type_value) = type_value {
+                if let Type::Struct(struct_type) = &type_value
+                    && is_constructor(&resolved_type, struct_type)
+                {
+                    check_private_constructor(&scope, struct_type, self);
+                }
+                scope.resolutions.record(self.span, type_value);
+            }
+            self.check_instance_member(&scope);
             (scope, resolved_type)
         } else if let Some(type_value) = type_value {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.span,
                 "Types cannot be used as values",
                 &format!("cannot use type `{type_value}` as a value"),
             );
             (scope, Type::Error)
         } else if let Some(enum_type) = expected_enum_type {
-            if let Some(variant_type) = enum_type.get_variant(self) {
+            if let Some(variant_type) = enum_type.get_variant(&*scope, self) {
+                scope
+                    .resolutions
+                    .record(self.span, Type::Enum(enum_type.clone()));
                 (scope, variant_type)
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.span,
                     &format!("Could not find value `{self}`"),
                     "no such symbol found",
@@ -36,7 +56,7 @@ impl NameNode {
                 (scope, Type::Error)
             }
         } else {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.span,
                 &format!("Could not find value `{self}`"),
                 "no such symbol found",
@@ -44,6 +64,73 @@ impl NameNode {
             (scope, Type::Error)
         }
     }
+
+    fn check_assigned(&self, mut scope: Box<Scope>) -> Box<Scope> {
+        let Some(entry) = scope.get_value_entry(self) else {
+            return scope;
+        };
+
+        let (message, inline_message) = match entry.assignment {
+            Assignment::Assigned => return scope,
+            Assignment::MaybeAssigned => (
+                format!("Use of possibly unassigned value `{self}`"),
+                format!("`{self}` is not assigned on every path to this point"),
+            ),
+            Assignment::Unassigned => (
+                format!("Use of unassigned value `{self}`"),
+                format!("`{self}` has not been assigned a value"),
+            ),
+        };
+        let mut diagnostic =
+            Diagnostic::error(scope.diagnostics.span(self.span), &message, &inline_message)
+                .with_code("use-of-unassigned");
+        if let Some(span) = entry.span {
+            diagnostic = diagnostic.with_label(scope.diagnostics.span(span), "declared here");
+        }
+        scope.diagnostics.report(diagnostic);
+        // Only report the first use of each unassigned value
+        scope.assign_value(self);
+        scope
+    }
+}
+
+impl NameNode {
+    // Fields and instance methods are not in scope within a static method, which has no receiver
+    fn check_instance_member(&self, scope: &Scope) {
+        let is_static = |scope_type: &ScopeType| matches!(scope_type, ScopeType::StaticMethod);
+        if !scope.within(is_static) || !scope.is_declared_outside(self, is_static) {
+            return;
+        }
+
+        let is_instance_member = match scope.get_self_type() {
+            Some(Type::Enum(enum_type)) => enum_type
+                .get_method(scope, self)
+                .is_some_and(|method| !method.is_static),
+            Some(Type::Struct(struct_type)) => {
+                struct_type.get_member(scope, self).is_some_and(|member| {
+                    !matches!(member.member_type, StructMemberType::StaticMethod(_))
+                })
+            }
+            _ => false,
+        };
+        if is_instance_member {
+            scope.diagnostics.error(
+                self.span,
+                &format!("Cannot use instance member `{self}` in a static method"),
+                "static methods have no `self` value",
+            );
+        }
+    }
+}
+
+fn is_constructor(value_type: &Type, struct_type: &Rc<StructType>) -> bool {
+    match value_type {
+        Type::Function(function_type) => matches!(
+            function_type.return_type.as_ref(),
+            Type::Struct(return_type) if Rc::ptr_eq(return_type, struct_type)
+        ),
+        _ => false,
+    }
 }
 
 impl Display for NameNode {
@@ -24,6 +24,8 @@ pub enum BinaryOperator {
     LessThanOrEqual,     // <=
     GreaterThan,         // >
     GreaterThanOrEqual,  // >=
+    Range,               // ..
+    RangeInclusive,      // ..=
     FunctionApplication, // =>
     Comma,               // ,
     LogicalAnd,          // and
@@ -45,6 +47,8 @@ impl Operator for BinaryOperator {
             Self::LessThanOrEqual => Token::Symbol(S::LessThanEqual),
             Self::GreaterThan => Token::Symbol(S::GreaterThan),
             Self::GreaterThanOrEqual => Token::Symbol(S::GreaterThanEqual),
+            Self::Range => Token::Symbol(S::DoubleDot),
+            Self::RangeInclusive => Token::Symbol(S::DoubleDotEqual),
             Self::FunctionApplication => Token::Symbol(S::ThickArrow),
             Self::Comma => Token::Symbol(S::Comma),
             Self::Assign => Token::Symbol(S::Equal),
@@ -61,16 +65,18 @@ impl Operator for BinaryOperator {
     fn precedence(&self) -> i32 {
         match self {
             // Function application should bind slightly less tight than function calls (see SpecialOperator)
-            Self::FunctionApplication => 8,
+            Self::FunctionApplication => 9,
             // Multiplicative
             Self::Multiply | Self::Divide | Self::Mod => 7,
             // Additive
             Self::Add | Self::Subtract => 6,
-            // Relational
+            // Relational and ranges
             Self::LessThan
             | Self::LessThanOrEqual
             | Self::GreaterThan
-            | Self::GreaterThanOrEqual => 5,
+            | Self::GreaterThanOrEqual
+            | Self::Range
+            | Self::RangeInclusive => 5,
             // Equality
             Self::Equal | Self::NotEqual => 4,
             // Logical and
@@ -18,6 +18,6 @@ impl Operator for PostfixOperator {
     }
 
     fn precedence(&self) -> i32 {
-        8
+        9
     }
 }
@@ -20,6 +20,6 @@ impl Operator for PrefixOperator {
     }
 
     fn precedence(&self) -> i32 {
-        8
+        9
     }
 }
@@ -1,18 +1,79 @@
-use std::rc::Rc;
+use std::{collections::HashMap, rc::Rc};
 
 use crate::{
-    checker::ModuleScope,
-    lexer::SourceCode,
-    parser::{ExportableModuleDefinitionNode, ModuleDefinitionNode, Node},
+    checker::{ModuleExports, ModuleScope, Resolutions, Type},
+    diagnostics::{Diagnostic, DiagnosticSink},
+    parser::{ExportableModuleDefinitionNode, ImportNode, ModuleDefinitionNode, Node, TokenSpan},
 };
 
 pub struct ProgramNode {
+    pub imports: Vec<Node<ImportNode>>,
     pub definitions: Vec<Node<ExportableModuleDefinitionNode>>,
 }
 
 impl ProgramNode {
-    pub fn check(&mut self, source: Rc<SourceCode>) {
-        let mut scope = ModuleScope::new(source);
+    // Checks the module, given the exports of the prelude and of each import which could be loaded
+    pub fn check(
+        &mut self,
+        diagnostics: Rc<DiagnosticSink>,
+        resolutions: Rc<Resolutions>,
+        prelude: Option<&ModuleExports>,
+        imports: &[Option<&ModuleExports>],
+    ) -> ModuleExports {
+        let mut scope = ModuleScope::new(diagnostics.clone());
+        if let Some(prelude) = prelude {
+            scope.add_prelude(prelude);
+        }
+        let mut imported_values: HashMap<&String, Type> = HashMap::new();
+        let mut imported_modules: Vec<(&ModuleExports, TokenSpan)> = vec![];
+        for (import, exports) in self.imports.iter().zip(imports) {
+            let Some(exports) = exports else {
+                continue;
+            };
+
+            // The same module may be reached through different paths, so compare the modules
+            let previous = imported_modules
+                .iter()
+                .find(|(previous, _)| std::ptr::eq(*previous, *exports));
+            if let Some((_, previous_span)) = previous {
+                let diagnostic = Diagnostic::warning(
+                    diagnostics.span(import.path.span),
+                    "Duplicate import",
+                    "this module is already imported",
+                )
+                .with_code("duplicate-import")
+                .with_label(diagnostics.span(*previous_span), "first imported here");
+                diagnostics.report(diagnostic);
+                continue;
+            }
+            imported_modules.push((exports, import.path.span));
+
+            let mut conflicts = vec![];
+            for (name, entry) in &exports.types {
+                if !scope.import(name, entry) {
+                    conflicts.push(name);
+                }
+            }
+            for (name, value) in &exports.values {
+                if imported_values.insert(name, value.clone()).is_some() {
+                    conflicts.push(name);
+                }
+            }
+
+            if !conflicts.is_empty() {
+                conflicts.sort();
+                let conflicts = conflicts
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>();
+                diagnostics.error(
+                    import.path.span,
+                    "Conflicting imports",
+                    &format!("{} already imported", conflicts.join(", ")),
+                );
+            }
+        }
+
         for definition in self.definitions() {
             if let Some(type_node) = definition.to_module_type_node() {
                 scope.declare(definition.name(), type_node);
@@ -20,15 +81,46 @@ impl ProgramNode {
         }
 
         scope.resolve();
+        let mut exports = ModuleExports::default();
+        for definition in self.public_definitions() {
+            if let Some(entry) = scope.get_type_entry(definition.name()) {
+                exports.types.insert(definition.name().value.clone(), entry);
+            }
+        }
 
         let mut scope = scope.to_scope();
+        scope.resolutions = resolutions;
+        // Values of the prelude can be shadowed by those imported or defined in the module
+        for (name, value) in prelude.iter().flat_map(|prelude| &prelude.values) {
+            scope.add_value(name, value.clone());
+        }
+        for (name, value) in &imported_values {
+            scope.add_value(name, value.clone());
+        }
         for definition in self.definitions_mut() {
+            if imported_values.contains_key(&definition.name().value) {
+                diagnostics.error(
+                    definition.name().span,
+                    "Duplicate value name",
+                    "a value with this name is already imported",
+                );
+            }
             definition.add_to_scope(&mut scope);
         }
 
+        for definition in self.public_definitions() {
+            if let Some(value) = scope.get_local_value(definition.name()) {
+                exports
+                    .values
+                    .insert(definition.name().value.clone(), value);
+            }
+        }
+
         for definition in self.definitions() {
             scope = definition.check(scope);
         }
+
+        exports
     }
 
     fn definitions(&self) -> impl Iterator<Item = &ModuleDefinitionNode> {
@@ -38,4 +130,11 @@ impl ProgramNode {
     fn definitions_mut(&mut self) -> impl Iterator<Item = &mut ModuleDefinitionNode> {
         self.definitions.iter_mut().map(|def| &mut def.definition)
     }
+
+    fn public_definitions(&self) -> impl Iterator<Item = &ModuleDefinitionNode> {
+        self.definitions
+            .iter()
+            .filter(|def| def.public)
+            .map(|def| &def.definition)
+    }
 }
@@ -28,4 +28,8 @@ impl BlockNode {
             (scope, resolved_type)
         })
     }
+
+    pub fn diverges(&self) -> bool {
+        self.statements.iter().any(|statement| statement.diverges())
+    }
 }
@@ -0,0 +1,234 @@
+use crate::{
+    checker::{Scope, Type},
+    diagnostics::Diagnostic,
+    parser::{BlockNode, ExpressionNode, MatchNode, NameNode, StatementNode, TokenSpan},
+};
+
+const ENTRY: usize = 0;
+// Reached by falling off the end of the function body
+const END: usize = 1;
+
+// A graph with a node for each statement, and edges to the statements which may run next
+struct ControlFlowGraph {
+    successors: Vec<Vec<usize>>,
+    // Consecutive statements within a block, with the span of the later statement
+    sequences: Vec<(usize, usize, TokenSpan)>,
+    loops: Vec<Loop>,
+    // Statements which could not be parsed, and so may have returned
+    has_errors: bool,
+}
+
+struct Loop {
+    head: usize,
+    breaks: Vec<usize>,
+}
+
+// Reports statements which can never run, and functions which may reach the end of their body
+// without returning a value
+pub fn check_control_flow(
+    scope: &Scope,
+    name: &NameNode,
+    body: &BlockNode,
+    body_span: TokenSpan,
+    return_type: &Type,
+) {
+    let mut graph = ControlFlowGraph {
+        successors: vec![vec![], vec![]],
+        sequences: vec![],
+        loops: vec![],
+        has_errors: false,
+    };
+    // Block returns in the body of a function return from the function
+    let (open, _) = graph.add_block(body, vec![ENTRY]);
+    graph.add_edges(&open, END);
+
+    let reachable = graph.find_reachable();
+    for &(previous, node, span) in &graph.sequences {
+        if reachable[previous] && !reachable[node] {
+            scope.diagnostics.report(
+                Diagnostic::warning(
+                    scope.diagnostics.span(span),
+                    "Unreachable statement",
+                    "this statement can never be executed",
+                )
+                .with_code("unreachable-code"),
+            );
+        }
+    }
+
+    if reachable[END] && !graph.has_errors && !matches!(return_type, Type::Void | Type::Error) {
+        let end = TokenSpan {
+            start_index: body_span.end_index,
+            end_index: body_span.end_index,
+        };
+        scope.diagnostics.report(
+            Diagnostic::error(
+                scope.diagnostics.span(end),
+                &format!("Missing return in function `{name}`"),
+                &format!("function must return a value of type `{return_type}` on every path"),
+            )
+            .with_code("missing-return")
+            .with_label(scope.diagnostics.span(name.span), "function declared here"),
+        );
+    }
+}
+
+impl ControlFlowGraph {
+    fn add_node(&mut self, predecessors: &[usize]) -> usize {
+        let node = self.successors.len();
+        self.successors.push(vec![]);
+        self.add_edges(predecessors, node);
+        node
+    }
+
+    fn add_edges(&mut self, predecessors: &[usize], node: usize) {
+        for &predecessor in predecessors {
+            self.successors[predecessor].push(node);
+        }
+    }
+
+    // Adds the statements of a block, returning the nodes which fall through to the end of the
+    // block and the nodes which leave it with a block return
+    fn add_block(&mut self, block: &BlockNode, mut open: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+        let mut block_returns = vec![];
+        let mut previous = None;
+        for statement in &block.statements {
+            let node = self.add_node(&open);
+            if let Some(previous) = previous {
+                self.sequences.push((previous, node, statement.span));
+            }
+            previous = Some(node);
+
+            open = match &statement.value {
+                StatementNode::BlockReturn(expression) => {
+                    block_returns.extend(self.add_expression(expression, vec![node]));
+                    vec![]
+                }
+                statement => self.add_statement(statement, node),
+            };
+        }
+
+        (open, block_returns)
+    }
+
+    // Adds the parts of a statement following its node, returning the nodes which fall through to
+    // the next statement
+    fn add_statement(&mut self, statement: &StatementNode, node: usize) -> Vec<usize> {
+        match statement {
+            StatementNode::Break => {
+                if let Some(enclosing_loop) = self.loops.last_mut() {
+                    enclosing_loop.breaks.push(node);
+                }
+                vec![]
+            }
+            StatementNode::Continue => {
+                if let Some(enclosing_loop) = self.loops.last() {
+                    let head = enclosing_loop.head;
+                    self.add_edges(&[node], head);
+                }
+                vec![]
+            }
+            StatementNode::Declaration(declaration) => match declaration.initializer.as_ref() {
+                Some(initializer) => self.add_expression(initializer, vec![node]),
+                None => vec![node],
+            },
+            StatementNode::BlockReturn(expression) => self.add_expression(expression, vec![node]),
+            StatementNode::Expression(ExpressionNode::Error) => {
+                self.has_errors = true;
+                vec![node]
+            }
+            StatementNode::Expression(expression) => self.add_expression(expression, vec![node]),
+            StatementNode::FunctionReturn(expression) => {
+                if let Some(expression) = expression {
+                    self.add_expression(expression, vec![node]);
+                }
+                vec![]
+            }
+            StatementNode::If(if_statement) => {
+                let mut open = vec![];
+                for condition in &if_statement.conditions {
+                    let (exits, _) = self.add_block(&condition.body, vec![node]);
+                    open.extend(exits);
+                }
+                match if_statement.else_branch.as_ref() {
+                    Some(else_branch) => open.extend(self.add_block(else_branch, vec![node]).0),
+                    None => open.push(node),
+                }
+                open
+            }
+            StatementNode::Match(match_node) => self.add_match(match_node, vec![node]),
+            StatementNode::TypeAlias(_) => vec![node],
+            StatementNode::WhileLoop(while_loop) => {
+                let mut open = self.add_loop(&while_loop.body, node);
+                // A loop over a constant `true` predicate can only be left with `break`
+                if !matches!(
+                    while_loop.predicate.value,
+                    ExpressionNode::BooleanLiteral(true)
+                ) {
+                    open.push(node);
+                }
+                open
+            }
+            StatementNode::ForLoop(for_loop) => {
+                let mut open = self.add_loop(&for_loop.body, node);
+                open.push(node);
+                open
+            }
+        }
+    }
+
+    // Adds the body of a loop headed by the node, returning the nodes which break out of it
+    fn add_loop(&mut self, body: &BlockNode, head: usize) -> Vec<usize> {
+        self.loops.push(Loop {
+            head,
+            breaks: vec![],
+        });
+        let (exits, _) = self.add_block(body, vec![head]);
+        self.add_edges(&exits, head);
+        self.loops.pop().map_or_else(Vec::new, |ended| ended.breaks)
+    }
+
+    // Adds the statements within an expression, such as the statements of a block expression
+    fn add_expression(&mut self, expression: &ExpressionNode, open: Vec<usize>) -> Vec<usize> {
+        match expression {
+            ExpressionNode::Block(block) => {
+                let (mut exits, block_returns) = self.add_block(block, open);
+                exits.extend(block_returns);
+                exits
+            }
+            ExpressionNode::IfExpression(if_expression) => {
+                let open = self.add_expression(&if_expression.predicate, open);
+                let mut exits = self.add_expression(&if_expression.if_true, open.clone());
+                exits.extend(self.add_expression(&if_expression.if_false, open));
+                exits
+            }
+            ExpressionNode::Match(match_node) => self.add_match(match_node, open),
+            _ => open,
+        }
+    }
+
+    fn add_match(&mut self, match_node: &MatchNode, open: Vec<usize>) -> Vec<usize> {
+        let open = self.add_expression(&match_node.subject, open);
+        if match_node.cases.is_empty() {
+            return open;
+        }
+
+        let mut exits = vec![];
+        for case in &match_node.cases {
+            exits.extend(self.add_expression(&case.if_match, open.clone()));
+        }
+        exits
+    }
+
+    fn find_reachable(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.successors.len()];
+        let mut pending = vec![ENTRY];
+        while let Some(node) = pending.pop() {
+            if !reachable[node] {
+                reachable[node] = true;
+                pending.extend(&self.successors[node]);
+            }
+        }
+        reachable
+    }
+}
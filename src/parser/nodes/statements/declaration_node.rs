@@ -1,28 +1,56 @@
+use std::collections::HashMap;
+
 use crate::{
     checker::{Scope, Type},
-    parser::{ExpressionNode, NameNode, Node, TypeNode},
+    parser::{ExpressionNode, MatchPatternNode, Node, TypeNode, check_irrefutable},
 };
 
 pub struct DeclarationNode {
     pub mutable: bool,
-    pub name: NameNode,
+    // A single name, or a pattern destructuring the initializer into several names
+    pub pattern: Box<Node<MatchPatternNode>>,
     pub type_def: Option<Node<TypeNode>>,
     pub initializer: Option<Node<ExpressionNode>>,
 }
 
 impl DeclarationNode {
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
-        let expected_type = self
-            .type_def
-            .as_ref()
-            .map(|type_def| type_def.get_type(&*scope, None, None));
-
-        let (mut scope, resolved_type) = match self.initializer.as_ref() {
-            Some(initializer) => check_initializer(scope, expected_type, initializer),
-            None => (scope, Type::Error),
+        let expected_type = self.type_def.as_ref().map(|type_def| {
+            type_def.check(&scope);
+            type_def.get_type(&*scope, None, None)
+        });
+
+        let Some(initializer) = self.initializer.as_ref() else {
+            return self.check_unassigned(scope, expected_type);
         };
 
-        scope.add_value(&self.name, resolved_type);
+        let (mut scope, resolved_type) = check_initializer(scope, expected_type, initializer);
+        let mut bindings = HashMap::new();
+        self.pattern
+            .check(&scope, self.pattern.span, &mut bindings, &resolved_type);
+        check_irrefutable(&scope, &self.pattern, &resolved_type);
+
+        for name in self.pattern.get_bindings() {
+            let bound_type = bindings.get(&name.value).cloned().unwrap_or(Type::Error);
+            scope.declare_value(name, bound_type, self.mutable, name.span);
+        }
+        scope
+    }
+
+    // Declarations without an initializer take their annotated type and must be assigned before use
+    fn check_unassigned(&self, mut scope: Box<Scope>, expected_type: Option<Type>) -> Box<Scope> {
+        if let (MatchPatternNode::Binding(name), Some(expected_type)) =
+            (&self.pattern.value, expected_type)
+            && !expected_type.is_error()
+        {
+            scope.declare_unassigned_value(name, expected_type, self.mutable, name.span);
+            return scope;
+        }
+
+        // A missing initializer without a type annotation has already been reported
+        for name in self.pattern.get_bindings() {
+            scope.declare_value(name, Type::Error, self.mutable, name.span);
+        }
         scope
     }
 }
@@ -32,13 +60,14 @@ fn check_initializer(
     expected_type: Option<Type>,
     initializer: &Node<ExpressionNode>,
 ) -> (Box<Scope>, Type) {
-    let (scope, resolved_type) = initializer.check_expected(scope, expected_type.as_ref());
+    let (scope, resolved_type) =
+        initializer.check_optional(scope, initializer.span, expected_type.as_ref());
     let Some(expected_type) = expected_type else {
         return (scope, expected_type.unwrap_or(resolved_type));
     };
 
     if !resolved_type.is_assignable_to(&expected_type, &scope) {
-        scope.source.print_error(
+        scope.diagnostics.error(
             initializer.span,
             &format!("Initializer not assignable to type `{expected_type}`"),
             &format!("found type: `{resolved_type}`"),
@@ -0,0 +1,45 @@
+use crate::{
+    checker::{Scope, ScopeType, Type, get_element_type},
+    parser::{BlockNode, ExpressionNode, NameNode, Node, finish_loop},
+};
+
+pub struct ForLoopNode {
+    pub mutable: bool,
+    pub variable: NameNode,
+    pub iterable: Node<ExpressionNode>,
+    pub body: Node<BlockNode>,
+}
+
+impl ForLoopNode {
+    pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
+        let (scope, iterable_type) = self.iterable.check(scope);
+        let element_type = match get_element_type(&scope, &iterable_type) {
+            Some(element_type) => element_type,
+            None => {
+                if !iterable_type.is_error() {
+                    scope.diagnostics.error(
+                        self.iterable.span,
+                        &format!("Cannot iterate over type `{iterable_type}`"),
+                        "expected an array, a range or an implementation of `Iterator`",
+                    );
+                }
+                Type::Error
+            }
+        };
+
+        // The body may not run at all, so assignments within it are only definite when the loop
+        // is left with `break`
+        let before = scope.get_assignments();
+        let (mut scope, (end, state)) = scope.nest_with(ScopeType::Loop, |mut scope| {
+            let span = self.variable.span;
+            scope.declare_value(&self.variable, element_type, self.mutable, span);
+            let (mut scope, _) = self.body.check(scope, None);
+            let end = (!self.body.diverges()).then(|| scope.get_assignments());
+            let state = scope.take_loop_state();
+            (scope, (end, state))
+        });
+        let assignments = finish_loop(&mut scope, before, end, state, true);
+        scope.set_assignments(&assignments);
+        scope
+    }
+}
@@ -12,7 +12,7 @@ impl IfStatementConditionNode {
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
         let (scope, predicate_type) = self.predicate.check(scope);
         if !predicate_type.is_primitive(PrimitiveType::Bool) {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.predicate.span,
                 "If statement predicate expected to be bool",
                 &format!("found type: `{predicate_type}`"),
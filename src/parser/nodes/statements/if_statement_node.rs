@@ -1,5 +1,5 @@
 use crate::{
-    checker::Scope,
+    checker::{Assignments, Scope},
     parser::{BlockNode, IfStatementConditionNode, Node},
 };
 
@@ -10,14 +10,36 @@ pub struct IfStatementNode {
 
 impl IfStatementNode {
     pub fn check(&self, mut scope: Box<Scope>) -> Box<Scope> {
+        let before = scope.get_assignments();
+        let mut paths = vec![];
         for condition in &self.conditions {
             scope = condition.check(scope);
+            if !condition.body.diverges() {
+                paths.push(scope.get_assignments());
+            }
+            scope.set_assignments(&before);
         }
 
         if let Some(else_branch) = self.else_branch.as_ref() {
             (scope, _) = else_branch.check(scope, None);
+            if !else_branch.diverges() {
+                paths.push(scope.get_assignments());
+            }
+        } else {
+            paths.push(before);
         }
 
+        scope.set_assignments(&Assignments::join(paths));
         scope
     }
+
+    pub fn diverges(&self) -> bool {
+        self.else_branch
+            .as_ref()
+            .is_some_and(|else_branch| else_branch.diverges())
+            && self
+                .conditions
+                .iter()
+                .all(|condition| condition.body.diverges())
+    }
 }
@@ -0,0 +1,372 @@
+use std::{fmt::Display, rc::Rc};
+
+use crate::{
+    checker::{Scope, StructType, Type},
+    diagnostics::Diagnostic,
+    parser::{
+        ExpressionNode, MatchCaseNode, MatchPatternNode, NameNode, NamedStructMatchPattern, Node,
+        PrimitiveType, TokenSpan, get_literal_type,
+    },
+};
+
+// A simplified pattern used to analyse which values a set of match cases covers
+#[derive(Clone)]
+enum Pattern {
+    Wildcard,
+    Constructor(Constructor, Vec<Pattern>),
+}
+
+#[derive(Clone, PartialEq)]
+enum Constructor {
+    Literal(String),
+    Struct(String),
+    Tuple,
+    Variant(String),
+}
+
+// Reports match cases which can never be reached, and values which no case matches
+pub fn check_exhaustiveness(
+    scope: &Scope,
+    subject_span: TokenSpan,
+    subject_type: &Type,
+    cases: &[Node<MatchCaseNode>],
+) {
+    // Invalid patterns have already been reported, so there is nothing useful to analyse
+    let Some(patterns) = cases
+        .iter()
+        .map(|case| Pattern::from(scope, &case.pattern, subject_type))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
+    let mut rows = vec![];
+    for (case, pattern) in cases.iter().zip(patterns) {
+        let row = vec![pattern];
+        if !is_useful(scope, &rows, &row, std::slice::from_ref(subject_type)) {
+            scope.diagnostics.report(
+                Diagnostic::warning(
+                    scope.diagnostics.span(case.pattern.span),
+                    "Unreachable match case",
+                    "this pattern is already covered by previous cases",
+                )
+                .with_code("unreachable-pattern"),
+            );
+        }
+        rows.push(row);
+    }
+
+    let missing = find_missing(scope, &rows, std::slice::from_ref(subject_type));
+    if missing.is_empty() {
+        return;
+    }
+
+    let missing = missing
+        .iter()
+        .map(|row| format!("`{}`", row[0]))
+        .collect::<Vec<_>>();
+    scope.diagnostics.report(
+        Diagnostic::error(
+            scope.diagnostics.span(subject_span),
+            "Match is not exhaustive",
+            &format!("missing cases: {}", missing.join(", ")),
+        )
+        .with_code("non-exhaustive-match")
+        .with_help("add a case for each missing pattern, or a wildcard `_` case"),
+    );
+}
+
+// Reports a declaration pattern which does not match every value of the initializer's type
+pub fn check_irrefutable(scope: &Scope, node: &Node<MatchPatternNode>, subject_type: &Type) {
+    // Invalid patterns have already been reported
+    let Some(pattern) = Pattern::from(scope, node, subject_type) else {
+        return;
+    };
+
+    let rows = vec![vec![pattern]];
+    let missing = find_missing(scope, &rows, std::slice::from_ref(subject_type));
+    if missing.is_empty() {
+        return;
+    }
+
+    let missing = missing
+        .iter()
+        .map(|row| format!("`{}`", row[0]))
+        .collect::<Vec<_>>();
+    scope.diagnostics.report(
+        Diagnostic::error(
+            scope.diagnostics.span(node.span),
+            "Refutable pattern in declaration",
+            &format!("not matched: {}", missing.join(", ")),
+        )
+        .with_code("refutable-pattern")
+        .with_help("use a `match` to handle the values which the pattern does not match"),
+    );
+}
+
+impl Pattern {
+    // Converts a pattern node, or returns `None` if it does not match the subject type
+    fn from(scope: &Scope, node: &MatchPatternNode, subject_type: &Type) -> Option<Pattern> {
+        match (node, subject_type) {
+            (
+                MatchPatternNode::Binding(_) | MatchPatternNode::Wildcard | MatchPatternNode::Else,
+                _,
+            ) => Some(Pattern::Wildcard),
+            (MatchPatternNode::Variant(pattern), Type::Enum(enum_type)) => {
+                let variant_type = enum_type
+                    .get_variant_types(scope)
+                    .get(&pattern.name.value)?;
+
+                let arguments = match (variant_type, pattern.inner_pattern.as_ref()) {
+                    (Some(inner_type), Some(inner_pattern)) => {
+                        vec![Pattern::from(scope, inner_pattern, inner_type)?]
+                    }
+                    (None, None) => vec![],
+                    _ => return None,
+                };
+                let constructor = Constructor::Variant(pattern.name.value.clone());
+                Some(Pattern::Constructor(constructor, arguments))
+            }
+            (MatchPatternNode::Variant(pattern), Type::Struct(struct_type)) => {
+                let fields = pattern.inner_pattern.as_deref().map(std::slice::from_ref);
+                let fields = fields.unwrap_or_default();
+                Pattern::from_struct(scope, &pattern.name, fields, struct_type)
+            }
+            (MatchPatternNode::Struct(pattern), Type::Struct(struct_type)) => {
+                Pattern::from_struct(scope, &pattern.name, &pattern.fields, struct_type)
+            }
+            (MatchPatternNode::NamedStruct(pattern), Type::Struct(struct_type)) => {
+                Pattern::from_named_struct(scope, pattern, struct_type)
+            }
+            (MatchPatternNode::Tuple(patterns), Type::Tuple(tuple_type))
+                if patterns.len() == tuple_type.elements.len() =>
+            {
+                let arguments = Pattern::from_all(scope, patterns, &tuple_type.elements)?;
+                Some(Pattern::Constructor(Constructor::Tuple, arguments))
+            }
+            (MatchPatternNode::Literal(literal), _)
+                if get_literal_type(literal, subject_type)
+                    .is_assignable_to(subject_type, scope) =>
+            {
+                let value = match literal {
+                    ExpressionNode::BooleanLiteral(value) => value.to_string(),
+                    ExpressionNode::CharacterLiteral(value) => format!("'{value}'"),
+                    ExpressionNode::IntegerLiteral(value) => value.value.to_string(),
+                    ExpressionNode::StringLiteral(value) => format!("\"{value}\""),
+                    _ => return None,
+                };
+                Some(Pattern::Constructor(Constructor::Literal(value), vec![]))
+            }
+            _ => None,
+        }
+    }
+
+    fn from_struct(
+        scope: &Scope,
+        name: &NameNode,
+        fields: &[Node<MatchPatternNode>],
+        struct_type: &Rc<StructType>,
+    ) -> Option<Pattern> {
+        let field_types = &struct_type.get_constructor(scope).parameters;
+        if &name.value != struct_type.name() || fields.len() != field_types.len() {
+            return None;
+        }
+
+        let arguments = Pattern::from_all(scope, fields, field_types)?;
+        Some(Pattern::Constructor(
+            Constructor::Struct(name.value.clone()),
+            arguments,
+        ))
+    }
+
+    // Fields which are not named in the pattern become wildcards
+    fn from_named_struct(
+        scope: &Scope,
+        pattern: &NamedStructMatchPattern,
+        struct_type: &Rc<StructType>,
+    ) -> Option<Pattern> {
+        if &pattern.name.value != struct_type.name() {
+            return None;
+        }
+
+        let field_types = &struct_type.get_constructor(scope).parameters;
+        let mut arguments = vec![Pattern::Wildcard; field_types.len()];
+        for field in &pattern.fields {
+            let index = struct_type.get_field_index(&field.name.value)?;
+            arguments[index] = Pattern::from(scope, &field.pattern, &field_types[index])?;
+        }
+        Some(Pattern::Constructor(
+            Constructor::Struct(pattern.name.value.clone()),
+            arguments,
+        ))
+    }
+
+    fn from_all(
+        scope: &Scope,
+        nodes: &[Node<MatchPatternNode>],
+        types: &[Type],
+    ) -> Option<Vec<Pattern>> {
+        nodes
+            .iter()
+            .zip(types.iter())
+            .map(|(node, node_type)| Pattern::from(scope, node, node_type))
+            .collect()
+    }
+}
+
+// The constructors which together cover every value of a type, with their argument types, or
+// `None` if the values of the type cannot be enumerated
+fn get_constructors(scope: &Scope, subject_type: &Type) -> Option<Vec<(Constructor, Vec<Type>)>> {
+    match subject_type {
+        Type::Enum(enum_type) => Some(
+            enum_type
+                .get_variants(scope)
+                .into_iter()
+                .map(|(name, variant_type)| {
+                    let constructor = Constructor::Variant(name.clone());
+                    (constructor, variant_type.into_iter().cloned().collect())
+                })
+                .collect(),
+        ),
+        Type::Primitive(PrimitiveType::Bool) => Some(vec![
+            (Constructor::Literal("true".to_owned()), vec![]),
+            (Constructor::Literal("false".to_owned()), vec![]),
+        ]),
+        Type::Struct(struct_type) => {
+            let constructor = Constructor::Struct(struct_type.name().clone());
+            let field_types = struct_type.get_constructor(scope).parameters.clone();
+            Some(vec![(constructor, field_types)])
+        }
+        Type::Tuple(tuple_type) => Some(vec![(Constructor::Tuple, tuple_type.elements.clone())]),
+        _ => None,
+    }
+}
+
+// Whether the row matches any value which none of the previous rows match
+fn is_useful(scope: &Scope, rows: &[Vec<Pattern>], row: &[Pattern], types: &[Type]) -> bool {
+    let Some((head, tail)) = row.split_first() else {
+        return rows.is_empty();
+    };
+
+    match head {
+        Pattern::Constructor(constructor, arguments) => {
+            let argument_types = get_argument_types(scope, types, constructor);
+            let rows = specialize(rows, constructor, arguments.len());
+            let row = [arguments.as_slice(), tail].concat();
+            is_useful(
+                scope,
+                &rows,
+                &row,
+                &[argument_types, types[1..].to_vec()].concat(),
+            )
+        }
+        Pattern::Wildcard => {
+            // A column without constructors is not expanded, which would never end for a
+            // recursive type
+            let constructors =
+                get_constructors(scope, &types[0]).filter(|_| has_constructors(rows));
+            match constructors {
+                Some(constructors) => constructors.iter().any(|(constructor, argument_types)| {
+                    let rows = specialize(rows, constructor, argument_types.len());
+                    let wildcards = vec![Pattern::Wildcard; argument_types.len()];
+                    let row = [wildcards.as_slice(), tail].concat();
+                    let types = [argument_types.as_slice(), &types[1..]].concat();
+                    is_useful(scope, &rows, &row, &types)
+                }),
+                None => is_useful(scope, &default_rows(rows), tail, &types[1..]),
+            }
+        }
+    }
+}
+
+// Finds rows of patterns matching the values which none of the given rows match
+fn find_missing(scope: &Scope, rows: &[Vec<Pattern>], types: &[Type]) -> Vec<Vec<Pattern>> {
+    let Some((head_type, tail_types)) = types.split_first() else {
+        return if rows.is_empty() {
+            vec![vec![]]
+        } else {
+            vec![]
+        };
+    };
+
+    // A column without constructors is reported as a wildcard, rather than as every constructor
+    let constructors = get_constructors(scope, head_type).filter(|_| has_constructors(rows));
+    let Some(constructors) = constructors else {
+        return find_missing(scope, &default_rows(rows), tail_types)
+            .into_iter()
+            .map(|row| [vec![Pattern::Wildcard], row].concat())
+            .collect();
+    };
+
+    let mut missing = vec![];
+    for (constructor, argument_types) in constructors {
+        let arity = argument_types.len();
+        let rows = specialize(rows, &constructor, arity);
+        let types = [argument_types, tail_types.to_vec()].concat();
+        for row in find_missing(scope, &rows, &types) {
+            let (arguments, tail) = row.split_at(arity);
+            let head = Pattern::Constructor(constructor.clone(), arguments.to_vec());
+            missing.push([vec![head], tail.to_vec()].concat());
+        }
+    }
+
+    missing
+}
+
+// Keeps the rows which match the constructor, replacing their first pattern with its arguments
+fn specialize(rows: &[Vec<Pattern>], constructor: &Constructor, arity: usize) -> Vec<Vec<Pattern>> {
+    rows.iter()
+        .filter_map(|row| match &row[0] {
+            Pattern::Constructor(row_constructor, arguments) if row_constructor == constructor => {
+                Some([arguments.as_slice(), &row[1..]].concat())
+            }
+            Pattern::Constructor(..) => None,
+            Pattern::Wildcard => Some([vec![Pattern::Wildcard; arity], row[1..].to_vec()].concat()),
+        })
+        .collect()
+}
+
+fn has_constructors(rows: &[Vec<Pattern>]) -> bool {
+    rows.iter()
+        .any(|row| matches!(row[0], Pattern::Constructor(..)))
+}
+
+// Keeps the rows which match any value, removing their first pattern
+fn default_rows(rows: &[Vec<Pattern>]) -> Vec<Vec<Pattern>> {
+    rows.iter()
+        .filter(|row| matches!(row[0], Pattern::Wildcard))
+        .map(|row| row[1..].to_vec())
+        .collect()
+}
+
+fn get_argument_types(scope: &Scope, types: &[Type], constructor: &Constructor) -> Vec<Type> {
+    get_constructors(scope, &types[0])
+        .and_then(|constructors| {
+            constructors
+                .into_iter()
+                .find(|(candidate, _)| candidate == constructor)
+        })
+        .map_or_else(Vec::new, |(_, argument_types)| argument_types)
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Pattern::Constructor(constructor, arguments) = self else {
+            return write!(f, "_");
+        };
+
+        let arguments = arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match constructor {
+            Constructor::Literal(value) => write!(f, "{value}"),
+            Constructor::Tuple => write!(f, "({arguments})"),
+            Constructor::Struct(name) | Constructor::Variant(name) if !arguments.is_empty() => {
+                write!(f, "{name}({arguments})")
+            }
+            Constructor::Struct(name) | Constructor::Variant(name) => write!(f, "{name}"),
+        }
+    }
+}
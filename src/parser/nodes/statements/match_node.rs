@@ -1,6 +1,6 @@
 use crate::{
-    checker::{Scope, Type},
-    parser::{ExpressionNode, MatchCaseNode, Node},
+    checker::{Assignments, Scope, Type},
+    parser::{ExpressionNode, MatchCaseNode, Node, check_exhaustiveness},
 };
 
 pub struct MatchNode {
@@ -10,25 +10,30 @@ pub struct MatchNode {
 
 impl MatchNode {
     pub fn check_statement(&self, scope: Box<Scope>) -> Box<Scope> {
-        let (mut scope, subject_type) = self.check_subject(scope);
+        let (mut scope, subject_type) = self.subject.check(scope);
+        let before = scope.get_assignments();
+        let mut paths = vec![];
         for case in &self.cases {
             let (new_scope, _) = case.check(scope, None, &subject_type);
-            scope = new_scope;
+            scope = self.end_case(new_scope, case, &before, &mut paths);
         }
 
+        scope.set_assignments(&Assignments::join(paths));
+        self.check_cases(&scope, &subject_type);
         scope
     }
 
     pub fn check(&self, scope: Box<Scope>, expected_type: Option<&Type>) -> (Box<Scope>, Type) {
-        let (mut scope, subject_type) = self.check_subject(scope);
+        let (mut scope, subject_type) = self.subject.check(scope);
+        let before = scope.get_assignments();
+        let mut paths = vec![];
         let mut resolved_type = None;
 
         // TODO don't check for match branches in statement version
-        // TODO completeness check
 
         for case in &self.cases {
             let (new_scope, case_type) = case.check(scope, expected_type, &subject_type);
-            scope = new_scope;
+            scope = self.end_case(new_scope, case, &before, &mut paths);
 
             // TODO dedupe with array parsing potentially
             if let Some(t) = resolved_type.as_ref() {
@@ -37,7 +42,7 @@ impl MatchNode {
                 } else if t.is_assignable_to(&case_type, &scope) {
                     resolved_type = Some(case_type);
                 } else {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         case.if_match.span,
                         "Match cases types don't match",
                         &format!("case results in type `{case_type}` which does not match previous type `{t}`"),
@@ -48,6 +53,8 @@ impl MatchNode {
             }
         }
 
+        scope.set_assignments(&Assignments::join(paths));
+        self.check_cases(&scope, &subject_type);
         if let Some(resolved_type) = resolved_type {
             (scope, resolved_type)
         } else {
@@ -55,17 +62,28 @@ impl MatchNode {
         }
     }
 
-    fn check_subject(&self, scope: Box<Scope>) -> (Box<Scope>, Type) {
-        let (scope, subject_type) = self.subject.check(scope);
-        if !matches!(subject_type, Type::Enum(_)) && !subject_type.is_error() {
-            // TODO handle other types besides enums
-            scope.source.print_error(
-                self.subject.span,
-                "Match expressions only support enums",
-                &format!("found type: `{subject_type}`"),
-            );
+    pub fn diverges(&self) -> bool {
+        !self.cases.is_empty() && self.cases.iter().all(|case| case.if_match.diverges())
+    }
+
+    // Records the assignments made by a case, and resets them before checking the next case
+    fn end_case(
+        &self,
+        mut scope: Box<Scope>,
+        case: &MatchCaseNode,
+        before: &Assignments,
+        paths: &mut Vec<Assignments>,
+    ) -> Box<Scope> {
+        if !case.if_match.diverges() {
+            paths.push(scope.get_assignments());
         }
+        scope.set_assignments(before);
+        scope
+    }
 
-        (scope, subject_type)
+    fn check_cases(&self, scope: &Scope, subject_type: &Type) {
+        if !subject_type.is_error() {
+            check_exhaustiveness(scope, self.subject.span, subject_type, &self.cases);
+        }
     }
 }
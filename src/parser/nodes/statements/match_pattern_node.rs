@@ -1,13 +1,24 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use crate::{
-    checker::{Scope, Type},
-    parser::{NameNode, Node, TokenSpan},
+    checker::{Scope, StructType, Type},
+    parser::{
+        ExpressionNode, NameNode, Node, PrimitiveType, TokenSpan, check_integer_literal,
+        check_private_member,
+    },
 };
 
 pub enum MatchPatternNode {
     Variant(VariantMatchPattern),
+    Struct(StructMatchPattern),
+    NamedStruct(NamedStructMatchPattern),
+    Tuple(Vec<Node<MatchPatternNode>>),
+    Literal(ExpressionNode),
     Binding(NameNode),
+    Wildcard,
     Else,
 }
 
@@ -21,9 +32,31 @@ impl MatchPatternNode {
     ) {
         match self {
             MatchPatternNode::Variant(pattern) => pattern.check(scope, bindings, subject_type),
+            MatchPatternNode::Struct(pattern) => pattern.check(scope, bindings, subject_type),
+            MatchPatternNode::NamedStruct(pattern) => pattern.check(scope, bindings, subject_type),
+            MatchPatternNode::Tuple(patterns) => {
+                check_tuple_pattern(scope, span, bindings, patterns, subject_type);
+            }
+            MatchPatternNode::Literal(literal) => {
+                let literal_type = match literal {
+                    ExpressionNode::IntegerLiteral(value) => {
+                        check_integer_literal(scope, span, value.value, Some(subject_type))
+                    }
+                    _ => get_literal_type(literal, subject_type),
+                };
+                if !literal_type.is_assignable_to(subject_type, scope) && !subject_type.is_error() {
+                    scope.diagnostics.error(
+                        span,
+                        "Mismatched pattern type",
+                        &format!(
+                            "expected `{subject_type}`, found literal of type `{literal_type}`"
+                        ),
+                    );
+                }
+            }
             MatchPatternNode::Binding(name) => {
                 if bindings.contains_key(&name.value) {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         span,
                         &format!("Duplicate pattern binding of `{name}`"),
                         "a binding of this name is declared elsewhere in this pattern",
@@ -32,9 +65,31 @@ impl MatchPatternNode {
                     bindings.insert(name.value.clone(), subject_type.clone());
                 }
             }
-            MatchPatternNode::Else => {}
+            MatchPatternNode::Wildcard | MatchPatternNode::Else => {}
         }
     }
+
+    // The names bound by the pattern, in the order they appear
+    pub fn get_bindings(&self) -> Vec<&NameNode> {
+        let patterns: Vec<&Node<MatchPatternNode>> = match self {
+            MatchPatternNode::Binding(name) => return vec![name],
+            MatchPatternNode::Variant(pattern) => {
+                pattern.inner_pattern.as_deref().into_iter().collect()
+            }
+            MatchPatternNode::Struct(pattern) => pattern.fields.iter().collect(),
+            MatchPatternNode::NamedStruct(pattern) => {
+                pattern.fields.iter().map(|field| &field.pattern).collect()
+            }
+            MatchPatternNode::Tuple(patterns) => patterns.iter().collect(),
+            MatchPatternNode::Literal(_) | MatchPatternNode::Wildcard | MatchPatternNode::Else => {
+                vec![]
+            }
+        };
+        patterns
+            .into_iter()
+            .flat_map(|pattern| pattern.get_bindings())
+            .collect()
+    }
 }
 
 pub struct VariantMatchPattern {
@@ -44,12 +99,24 @@ pub struct VariantMatchPattern {
 
 impl VariantMatchPattern {
     pub fn check(&self, scope: &Scope, bindings: &mut HashMap<String, Type>, subject_type: &Type) {
+        if let Type::Struct(struct_type) = subject_type {
+            // A struct with a single field is destructured like a variant
+            let fields = self.inner_pattern.as_deref().map(std::slice::from_ref);
+            return check_struct_pattern(
+                scope,
+                bindings,
+                &self.name,
+                fields.unwrap_or_default(),
+                struct_type,
+            );
+        }
+
         if let Type::Enum(enum_type) = subject_type {
-            if let Some(variant) = enum_type.variants.get(&self.name.value) {
+            if let Some(variant) = enum_type.get_variant_types(scope).get(&self.name.value) {
                 if let Some(inner_type) = variant {
                     if self.inner_pattern.is_none() {
                         // TODO consider relaxing this when the subject is just a name...
-                        scope.source.print_error(
+                        scope.diagnostics.error(
                             self.name.span,
                             "Expected binding pattern",
                             &format!("typed variant `{}` must have a binding pattern", self.name),
@@ -58,7 +125,7 @@ impl VariantMatchPattern {
                         return self.check_inner_pattern(scope, bindings, inner_type);
                     }
                 } else if let Some(inner_pattern) = self.inner_pattern.as_ref() {
-                    scope.source.print_error(
+                    scope.diagnostics.error(
                         inner_pattern.span,
                         "Unexpected binding pattern",
                         &format!(
@@ -68,17 +135,17 @@ impl VariantMatchPattern {
                     );
                 }
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     self.name.span,
                     &format!("Could not find variant `{}`", self.name),
                     &format!("enum `{}` has no such variant", enum_type.name()),
                 );
             }
         } else if !subject_type.is_error() {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.name.span,
                 "Unexpected variant pattern",
-                &format!("cannot use variant pattern on non-enum type `{subject_type}`"),
+                &format!("cannot use variant pattern on type `{subject_type}`"),
             );
         }
         self.check_inner_pattern(scope, bindings, &Type::Error);
@@ -95,3 +162,216 @@ impl VariantMatchPattern {
         }
     }
 }
+
+pub struct StructMatchPattern {
+    pub name: NameNode,
+    pub fields: Vec<Node<MatchPatternNode>>,
+}
+
+impl StructMatchPattern {
+    pub fn check(&self, scope: &Scope, bindings: &mut HashMap<String, Type>, subject_type: &Type) {
+        if let Type::Struct(struct_type) = subject_type {
+            return check_struct_pattern(scope, bindings, &self.name, &self.fields, struct_type);
+        }
+
+        if !subject_type.is_error() {
+            scope.diagnostics.error(
+                self.name.span,
+                "Unexpected struct pattern",
+                &format!("cannot use struct pattern on type `{subject_type}`"),
+            );
+        }
+        check_patterns(scope, bindings, &self.fields, &[]);
+    }
+}
+
+pub struct NamedStructMatchPattern {
+    pub name: NameNode,
+    pub fields: Vec<NamedFieldPattern>,
+}
+
+pub struct NamedFieldPattern {
+    pub name: NameNode,
+    pub pattern: Node<MatchPatternNode>,
+}
+
+impl NamedStructMatchPattern {
+    // Fields which are not named in the pattern match any value
+    pub fn check(&self, scope: &Scope, bindings: &mut HashMap<String, Type>, subject_type: &Type) {
+        let struct_type = match subject_type {
+            Type::Struct(struct_type) if &self.name.value == struct_type.name() => struct_type,
+            Type::Struct(struct_type) => {
+                scope.diagnostics.error(
+                    self.name.span,
+                    "Mismatched struct pattern",
+                    &format!("expected struct `{}`", struct_type.name()),
+                );
+                return self.check_fields(scope, bindings, |_| None);
+            }
+            _ => {
+                if !subject_type.is_error() {
+                    scope.diagnostics.error(
+                        self.name.span,
+                        "Unexpected struct pattern",
+                        &format!("cannot use struct pattern on type `{subject_type}`"),
+                    );
+                }
+                return self.check_fields(scope, bindings, |_| None);
+            }
+        };
+
+        let field_types = &struct_type.get_constructor(scope).parameters;
+        let mut named = HashSet::new();
+        self.check_fields(scope, bindings, |field| {
+            let Some(index) = struct_type.get_field_index(&field.value) else {
+                scope.diagnostics.error(
+                    field.span,
+                    &format!("Could not find field `{field}`"),
+                    &format!("struct `{}` has no such field", struct_type.name()),
+                );
+                return None;
+            };
+            if !named.insert(index) {
+                scope.diagnostics.error(
+                    field.span,
+                    &format!("Duplicate field `{field}` in pattern"),
+                    "this field is matched elsewhere in this pattern",
+                );
+            }
+
+            let field_node = &struct_type.get_field_node(&field.value)?;
+            if !field_node.public {
+                check_private_member(scope, subject_type, field, field_node.name.span);
+            }
+            field_types.get(index).cloned()
+        });
+    }
+
+    fn check_fields(
+        &self,
+        scope: &Scope,
+        bindings: &mut HashMap<String, Type>,
+        mut get_field_type: impl FnMut(&NameNode) -> Option<Type>,
+    ) {
+        for field in &self.fields {
+            let field_type = get_field_type(&field.name).unwrap_or(Type::Error);
+            field
+                .pattern
+                .check(scope, field.pattern.span, bindings, &field_type);
+        }
+    }
+}
+
+fn check_struct_pattern(
+    scope: &Scope,
+    bindings: &mut HashMap<String, Type>,
+    name: &NameNode,
+    fields: &[Node<MatchPatternNode>],
+    struct_type: &Rc<StructType>,
+) {
+    if &name.value != struct_type.name() {
+        scope.diagnostics.error(
+            name.span,
+            "Mismatched struct pattern",
+            &format!("expected struct `{}`", struct_type.name()),
+        );
+        return check_patterns(scope, bindings, fields, &[]);
+    }
+
+    let field_types = &struct_type.get_constructor(scope).parameters;
+    if field_types.len() != fields.len() {
+        scope.diagnostics.error(
+            name.span,
+            "Wrong number of fields in pattern",
+            &format!(
+                "struct `{}` has {} fields, but the pattern has {}",
+                struct_type.name(),
+                field_types.len(),
+                fields.len()
+            ),
+        );
+    }
+
+    // Matching a private field by position reads it just like matching it by name
+    let subject_type = Type::Struct(struct_type.clone());
+    for (pattern, field_name) in fields.iter().zip(struct_type.get_field_names()) {
+        let Some(field) = struct_type.get_field_node(field_name) else {
+            continue;
+        };
+        if !field.public && !matches!(pattern.value, MatchPatternNode::Wildcard) {
+            let name = NameNode {
+                value: field_name.clone(),
+                span: pattern.span,
+            };
+            check_private_member(scope, &subject_type, &name, field.name.span);
+        }
+    }
+    check_patterns(scope, bindings, fields, field_types);
+}
+
+fn check_tuple_pattern(
+    scope: &Scope,
+    span: TokenSpan,
+    bindings: &mut HashMap<String, Type>,
+    patterns: &[Node<MatchPatternNode>],
+    subject_type: &Type,
+) {
+    match subject_type {
+        Type::Tuple(tuple_type) => {
+            let element_types = &tuple_type.elements;
+            if element_types.len() != patterns.len() {
+                scope.diagnostics.error(
+                    span,
+                    "Wrong number of elements in pattern",
+                    &format!(
+                        "expected {} elements for type `{subject_type}`, found {}",
+                        element_types.len(),
+                        patterns.len()
+                    ),
+                );
+            }
+            check_patterns(scope, bindings, patterns, element_types);
+        }
+        _ => {
+            if !subject_type.is_error() {
+                scope.diagnostics.error(
+                    span,
+                    "Unexpected tuple pattern",
+                    &format!("cannot use tuple pattern on type `{subject_type}`"),
+                );
+            }
+            check_patterns(scope, bindings, patterns, &[]);
+        }
+    }
+}
+
+// Checks each pattern against the corresponding type, using the error type for missing types
+fn check_patterns(
+    scope: &Scope,
+    bindings: &mut HashMap<String, Type>,
+    patterns: &[Node<MatchPatternNode>],
+    types: &[Type],
+) {
+    for (index, pattern) in patterns.iter().enumerate() {
+        let pattern_type = types.get(index).unwrap_or(&Type::Error);
+        pattern.check(scope, pattern.span, bindings, pattern_type);
+    }
+}
+
+// Integer literal patterns take on the type of an integer subject
+pub fn get_literal_type(literal: &ExpressionNode, subject_type: &Type) -> Type {
+    match (literal, subject_type) {
+        (ExpressionNode::IntegerLiteral(_), Type::Primitive(primitive))
+            if primitive.is_integer() =>
+        {
+            subject_type.clone()
+        }
+        (ExpressionNode::BooleanLiteral(_), _) => Type::Primitive(PrimitiveType::Bool),
+        (ExpressionNode::CharacterLiteral(_), _) => Type::Primitive(PrimitiveType::Char),
+        (ExpressionNode::IntegerLiteral(_), _) => Type::Primitive(PrimitiveType::Int),
+        (ExpressionNode::StringLiteral(_), _) => {
+            Type::Array(Box::new(Type::Primitive(PrimitiveType::Char)))
+        }
+        _ => Type::Error,
+    }
+}
@@ -1,18 +1,24 @@
 mod block_node;
+mod control_flow;
 mod declaration_node;
+mod for_loop_node;
 mod if_statement_condition_node;
 mod if_statement_node;
 mod match_case_node;
+mod match_exhaustiveness;
 mod match_node;
 mod match_pattern_node;
 mod statement_node;
 mod while_loop_node;
 
 pub use block_node::*;
+pub use control_flow::*;
 pub use declaration_node::*;
+pub use for_loop_node::*;
 pub use if_statement_condition_node::*;
 pub use if_statement_node::*;
 pub use match_case_node::*;
+pub use match_exhaustiveness::*;
 pub use match_node::*;
 pub use match_pattern_node::*;
 pub use statement_node::*;
@@ -2,7 +2,7 @@ use crate::{
     checker::{Scope, ScopeType, Type, Types},
     lexer::Keyword,
     parser::{
-        DeclarationNode, ExpressionNode, IfStatementNode, MatchNode, Node, TokenSpan,
+        DeclarationNode, ExpressionNode, ForLoopNode, IfStatementNode, MatchNode, Node, TokenSpan,
         TypeAliasNode, WhileLoopNode,
     },
 };
@@ -13,6 +13,7 @@ pub enum StatementNode {
     Continue,
     Declaration(DeclarationNode),
     Expression(ExpressionNode),
+    ForLoop(ForLoopNode),
     FunctionReturn(Option<Node<ExpressionNode>>),
     If(IfStatementNode),
     Match(MatchNode),
@@ -29,7 +30,8 @@ impl StatementNode {
     ) -> (Box<Scope>, Option<Type>) {
         match self {
             Self::BlockReturn(expression) => {
-                let (scope, resolved_type) = expression.check_expected(scope, expected_type);
+                let (scope, resolved_type) =
+                    expression.check_optional(scope, expression.span, expected_type);
                 (scope, Some(resolved_type))
             }
             Self::Break => check_loop(Keyword::Break, span, scope),
@@ -40,6 +42,7 @@ impl StatementNode {
                 let (scope, _) = expression.check(scope);
                 (scope, None)
             }
+            Self::ForLoop(node) => (node.check(scope), None),
             Self::FunctionReturn(expression) => {
                 check_function_return(expression.as_ref(), span, scope)
             }
@@ -49,11 +52,26 @@ impl StatementNode {
             Self::WhileLoop(node) => (node.check(scope), None),
         }
     }
+
+    // Whether the statement always leaves the enclosing loop or function
+    pub fn diverges(&self) -> bool {
+        match self {
+            Self::Break | Self::Continue | Self::FunctionReturn(_) => true,
+            Self::If(node) => node.diverges(),
+            Self::Match(node) => node.diverges(),
+            _ => false,
+        }
+    }
 }
 
-fn check_loop(keyword: Keyword, span: TokenSpan, scope: Box<Scope>) -> (Box<Scope>, Option<Type>) {
+fn check_loop(
+    keyword: Keyword,
+    span: TokenSpan,
+    mut scope: Box<Scope>,
+) -> (Box<Scope>, Option<Type>) {
+    scope.record_loop_jump(keyword == Keyword::Break);
     if !scope.within(|scope_type| matches!(scope_type, ScopeType::Loop)) {
-        scope.source.print_error(
+        scope.diagnostics.error(
             span,
             &format!("Unexpected {keyword}"),
             &format!("{keyword} is not valid outside of a loop"),
@@ -70,7 +88,9 @@ fn check_function_return(
     let expected_type = scope.get_return_type();
     if let Some(expected_type) = expected_type {
         let (new_scope, resolved_type) = match expression {
-            Some(expression) => expression.check_expected(scope, Some(&expected_type)),
+            Some(expression) => {
+                expression.check_optional(scope, expression.span, Some(&expected_type))
+            }
             None => (scope, Type::Void),
         };
         scope = new_scope;
@@ -80,7 +100,7 @@ fn check_function_return(
                 Some(expression) => expression.span,
                 None => statement_span,
             };
-            scope.source.print_error(
+            scope.diagnostics.error(
                 error_span,
                 &format!("Function must return value of type `{expected_type}`"),
                 &format!("found type: `{resolved_type}`"),
@@ -88,7 +108,7 @@ fn check_function_return(
         }
     } else {
         // TODO should this be a panic? I don't think this ought to occur
-        scope.source.print_error(
+        scope.diagnostics.error(
             statement_span,
             "Unexpected return",
             "return found outside of a function",
@@ -1,6 +1,6 @@
 use crate::{
-    checker::{Scope, ScopeType},
-    parser::{BlockNode, ExpressionNode, Node, PrimitiveType},
+    checker::{Assignment, Assignments, LoopAssignment, LoopState, Scope, ScopeType},
+    parser::{BlockNode, ExpressionNode, Node, PrimitiveType, report_assign_to_immutable},
 };
 
 pub struct WhileLoopNode {
@@ -12,16 +12,64 @@ impl WhileLoopNode {
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
         let (scope, predicate_type) = self.predicate.check(scope);
         if !predicate_type.is_primitive(PrimitiveType::Bool) {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 self.predicate.span,
                 "While loop predicate expected to be bool",
                 &format!("found type: `{predicate_type}`"),
             );
         }
 
-        scope.nest(ScopeType::Loop, |scope| {
-            let (scope, _) = self.body.check(scope, None);
-            scope
-        })
+        // The loop is left when the predicate is false, before or after any run of the body, or
+        // with `break`. A constant `true` predicate can only be left with `break`.
+        let before = scope.get_assignments();
+        let (mut scope, (end, state)) = scope.nest_with(ScopeType::Loop, |scope| {
+            let (mut scope, _) = self.body.check(scope, None);
+            let end = (!self.body.diverges()).then(|| scope.get_assignments());
+            let state = scope.take_loop_state();
+            (scope, (end, state))
+        });
+        let conditional = !matches!(self.predicate.value, ExpressionNode::BooleanLiteral(true));
+        let assignments = finish_loop(&mut scope, before, end, state, conditional);
+        scope.set_assignments(&assignments);
+        scope
     }
 }
+
+// The assignments after a loop, from those before it, at the end of its body and at each `break`
+// and `continue`. Immutable values assigned by the body are reported if the body may run again
+// after assigning them.
+pub fn finish_loop(
+    scope: &mut Scope,
+    before: Assignments,
+    end: Option<Assignments>,
+    state: LoopState,
+    conditional: bool,
+) -> Assignments {
+    let mut repeats = state.continues;
+    repeats.extend(end);
+    let repeated = (!repeats.is_empty()).then(|| Assignments::join(repeats.clone()));
+    for assignment in state.assignments {
+        match &repeated {
+            Some(repeated)
+                if repeated.get(assignment.depth, &assignment.name) != Assignment::Unassigned =>
+            {
+                let LoopAssignment {
+                    name,
+                    span,
+                    declaration,
+                    ..
+                } = assignment;
+                report_assign_to_immutable(scope, span, &name, declaration, true);
+            }
+            // An enclosing loop may still run this loop again
+            _ => scope.record_loop_assignment(assignment),
+        }
+    }
+
+    let mut paths = state.breaks;
+    if conditional {
+        paths.push(before);
+        paths.extend(repeats);
+    }
+    Assignments::join(paths)
+}
@@ -1,7 +1,8 @@
 use std::{fmt::Display, rc::Rc};
 
 use crate::{
-    lexer::{Keyword, LocatedToken, Symbol, Token},
+    diagnostics::{Diagnostic, Label, Phase, Severity, SourceSpan},
+    lexer::{Keyword, LocatedToken, SourceCode, Symbol, Token},
     parser::TokenSpan,
 };
 
@@ -9,6 +10,8 @@ use crate::{
 pub enum SyntaxError {
     BlockReturnEarly,
     ExpectedBlock,
+    ExpectedCallOperator,
+    ExpectedCloseBrace,
     ExpectedCloseBracket,
     ExpectedCloseParen,
     ExpectedClosureBody,
@@ -17,8 +20,11 @@ pub enum SyntaxError {
     ExpectedElse,
     ExpectedEndStatement,
     ExpectedExpression,
+    ExpectedFieldPattern,
     ExpectedFields,
     ExpectedFunctionBody,
+    ExpectedImportPath,
+    ExpectedIn,
     ExpectedInitializer,
     ExpectedMatchBlock,
     ExpectedMatchExpression,
@@ -34,6 +40,7 @@ pub enum SyntaxError {
     UnexpectedBindingPattern,
     UnexpectedBlockReturn(StatementType),
     UnexpectedMethodSignatureQualifier(Keyword),
+    UnexpectedSpreadParameter,
 }
 
 #[derive(Clone, Copy)]
@@ -73,6 +80,7 @@ impl Display for NameType {
 #[derive(Clone, Copy)]
 pub enum StatementType {
     Block,
+    ForLoop,
     If,
     WhileLoop,
 }
@@ -81,6 +89,7 @@ impl Display for StatementType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Block => write!(f, "statement block"),
+            Self::ForLoop => write!(f, "for loop"),
             Self::If => write!(f, "if statement"),
             Self::WhileLoop => write!(f, "while loop"),
         }
@@ -93,21 +102,29 @@ pub struct LocatedSyntaxError {
 }
 
 impl LocatedSyntaxError {
-    pub fn message(&self, tokens: Rc<Vec<LocatedToken>>) -> SyntaxErrorMessage<'_> {
+    pub fn message<'a>(&'a self, source: &'a SourceCode) -> SyntaxErrorMessage<'a> {
         SyntaxErrorMessage {
             error: self,
-            tokens,
+            source,
         }
     }
 
     pub fn inline_message(&self) -> SyntaxErrorInlineMessage<'_> {
         SyntaxErrorInlineMessage { error: self }
     }
+
+    pub fn to_diagnostic(&self, source: &Rc<SourceCode>) -> Diagnostic {
+        let message = self.message(source).to_string();
+        let inline_message = self.inline_message().to_string();
+        let span = SourceSpan::new(source.clone(), source.character_span(self.span));
+        let label = Label::new(span, &inline_message);
+        Diagnostic::new(Severity::Error, Phase::Parser, &message, label)
+    }
 }
 
 pub struct SyntaxErrorMessage<'a> {
     error: &'a LocatedSyntaxError,
-    tokens: Rc<Vec<LocatedToken>>,
+    source: &'a SourceCode,
 }
 
 impl Display for SyntaxErrorMessage<'_> {
@@ -117,6 +134,8 @@ impl Display for SyntaxErrorMessage<'_> {
         match self.error.error {
             E::BlockReturnEarly => return write!(f, "early block return statement"),
             E::ExpectedBlock => write!(f, "expected statement block"),
+            E::ExpectedCallOperator => write!(f, "expected call operator"),
+            E::ExpectedCloseBrace => write!(f, "expected close brace"),
             E::ExpectedCloseBracket => write!(f, "expected close bracket"),
             E::ExpectedCloseParen => write!(f, "expected close parenthesis"),
             E::ExpectedClosureBody => write!(f, "expected closure body"),
@@ -125,8 +144,11 @@ impl Display for SyntaxErrorMessage<'_> {
             E::ExpectedElse => write!(f, "`{}` following true branch expression", Keyword::Else),
             E::ExpectedEndStatement => write!(f, "expected end of statement"),
             E::ExpectedExpression | E::ExpectedMatchExpression => write!(f, "expected expression"),
+            E::ExpectedFieldPattern => write!(f, "expected `:` following field name"),
             E::ExpectedFields => write!(f, "expected fields"),
             E::ExpectedFunctionBody => write!(f, "expected function body"),
+            E::ExpectedImportPath => write!(f, "expected import path"),
+            E::ExpectedIn => write!(f, "expected `{}` following loop variable", Keyword::In),
             E::ExpectedInitializer => write!(f, "expected initializer"),
             E::ExpectedMatchBlock => write!(f, "expected match block"),
             E::ExpectedMatchPattern => write!(f, "expected match pattern"),
@@ -135,7 +157,9 @@ impl Display for SyntaxErrorMessage<'_> {
             E::ExpectedName(name_type) => write!(f, "expected {name_type}"),
             E::ExpectedParameters => write!(f, "expected parameters"),
             E::ExpectedThen => write!(f, "expected `{}` following predicate`", Keyword::Then),
-            E::ExpectedTopLevelDefinition => write!(f, "expected struct, tuple, enum, or function"),
+            E::ExpectedTopLevelDefinition => {
+                write!(f, "expected import, struct, tuple, enum, or function")
+            }
             E::ExpectedType => write!(f, "expected type name"),
             E::ExpectedVariants => write!(f, "expected enum variants"),
             E::UnexpectedBindingPattern => {
@@ -147,13 +171,17 @@ impl Display for SyntaxErrorMessage<'_> {
             E::UnexpectedMethodSignatureQualifier(keyword) => {
                 return write!(f, "unexpected qualifier `{keyword}` for interface method");
             }
+            E::UnexpectedSpreadParameter => {
+                return write!(f, "spread parameter must be the last closure parameter");
+            }
         }?;
         write!(f, ", found ")?;
 
-        let LocatedToken { token, .. } = &self.tokens[self.error.span.start_index];
+        let LocatedToken { token, .. } = self.source.token(self.error.span.start_index);
         match token {
             T::CharacterLiteral(_) => write!(f, "character literal"),
             T::Name(name) => write!(f, "name `{name}`"),
+            T::FloatLiteral(literal) => write!(f, "float literal `{literal:?}`"),
             T::IntegerLiteral(literal) => write!(f, "integer literal `{literal}`"),
             T::StringLiteral(literal) => write!(f, "string literal {literal}"),
             T::Symbol(symbol) => write!(f, "`{symbol}`"),
@@ -176,6 +204,10 @@ impl Display for SyntaxErrorInlineMessage<'_> {
             E::ExpectedBlock | E::ExpectedMatchBlock | E::ExpectedMethodSignatures => {
                 fmt_symbol(f, S::OpenBrace)
             }
+            E::ExpectedCallOperator => {
+                write!(f, "expected `()` following `{}`", Keyword::Operator)
+            }
+            E::ExpectedCloseBrace => fmt_symbol(f, S::CloseBrace),
             E::ExpectedCloseBracket => fmt_symbol(f, S::CloseBracket),
             E::ExpectedCloseParen => fmt_symbol(f, S::CloseParen),
             E::ExpectedClosureBody | E::ExpectedMatchExpression => fmt_symbol(f, S::SkinnyArrow),
@@ -184,16 +216,21 @@ impl Display for SyntaxErrorInlineMessage<'_> {
             E::ExpectedElse => write!(f, "expected `{}`", Keyword::Else),
             E::ExpectedEndStatement => fmt_symbol(f, S::Semicolon),
             E::ExpectedExpression => write!(f, "expected expression"),
+            E::ExpectedFieldPattern => fmt_symbol(f, S::Colon),
             E::ExpectedFields | E::ExpectedParameters | E::ExpectedVariants => {
                 fmt_symbol(f, S::OpenParen)
             }
             E::ExpectedFunctionBody => fmt_symbols(f, S::SkinnyArrow, S::OpenBrace),
+            E::ExpectedImportPath => write!(f, "expected file path e.g. \"module.txt\""),
+            E::ExpectedIn => write!(f, "expected `{}`", Keyword::In),
             E::ExpectedInitializer => fmt_symbol(f, S::Equal),
             E::ExpectedMatchPattern => write!(f, "expected pattern e.g. Variant(let binding)"),
             E::ExpectedMethods => fmt_symbols(f, S::OpenBrace, S::Semicolon),
             E::ExpectedName(name_type) => write!(f, "expected {name_type}"),
             E::ExpectedThen => write!(f, "expected `{}`", Keyword::Then),
-            E::ExpectedTopLevelDefinition => write!(f, "expected struct, tuple, enum, or function"),
+            E::ExpectedTopLevelDefinition => {
+                write!(f, "expected import, struct, tuple, enum, or function")
+            }
             E::ExpectedType => write!(f, "expected type name"),
             E::UnexpectedBindingPattern => {
                 write!(
@@ -208,6 +245,12 @@ impl Display for SyntaxErrorInlineMessage<'_> {
             E::UnexpectedMethodSignatureQualifier(_) => {
                 write!(f, "interface methods must not be qualified")
             }
+            E::UnexpectedSpreadParameter => {
+                write!(
+                    f,
+                    "the remaining arguments are collected by the last parameter"
+                )
+            }
         }
     }
 }
@@ -1,12 +1,16 @@
 use std::{cell::OnceCell, collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{EnumType, Scope, ScopeType, Types},
-    parser::{EnumVariantNode, ImplementationNode, ImplementationType, NameNode, Node, NodeVec},
+    checker::{EnumType, Scope, ScopeType, TypeParameterList, TypeParameterMap, Types},
+    parser::{
+        EnumVariantNode, ImplementationNode, ImplementationType, NameNode, Node, NodeVec,
+        TypeParameterListNode,
+    },
 };
 
 pub struct EnumNode {
     pub name: NameNode,
+    pub type_parameters: Option<Node<TypeParameterListNode>>,
     pub variants: NodeVec<EnumVariantNode>,
     pub implementation: Option<Node<ImplementationNode>>,
     resolved_type: OnceCell<Rc<EnumType>>,
@@ -15,11 +19,13 @@ pub struct EnumNode {
 impl EnumNode {
     pub fn new(
         name: NameNode,
+        type_parameters: Option<Node<TypeParameterListNode>>,
         variants: NodeVec<EnumVariantNode>,
         implementation: Option<Node<ImplementationNode>>,
     ) -> Self {
         Self {
             name,
+            type_parameters,
             variants,
             implementation,
             resolved_type: OnceCell::new(),
@@ -28,14 +34,19 @@ impl EnumNode {
 
     pub fn check(self: &Rc<Self>, scope: Box<Scope>) -> Box<Scope> {
         let self_type = self.get_type(&*scope);
-        scope.nest(ScopeType::Enum(self_type), |scope| self.check_nested(scope))
+        scope.nest(ScopeType::Enum(self_type), |mut scope| {
+            if let Some(type_parameters) = self.type_parameters.as_ref() {
+                scope = type_parameters.check(scope, type_parameters.span);
+            }
+            self.check_nested(scope)
+        })
     }
 
     fn check_nested(self: &Rc<Self>, scope: Box<Scope>) -> Box<Scope> {
         let mut scope_names = HashSet::new();
         for variant in self.variants.iter() {
             if !scope_names.insert(variant.name.clone()) {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     variant.name.span,
                     &format!("Duplicate enum variant `{}`", variant.name),
                     &format!(
@@ -44,6 +55,10 @@ impl EnumNode {
                     ),
                 );
             }
+
+            if let Some(type_def) = variant.type_def.as_ref() {
+                type_def.check(&scope);
+            }
         }
 
         if let Some(implementation) = self.implementation.as_ref() {
@@ -54,9 +69,32 @@ impl EnumNode {
         scope
     }
 
+    pub fn get_type_parameters(&self) -> TypeParameterList {
+        self.type_parameters
+            .as_ref()
+            .map_or_else(TypeParameterList::default, |type_parameters| {
+                type_parameters.get_types_list().clone()
+            })
+    }
+
+    pub fn get_type_parameters_map(&self) -> Option<&TypeParameterMap> {
+        self.type_parameters
+            .as_ref()
+            .map(|type_parameters| type_parameters.get_types_map())
+    }
+
     pub fn get_type(self: &Rc<Self>, types: &impl Types) -> Rc<EnumType> {
-        self.resolved_type
-            .get_or_init(|| EnumType::from(self.clone(), types))
-            .clone()
+        if let Some(enum_type) = self.resolved_type.get() {
+            return enum_type.clone();
+        }
+
+        let enum_type = self
+            .resolved_type
+            .get_or_init(|| EnumType::from(self.clone()))
+            .clone();
+        // Initialize the variants using the module level types, once the type itself is
+        // resolved so that variants can refer back to the enum
+        enum_type.get_variant_types(types);
+        enum_type
     }
 }
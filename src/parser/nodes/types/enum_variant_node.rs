@@ -1,7 +1,7 @@
 use std::cell::OnceCell;
 
 use crate::{
-    checker::{Type, Types},
+    checker::{Type, TypeParameterMap, Types},
     parser::{NameNode, Node, TypeNode},
 };
 
@@ -20,15 +20,23 @@ impl EnumVariantNode {
         }
     }
 
-    pub fn get_type(&self, types: &impl Types) -> Option<&Type> {
+    pub fn get_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Option<&Type> {
         self.resolved_type
-            .get_or_init(|| self.init_type(types))
+            .get_or_init(|| self.init_type(types, type_params))
             .as_ref()
     }
 
-    fn init_type(&self, types: &impl Types) -> Option<Type> {
+    fn init_type(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Option<Type> {
         self.type_def
             .as_ref()
-            .map(|ty| ty.get_type(types, None, None))
+            .map(|ty| ty.get_type(types, type_params, None))
     }
 }
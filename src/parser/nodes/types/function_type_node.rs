@@ -1,8 +1,8 @@
 use std::{cell::OnceCell, rc::Rc};
 
 use crate::{
-    checker::{FunctionType, TypeParameterMap, Types},
-    parser::{Node, TypeListNode, TypeNode, VisitedTypes},
+    checker::{FunctionType, Scope, TypeParameterMap, Types},
+    parser::{NameNode, Node, TypeListNode, TypeNode, VisitedTypes},
 };
 
 pub struct FunctionTypeNode {
@@ -31,6 +31,16 @@ impl FunctionTypeNode {
             .clone()
     }
 
+    pub fn check(&self, scope: &Scope) {
+        self.parameters.check(scope);
+        self.return_type.check(scope);
+    }
+
+    pub fn get_inferred_names<'a>(&'a self, names: &mut Vec<&'a NameNode>) {
+        self.parameters.get_inferred_names(names);
+        self.return_type.get_inferred_names(names);
+    }
+
     fn init_type(
         &self,
         types: &impl Types,
@@ -1,13 +1,15 @@
-use std::{cell::OnceCell, collections::HashSet, rc::Rc};
+use std::{collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{EnumType, FunctionType, InterfaceType, Scope, StructType, Type, Types},
-    parser::{FunctionNode, ImplementationEntryNode, InterfaceImplementationNode, Node},
+    checker::{
+        EnumType, FunctionType, InterfaceType, Scope, StructType, Type, TypeParameterBindings,
+        TypeParameterMap,
+    },
+    parser::{FunctionNode, ImplementationEntryNode, InterfaceImplementationNode, Node, TokenSpan},
 };
 
 pub struct ImplementationNode {
     pub entries: Vec<Node<ImplementationEntryNode>>,
-    implemented_interfaces: OnceCell<HashSet<usize>>,
 }
 
 pub enum ImplementationType {
@@ -26,16 +28,16 @@ impl ImplementationType {
 
 pub struct Method {
     pub public: bool,
+    pub is_static: bool,
     pub name: String,
+    // The span of the method's name, or of the implementation of the interface declaring it
+    pub span: TokenSpan,
     pub function_type: Rc<FunctionType>,
 }
 
 impl ImplementationNode {
     pub fn new(entries: Vec<Node<ImplementationEntryNode>>) -> Self {
-        Self {
-            entries,
-            implemented_interfaces: OnceCell::new(),
-        }
+        Self { entries }
     }
 
     pub fn check(
@@ -70,24 +72,32 @@ impl ImplementationNode {
         scope
     }
 
-    pub fn get_methods(&self, scope: &Scope) -> Vec<Method> {
+    pub fn get_methods(
+        &self,
+        scope: &Scope,
+        type_params: Option<&TypeParameterMap>,
+    ) -> Vec<Method> {
         let mut methods = vec![];
         for entry in &self.entries {
             match &entry.value {
                 ImplementationEntryNode::Method(method) => {
                     methods.push(Method {
                         public: method.public,
+                        is_static: method.is_static,
                         name: method.function.name().clone(),
-                        function_type: method.function.get_type(scope).clone(),
+                        span: method.function.signature.name.span,
+                        function_type: method.function.get_type(scope, type_params),
                     });
                 }
                 ImplementationEntryNode::Interface(implementation) => {
-                    let interface_type = scope.get_type(&implementation.name);
-                    if let Some(Type::Interface(interface_type)) = interface_type {
+                    let interface_type = implementation.get_type(scope, type_params);
+                    if let Type::Interface(interface_type) = interface_type {
                         for (name, function_type) in &interface_type.methods {
                             methods.push(Method {
                                 public: true,
+                                is_static: false,
                                 name: name.clone(),
+                                span: entry.span,
                                 function_type: function_type.clone(),
                             });
                         }
@@ -99,29 +109,35 @@ impl ImplementationNode {
         methods
     }
 
-    pub fn implements(&self, scope: &Scope, interface_type: &Rc<InterfaceType>) -> bool {
-        let interface_type_id = scope.global().get_type_id(&interface_type.name);
-        match interface_type_id {
-            Some(type_id) => self
-                .implemented_interfaces
-                .get_or_init(|| self.init_implemented_interfaces(scope))
-                .contains(&type_id),
-            None => false,
-        }
+    pub fn implements(
+        &self,
+        scope: &Scope,
+        interface_type: &Rc<InterfaceType>,
+        type_params: Option<&TypeParameterMap>,
+        bindings: &TypeParameterBindings,
+    ) -> bool {
+        let expected_type = Type::Interface(interface_type.clone());
+        self.get_interfaces(scope, type_params, bindings)
+            .into_iter()
+            .any(|implemented| Type::Interface(implemented).is_equivalent_to(&expected_type, scope))
     }
 
-    fn init_implemented_interfaces(&self, scope: &Scope) -> HashSet<usize> {
-        let mut result = HashSet::new();
+    pub fn get_interfaces(
+        &self,
+        scope: &Scope,
+        type_params: Option<&TypeParameterMap>,
+        bindings: &TypeParameterBindings,
+    ) -> Vec<Rc<InterfaceType>> {
+        let mut interfaces = vec![];
         for entry in &self.entries {
-            if let ImplementationEntryNode::Interface(node) = &entry.value {
-                let type_id = scope.get_type_id(&node.name);
-                if let Some(type_id) = type_id {
-                    result.insert(type_id);
-                }
+            if let ImplementationEntryNode::Interface(node) = &entry.value
+                && let Type::Interface(interface_type) = node.get_type(scope.global(), type_params)
+            {
+                interfaces.push(interface_type.bind(scope, bindings));
             }
         }
 
-        result
+        interfaces
     }
 }
 
@@ -132,13 +148,12 @@ fn check_duplicate_interface(
     scope_names: &mut HashSet<String>,
     implemented_interfaces: &mut HashSet<String>,
 ) {
-    // TODO Should this use type ids instead?
-    let implemented_type = scope.get_type(&interface_implementation.name);
-    if let Some(Type::Interface(interface_type)) = implemented_type
+    let implemented_type = interface_implementation.get_type(scope, None);
+    if let Type::Interface(interface_type) = implemented_type
         && !implemented_interfaces.insert(interface_type.name.clone())
     {
-        scope.source.print_error(
-            interface_implementation.name.span,
+        scope.diagnostics.error(
+            interface_implementation.interface.span,
             &format!("Duplicate implementation of `{}`", interface_type.name),
             &format!(
                 "{} `{}` already implements this interface",
@@ -164,7 +179,7 @@ fn check_duplicate_method(
     if scope_names.contains(method.name()) {
         print_duplicate_member_error(scope, self_type, method);
     } else {
-        let method_type = Type::Function(method.get_type(scope).clone());
+        let method_type = Type::Function(method.get_type(scope, None));
         scope.add_value(method.name(), method_type);
         scope_names.insert(method.name().clone());
     }
@@ -176,7 +191,7 @@ fn print_duplicate_member_error(
     method: &FunctionNode,
 ) {
     let container_type = get_container_type(self_type);
-    scope.source.print_error(
+    scope.diagnostics.error(
         method.signature.name.span,
         &format!("Duplicate {} member `{}`", container_type, method.name()),
         &format!(
@@ -1,49 +1,42 @@
 use std::{collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{EnumType, FunctionType, Scope, Type, Types},
+    checker::{EnumType, FunctionType, Scope, Type, TypeParameterMap, Types},
     lexer::Symbol,
-    parser::{FunctionNode, ImplementationType, NameNode, Node},
+    parser::{FunctionNode, ImplementationType, Node, UserDefinedTypeNode},
 };
 
 pub struct InterfaceImplementationNode {
-    pub name: NameNode,
+    pub interface: Node<UserDefinedTypeNode>,
     pub methods: Option<Vec<Node<FunctionNode>>>,
 }
 
 impl InterfaceImplementationNode {
     pub fn check(&self, mut scope: Box<Scope>, self_type: &ImplementationType) -> Box<Scope> {
-        let implemented_type = scope.get_type(&self.name);
+        let implemented_type = self.get_type(&*scope, None);
+        self.interface.check(&scope);
 
-        if let Some(implemented_type) = implemented_type.as_ref() {
-            if !matches!(implemented_type, Type::Interface(_)) {
-                scope.source.print_error(
-                    self.name.span,
-                    "Can only implement interfaces",
-                    &format!("found non interface type: `{implemented_type}`"),
-                );
-            }
-        } else {
-            scope.source.print_error(
-                self.name.span,
-                &format!("Unknown type `{}`", self.name),
-                "could not find this type",
+        if !matches!(implemented_type, Type::Interface(_) | Type::Error) {
+            scope.diagnostics.error(
+                self.interface.span,
+                "Can only implement interfaces",
+                &format!("found non interface type: `{implemented_type}`"),
             );
         }
 
         let mut method_names = HashSet::new();
         if let Some(methods) = self.methods.as_ref() {
             for method in methods {
-                scope = check_method(scope, method, implemented_type.as_ref());
+                scope = check_method(scope, method, &implemented_type);
                 method_names.insert(method.name());
             }
 
-            if let Some(Type::Interface(interface_type)) = implemented_type.as_ref() {
+            if let Type::Interface(interface_type) = &implemented_type {
                 for method in interface_type.methods.keys() {
                     if !method_names.contains(method) {
-                        scope.source.print_error(
-                            self.name.span,
-                            &format!("Implementation of `{}` is incomplete", interface_type.name),
+                        scope.diagnostics.error(
+                            self.interface.span,
+                            &format!("Implementation of `{implemented_type}` is incomplete"),
                             &format!("does not implement method `{method}`"),
                         );
                     }
@@ -53,16 +46,14 @@ impl InterfaceImplementationNode {
 
         if self.methods.is_none() {
             match self_type {
-                ImplementationType::Struct(_) => scope.source.print_error(
-                    self.name.span.after(),
+                ImplementationType::Struct(_) => scope.diagnostics.error(
+                    self.interface.span.after(),
                     "Cannot infer interface implementation for structs",
                     &format!("expected `{}`", Symbol::OpenBrace),
                 ),
-                ImplementationType::Enum(enum_type) => self.check_enum_default_implementation(
-                    &scope,
-                    enum_type,
-                    implemented_type.as_ref(),
-                ),
+                ImplementationType::Enum(enum_type) => {
+                    self.check_enum_default_implementation(&scope, enum_type, &implemented_type)
+                }
             }
         }
 
@@ -73,10 +64,10 @@ impl InterfaceImplementationNode {
         &self,
         scope: &Scope,
         enum_type: &Rc<EnumType>,
-        implemented_type: Option<&Type>,
+        implemented_type: &Type,
     ) {
-        if let Some(Type::Interface(interface_type)) = implemented_type {
-            for (variant_name, variant_type) in &enum_type.variants {
+        if let Type::Interface(interface_type) = implemented_type {
+            for (variant_name, variant_type) in enum_type.get_variant_types(scope) {
                 if let Some(variant_type) = variant_type {
                     let implements_interface = match variant_type.clone() {
                         Type::Enum(e) => e.implements(scope, interface_type),
@@ -84,18 +75,17 @@ impl InterfaceImplementationNode {
                         _ => false,
                     };
                     if !implements_interface {
-                        scope.source.print_error(
-                            self.name.span,
+                        scope.diagnostics.error(
+                            self.interface.span,
                             "Cannot infer interface implementation",
                             &format!(
-                                "variant `{}` does not implement `{}`",
-                                variant_name, interface_type.name
+                                "variant `{variant_name}` does not implement `{implemented_type}`"
                             ),
                         );
                     }
                 } else {
-                    scope.source.print_error(
-                        self.name.span,
+                    scope.diagnostics.error(
+                        self.interface.span,
                         "Cannot infer interface implementation",
                         &format!("variant `{variant_name}` is untyped"),
                     );
@@ -103,14 +93,18 @@ impl InterfaceImplementationNode {
             }
         }
     }
+
+    pub fn get_type(&self, types: &impl Types, type_params: Option<&TypeParameterMap>) -> Type {
+        self.interface.get_type(types, type_params, None)
+    }
 }
 
 fn check_method(
     scope: Box<Scope>,
     method: &Node<FunctionNode>,
-    implemented_type: Option<&Type>,
+    implemented_type: &Type,
 ) -> Box<Scope> {
-    if let Some(Type::Interface(interface_type)) = implemented_type {
+    if let Type::Interface(interface_type) = implemented_type {
         let interface_method = interface_type.methods.get(method.name());
         if let Some(interface_method) = interface_method {
             check_method_equivalence(&scope, interface_method, method);
@@ -125,9 +119,9 @@ fn check_method_equivalence(
     interface_type: &FunctionType,
     implemented_method: &Node<FunctionNode>,
 ) {
-    let implemented_type = implemented_method.get_type(scope);
+    let implemented_type = implemented_method.get_type(scope, None);
     if interface_type.parameters.len() != implemented_type.parameters.len() {
-        scope.source.print_error(
+        scope.diagnostics.error(
             implemented_method.signature.parameters.span,
             &format!(
                 "Implementation of `{}` contains {} parameters",
@@ -163,7 +157,7 @@ fn check_method_equivalence(
                 .type_def
                 .as_ref()
                 .map_or(parameter_node.span, |t| t.span);
-            scope.source.print_error(
+            scope.diagnostics.error(
                 error_span,
                 &format!(
                     "Parameter {} of `{}` does not match expected type from interface",
@@ -186,7 +180,7 @@ fn check_method_equivalence(
             .return_type
             .as_ref()
             .map_or_else(|| implemented_method.body.span.start(), |node| node.span);
-        scope.source.print_error(
+        scope.diagnostics.error(
             error_span,
             &format!(
                 "Return type of `{}` does not match expected type from interface",
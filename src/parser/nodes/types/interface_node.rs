@@ -5,40 +5,70 @@ use std::{
 };
 
 use crate::{
-    checker::{InterfaceType, Scope, Types},
-    parser::{FunctionSignatureNode, NameNode, NodeVec},
+    checker::{InterfaceType, Scope, ScopeType, TypeParameterList, TypeParameterMap, Types},
+    parser::{FunctionSignatureNode, NameNode, Node, NodeVec, TypeParameterListNode},
 };
 
 pub struct InterfaceNode {
     pub name: NameNode,
+    pub type_parameters: Option<Node<TypeParameterListNode>>,
     method_signatures: NodeVec<FunctionSignatureNode>,
     resolved_type: OnceCell<Rc<InterfaceType>>,
 }
 
 impl InterfaceNode {
-    pub fn new(name: NameNode, method_signatures: NodeVec<FunctionSignatureNode>) -> Self {
+    pub fn new(
+        name: NameNode,
+        type_parameters: Option<Node<TypeParameterListNode>>,
+        method_signatures: NodeVec<FunctionSignatureNode>,
+    ) -> Self {
         Self {
             name,
+            type_parameters,
             method_signatures,
             resolved_type: OnceCell::new(),
         }
     }
 
     pub fn check(&self, scope: Box<Scope>) -> Box<Scope> {
+        scope.nest(ScopeType::Type, |mut scope| {
+            if let Some(type_parameters) = self.type_parameters.as_ref() {
+                scope = type_parameters.check(scope, type_parameters.span);
+            }
+            self.check_nested(scope)
+        })
+    }
+
+    fn check_nested(&self, scope: Box<Scope>) -> Box<Scope> {
         let mut method_names = HashSet::new();
         for method in self.method_signatures.iter() {
             if !method_names.insert(&method.name.value) {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     method.name.span,
                     &format!("Duplicate method signature `{}`", method.name),
                     &format!("a method of `{}` already exists with this name", self.name),
                 );
             }
+            method.check_types(&scope);
         }
 
         scope
     }
 
+    pub fn get_type_parameters(&self) -> TypeParameterList {
+        self.type_parameters
+            .as_ref()
+            .map_or_else(TypeParameterList::default, |type_parameters| {
+                type_parameters.get_types_list().clone()
+            })
+    }
+
+    pub fn get_type_parameters_map(&self) -> Option<&TypeParameterMap> {
+        self.type_parameters
+            .as_ref()
+            .map(|type_parameters| type_parameters.get_types_map())
+    }
+
     pub fn get_type(&self, types: &impl Types) -> Rc<InterfaceType> {
         self.resolved_type
             .get_or_init(|| self.init_type(types))
@@ -49,13 +79,10 @@ impl InterfaceNode {
         let mut methods = HashMap::new();
         for method_signature in self.method_signatures.iter() {
             let name = method_signature.name.clone();
-            let method = method_signature.get_type(types).clone();
+            let method = method_signature.get_type(types, self.get_type_parameters_map());
             methods.entry(name).or_insert(method);
         }
 
-        Rc::new(InterfaceType {
-            name: self.name.clone(),
-            methods,
-        })
+        InterfaceType::new(self.name.clone(), self.get_type_parameters(), methods)
     }
 }
@@ -8,6 +8,17 @@ pub enum PrimitiveType {
     Char,
     Float,
     Int,
+    F32,
+    F64,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
 }
 
 impl PrimitiveType {
@@ -17,9 +28,64 @@ impl PrimitiveType {
             Keyword::Char => Some(Self::Char),
             Keyword::Float => Some(Self::Float),
             Keyword::Int => Some(Self::Int),
+            Keyword::F32 => Some(Self::F32),
+            Keyword::F64 => Some(Self::F64),
+            Keyword::I8 => Some(Self::I8),
+            Keyword::I16 => Some(Self::I16),
+            Keyword::I32 => Some(Self::I32),
+            Keyword::I64 => Some(Self::I64),
+            Keyword::U8 => Some(Self::U8),
+            Keyword::U16 => Some(Self::U16),
+            Keyword::U32 => Some(Self::U32),
+            Keyword::U64 => Some(Self::U64),
+            Keyword::Usize => Some(Self::Usize),
             _ => None,
         }
     }
+
+    pub fn is_integer(&self) -> bool {
+        self.get_bits().is_some() && !self.is_float()
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::Float | Self::F32 | Self::F64)
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.get_bits().is_some()
+    }
+
+    pub fn is_signed(&self) -> bool {
+        !matches!(
+            self,
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::Usize
+        )
+    }
+
+    // The width of a numeric type, where `int`, `float` and `usize` are 64 bits wide
+    pub fn get_bits(&self) -> Option<u32> {
+        match self {
+            Self::Bool | Self::Char => None,
+            Self::I8 | Self::U8 => Some(8),
+            Self::I16 | Self::U16 => Some(16),
+            Self::I32 | Self::U32 | Self::F32 => Some(32),
+            Self::Float | Self::Int | Self::F64 | Self::I64 | Self::U64 | Self::Usize => Some(64),
+        }
+    }
+
+    // The smallest and largest values of an integer type
+    pub fn get_range(&self) -> Option<(i128, i128)> {
+        if !self.is_integer() {
+            return None;
+        }
+
+        let bits = self.get_bits()?;
+        if self.is_signed() {
+            Some((-(1 << (bits - 1)), (1 << (bits - 1)) - 1))
+        } else {
+            Some((0, (1 << bits) - 1))
+        }
+    }
 }
 
 impl Display for PrimitiveType {
@@ -29,6 +95,17 @@ impl Display for PrimitiveType {
             Self::Char => Keyword::Char,
             Self::Float => Keyword::Float,
             Self::Int => Keyword::Int,
+            Self::F32 => Keyword::F32,
+            Self::F64 => Keyword::F64,
+            Self::I8 => Keyword::I8,
+            Self::I16 => Keyword::I16,
+            Self::I32 => Keyword::I32,
+            Self::I64 => Keyword::I64,
+            Self::U8 => Keyword::U8,
+            Self::U16 => Keyword::U16,
+            Self::U32 => Keyword::U32,
+            Self::U64 => Keyword::U64,
+            Self::Usize => Keyword::Usize,
         };
         write!(f, "{keyword}")
     }
@@ -1,41 +1,46 @@
 use std::cell::OnceCell;
 
 use crate::{
-    checker::{Scope, StructMember, StructMemberType, Type, Types},
+    checker::{Type, TypeParameterMap, Types},
     parser::{NameNode, Node, TypeNode},
 };
 
 pub struct StructFieldNode {
+    // Declared as `...name`, promoting the public members of the field's struct
+    pub is_spread: bool,
     pub public: bool,
+    pub mutable: bool,
     pub name: NameNode,
     pub type_def: Option<Node<TypeNode>>,
     resolved_type: OnceCell<Type>,
 }
 
 impl StructFieldNode {
-    pub fn new(public: bool, name: NameNode, type_def: Option<Node<TypeNode>>) -> Self {
+    pub fn new(
+        is_spread: bool,
+        public: bool,
+        mutable: bool,
+        name: NameNode,
+        type_def: Option<Node<TypeNode>>,
+    ) -> Self {
         Self {
+            is_spread,
             public,
+            mutable,
             name,
             type_def,
             resolved_type: OnceCell::new(),
         }
     }
 
-    pub fn get_member(&self, scope: &Scope) -> StructMember {
-        StructMember {
-            public: self.public,
-            member_type: StructMemberType::Field(self.get_type(scope).clone()),
-        }
-    }
-
-    pub fn get_type(&self, types: &impl Types) -> &Type {
-        self.resolved_type.get_or_init(|| self.init_type(types))
+    pub fn get_type(&self, types: &impl Types, type_params: Option<&TypeParameterMap>) -> &Type {
+        self.resolved_type
+            .get_or_init(|| self.init_type(types, type_params))
     }
 
-    fn init_type(&self, types: &impl Types) -> Type {
+    fn init_type(&self, types: &impl Types, type_params: Option<&TypeParameterMap>) -> Type {
         match self.type_def.as_ref() {
-            Some(type_def) => type_def.get_type(types, None, None),
+            Some(type_def) => type_def.get_type(types, type_params, None),
             None => Type::Error,
         }
     }
@@ -1,12 +1,17 @@
 use std::{cell::OnceCell, collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{Scope, ScopeType, StructType, Types},
-    parser::{ImplementationNode, ImplementationType, NameNode, Node, NodeVec, StructFieldNode},
+    checker::{Scope, ScopeType, StructType, Type, TypeParameterList, TypeParameterMap, Types},
+    diagnostics::Diagnostic,
+    parser::{
+        ImplementationNode, ImplementationType, NameNode, Node, NodeVec, StructFieldNode,
+        TypeParameterListNode,
+    },
 };
 
 pub struct StructNode {
     pub name: NameNode,
+    pub type_parameters: Option<Node<TypeParameterListNode>>,
     pub fields: NodeVec<StructFieldNode>,
     pub implementation: Option<Node<ImplementationNode>>,
     resolved_type: OnceCell<Rc<StructType>>,
@@ -15,11 +20,13 @@ pub struct StructNode {
 impl StructNode {
     pub fn new(
         name: NameNode,
+        type_parameters: Option<Node<TypeParameterListNode>>,
         fields: NodeVec<StructFieldNode>,
         implementation: Option<Node<ImplementationNode>>,
     ) -> Self {
         Self {
             name,
+            type_parameters,
             fields,
             implementation,
             resolved_type: OnceCell::new(),
@@ -28,7 +35,10 @@ impl StructNode {
 
     pub fn check(self: &Rc<Self>, scope: Box<Scope>) -> Box<Scope> {
         let self_type = self.get_type(&*scope);
-        scope.nest(ScopeType::Struct(self_type), |scope| {
+        scope.nest(ScopeType::Struct(self_type), |mut scope| {
+            if let Some(type_parameters) = self.type_parameters.as_ref() {
+                scope = type_parameters.check(scope, type_parameters.span);
+            }
             self.check_nested(scope)
         })
     }
@@ -37,10 +47,22 @@ impl StructNode {
         let mut scope_names = HashSet::new();
         for field in self.fields.iter() {
             if scope_names.insert(field.name.clone()) {
-                let field_type = field.get_type(&*scope).clone();
-                scope.add_value(&field.name, field_type);
+                let field_type = field
+                    .get_type(&*scope, self.get_type_parameters_map())
+                    .clone();
+                if field.is_spread && !matches!(field_type, Type::Struct(_) | Type::Error) {
+                    scope.diagnostics.error(
+                        field.name.span,
+                        "Spread field should be a struct",
+                        &format!("found type `{field_type}`"),
+                    );
+                }
+                scope.declare_value(&field.name, field_type, field.mutable, field.name.span);
+                if let Some(type_def) = field.type_def.as_ref() {
+                    type_def.check(&scope);
+                }
             } else {
-                scope.source.print_error(
+                scope.diagnostics.error(
                     field.name.span,
                     &format!("Duplicate struct member `{}`", field.name),
                     &format!(
@@ -51,6 +73,37 @@ impl StructNode {
             }
         }
 
+        let self_type = self.get_type(&*scope);
+        for conflict in self_type.get_promotion_conflicts(&scope) {
+            let diagnostic = Diagnostic::error(
+                scope.diagnostics.span(conflict.second.span),
+                &format!("Conflicting promoted member `{}`", conflict.name),
+                &format!(
+                    "`{}` is promoted through both `{}` and `{}`",
+                    conflict.name, conflict.first.name, conflict.second.name
+                ),
+            )
+            .with_code("conflicting-promotion")
+            .with_label(
+                scope.diagnostics.span(conflict.first.span),
+                &format!("`{}` is first promoted through here", conflict.name),
+            )
+            .with_help(&format!(
+                "declare `{}` in `{}` to choose which member it refers to",
+                conflict.name, self.name
+            ));
+            scope.diagnostics.report(diagnostic);
+        }
+
+        // Promoted members are in scope within methods, unless shadowed by a field or method
+        let promoted_members = self_type.get_promoted_members(&scope);
+        for (name, member) in promoted_members {
+            if !scope_names.contains(&name) {
+                let member_type = member.member_type.get_type();
+                scope.declare_value(&name, member_type, member.mutable, member.span);
+            }
+        }
+
         if let Some(implementation) = self.implementation.as_ref() {
             let self_type = ImplementationType::Struct(self.get_type(&*scope));
             return implementation.check(scope, &self_type, scope_names);
@@ -59,9 +112,32 @@ impl StructNode {
         scope
     }
 
+    pub fn get_type_parameters(&self) -> TypeParameterList {
+        self.type_parameters
+            .as_ref()
+            .map_or_else(TypeParameterList::default, |type_parameters| {
+                type_parameters.get_types_list().clone()
+            })
+    }
+
+    pub fn get_type_parameters_map(&self) -> Option<&TypeParameterMap> {
+        self.type_parameters
+            .as_ref()
+            .map(|type_parameters| type_parameters.get_types_map())
+    }
+
     pub fn get_type(self: &Rc<Self>, types: &impl Types) -> Rc<StructType> {
-        self.resolved_type
-            .get_or_init(|| StructType::from(self.clone(), types))
-            .clone()
+        if let Some(struct_type) = self.resolved_type.get() {
+            return struct_type.clone();
+        }
+
+        let struct_type = self
+            .resolved_type
+            .get_or_init(|| StructType::from(self.clone()))
+            .clone();
+        // Initialize the constructor using the module level types, once the type itself is
+        // resolved so that fields can refer back to the struct
+        struct_type.get_constructor(types);
+        struct_type
     }
 }
@@ -1,8 +1,8 @@
-use std::{cell::OnceCell, rc::Rc};
+use std::cell::OnceCell;
 
 use crate::{
-    checker::{Type, TypeParameterMap, Types},
-    parser::{TypeListNode, VisitedTypes},
+    checker::{Scope, TupleType, Type, TypeParameterMap, Types},
+    parser::{NameNode, TypeListNode, VisitedTypes},
 };
 
 pub struct TupleTypeNode {
@@ -29,13 +29,21 @@ impl TupleTypeNode {
             .clone()
     }
 
+    pub fn check(&self, scope: &Scope) {
+        self.fields.check(scope);
+    }
+
+    pub fn get_inferred_names<'a>(&'a self, names: &mut Vec<&'a NameNode>) {
+        self.fields.get_inferred_names(names);
+    }
+
     fn init_type(
         &self,
         types: &impl Types,
         type_params: Option<&TypeParameterMap>,
         visited: VisitedTypes,
     ) -> Type {
-        let fields = self.fields.get_type(types, type_params, visited);
-        Type::Tuple(Rc::new(fields))
+        let elements = self.fields.get_tuple_elements(types, type_params, visited);
+        Type::Tuple(TupleType::from_elements(types.get_diagnostics(), elements))
     }
 }
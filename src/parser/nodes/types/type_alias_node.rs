@@ -11,7 +11,7 @@ use crate::{
 
 pub struct TypeAliasNode {
     pub name: NameNode,
-    type_parameters: Option<Node<TypeParameterListNode>>,
+    pub type_parameters: Option<Node<TypeParameterListNode>>,
     type_def: Node<TypeNode>,
     resolved_type: OnceCell<Type>,
 }
@@ -36,6 +36,7 @@ impl TypeAliasNode {
                 scope = type_parameters.check(scope, type_parameters.span);
             }
             let resolved_type = self.get_type(&*scope).clone();
+            self.type_def.check(&scope);
             (scope, resolved_type)
         })
     }
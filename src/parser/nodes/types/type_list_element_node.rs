@@ -1,9 +1,11 @@
 use crate::{
-    checker::{Type, TypeParameterMap, Types},
-    parser::{Node, TypeNode, VisitedTypes},
+    checker::{TupleElement, Type, TypeParameterMap, Types},
+    parser::{NameNode, Node, TypeNode, VisitedTypes},
 };
 
 pub struct TypeListElementNode {
+    // Elements of tuple types can be named, as in `(left: int, right: int)`
+    pub name: Option<NameNode>,
     pub is_spread: bool,
     pub inner_type: Node<TypeNode>,
 }
@@ -15,21 +17,64 @@ impl TypeListElementNode {
         type_params: Option<&TypeParameterMap>,
         visited: VisitedTypes,
     ) -> Vec<Type> {
+        self.get_tuple_elements(types, type_params, visited)
+            .into_iter()
+            .map(|element| element.element_type)
+            .collect()
+    }
+
+    // Spreading a named tuple type keeps the names of its elements
+    pub fn get_tuple_elements(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+        visited: VisitedTypes,
+    ) -> Vec<TupleElement> {
         let resolved_type = self.inner_type.get_type(types, type_params, visited);
         if !self.is_spread {
-            return vec![resolved_type];
+            return vec![TupleElement {
+                name: self.name.as_ref().map(|name| name.value.clone()),
+                element_type: resolved_type,
+                span: self
+                    .name
+                    .as_ref()
+                    .map_or(self.inner_type.span, |name| name.span),
+            }];
+        }
+
+        if let Type::Tuple(tuple_type) = resolved_type {
+            let names = tuple_type.get_names();
+            return names
+                .into_iter()
+                .zip(tuple_type.elements.iter())
+                .map(|(name, element_type)| TupleElement {
+                    name,
+                    element_type: element_type.clone(),
+                    span: self.inner_type.span,
+                })
+                .collect();
         }
 
-        if let Type::Tuple(types) = resolved_type {
-            return types.to_vec();
+        if let Type::TypeParameter(type_parameter) = &resolved_type
+            && type_parameter.is_variadic
+        {
+            return vec![TupleElement {
+                name: None,
+                element_type: Type::Spread(type_parameter.clone()),
+                span: self.inner_type.span,
+            }];
         }
 
-        types.print_error(
+        types.get_diagnostics().error(
             self.inner_type.span,
             "Spread type should be a tuple",
             &format!("found type `{resolved_type}`"),
         );
 
-        vec![resolved_type]
+        vec![TupleElement {
+            name: None,
+            element_type: resolved_type,
+            span: self.inner_type.span,
+        }]
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    checker::{Type, TypeParameterMap, Types},
-    parser::{Node, TypeListElementNode, VisitedTypes},
+    checker::{Scope, TupleElement, Type, TypeParameterMap, Types},
+    parser::{NameNode, Node, TypeListElementNode, VisitedTypes},
 };
 
 pub struct TypeListNode {
@@ -19,4 +19,28 @@ impl TypeListNode {
             .flat_map(|element| element.get_types(types, type_params, visited.clone()))
             .collect()
     }
+
+    pub fn get_tuple_elements(
+        &self,
+        types: &impl Types,
+        type_params: Option<&TypeParameterMap>,
+        visited: VisitedTypes,
+    ) -> Vec<TupleElement> {
+        self.elements
+            .iter()
+            .flat_map(|element| element.get_tuple_elements(types, type_params, visited.clone()))
+            .collect()
+    }
+
+    pub fn check(&self, scope: &Scope) {
+        for element in &self.elements {
+            element.inner_type.check(scope);
+        }
+    }
+
+    pub fn get_inferred_names<'a>(&'a self, names: &mut Vec<&'a NameNode>) {
+        for element in &self.elements {
+            element.inner_type.get_inferred_names(names);
+        }
+    }
 }
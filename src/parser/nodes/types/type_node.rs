@@ -1,13 +1,18 @@
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
 use crate::{
-    checker::{Type, TypeParameterMap, Types},
-    parser::{FunctionTypeNode, PrimitiveType, TokenSpan, TupleTypeNode, UserDefinedTypeNode},
+    checker::{Scope, Type, TypeParameterMap, Types, make_optional},
+    parser::{
+        FunctionTypeNode, NameNode, PrimitiveType, TokenSpan, TupleTypeNode, UserDefinedTypeNode,
+    },
 };
 
 pub enum TypeNode {
     Array(Box<TypeNode>),
     Function(FunctionTypeNode),
+    // `infer Name` in a bound, implicitly declaring a type parameter solved from the bound
+    Infer(NameNode),
+    Optional(Box<TypeNode>),
     Primitive(PrimitiveType),
     ResultType(TokenSpan),
     SelfType(TokenSpan),
@@ -28,7 +33,11 @@ impl TypeNode {
         match self {
             Self::Array(node) => Type::Array(Box::new(node.get_type(types, type_params, visited))),
             Self::Primitive(primitive) => Type::Primitive(*primitive),
+            Self::Optional(node) => {
+                make_optional(types, node.get_type(types, type_params, visited))
+            }
             Self::Function(node) => Type::Function(node.get_type(types, type_params, visited)),
+            Self::Infer(name) => get_inferred_type(types, type_params, name),
             Self::ResultType(span) => get_result_type(types, *span),
             Self::SelfType(span) => get_self_type(types, *span),
             Self::Tuple(node) => node.get_type(types, type_params, visited),
@@ -36,6 +45,53 @@ impl TypeNode {
             Self::Void => Type::Void,
         }
     }
+
+    // Verifies that the type arguments used within this type satisfy their bounds
+    pub fn check(&self, scope: &Scope) {
+        match self {
+            Self::Array(node) => node.check(scope),
+            Self::Function(node) => node.check(scope),
+            Self::Optional(node) => node.check(scope),
+            Self::Tuple(node) => node.check(scope),
+            Self::UserDefined(node) => node.check(scope),
+            Self::Infer(_)
+            | Self::Primitive(_)
+            | Self::ResultType(_)
+            | Self::SelfType(_)
+            | Self::Void => {}
+        }
+    }
+
+    // The names declared with `infer` within this type
+    pub fn get_inferred_names<'a>(&'a self, names: &mut Vec<&'a NameNode>) {
+        match self {
+            Self::Array(node) | Self::Optional(node) => node.get_inferred_names(names),
+            Self::Function(node) => node.get_inferred_names(names),
+            Self::Infer(name) => names.push(name),
+            Self::Tuple(node) => node.get_inferred_names(names),
+            Self::UserDefined(node) => node.get_inferred_names(names),
+            Self::Primitive(_) | Self::ResultType(_) | Self::SelfType(_) | Self::Void => {}
+        }
+    }
+}
+
+// Inferred names are declared by the type parameter list whose bounds contain them
+fn get_inferred_type(
+    types: &impl Types,
+    type_params: Option<&TypeParameterMap>,
+    name: &NameNode,
+) -> Type {
+    match type_params.and_then(|type_params| type_params.get(&name.value)) {
+        Some(type_parameter) => Type::TypeParameter(type_parameter.clone()),
+        None => {
+            types.get_diagnostics().error(
+                name.span,
+                &format!("Unexpected inferred type `{name}`"),
+                "`infer` can only be used in the bounds of a function's type parameters",
+            );
+            Type::Error
+        }
+    }
 }
 
 fn get_result_type(types: &impl Types, span: TokenSpan) -> Type {
@@ -43,7 +99,7 @@ fn get_result_type(types: &impl Types, span: TokenSpan) -> Type {
     if let Some(result_type) = result_type {
         result_type
     } else {
-        types.print_error(
+        types.get_diagnostics().error(
             span,
             "`Result` type not available outside of function bodies",
             "cannot use type `Result` here",
@@ -57,7 +113,7 @@ fn get_self_type(types: &impl Types, span: TokenSpan) -> Type {
     if let Some(self_type) = self_type {
         self_type
     } else {
-        types.print_error(
+        types.get_diagnostics().error(
             span,
             "`Self` type not available outside of struct or enum",
             "cannot use type `Self` here",
@@ -1,21 +1,24 @@
 use std::{
     cell::OnceCell,
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, hash_map::Entry},
     rc::Rc,
 };
 
 use crate::{
-    checker::{Scope, Type, TypeParameter, TypeParameterList, TypeParameterMap},
-    parser::{Node, TokenSpan, TypeParameterNode},
+    checker::{Scope, Type, TypeParameter, TypeParameterList, TypeParameterMap, Types},
+    parser::{NameNode, Node, TokenSpan, TypeParameterNode},
 };
 
 pub struct TypeParameterListNode {
     pub list: Vec<Node<TypeParameterNode>>,
     types: OnceCell<TypeParameterListNodeData>,
+    resolved_bounds: OnceCell<()>,
 }
 
 struct TypeParameterListNodeData {
     types_list: TypeParameterList,
+    // The declared parameters followed by those named with `infer` in their bounds
+    function_types_list: TypeParameterList,
     types_map: TypeParameterMap,
 }
 
@@ -24,39 +27,121 @@ impl TypeParameterListNode {
         Self {
             list,
             types: OnceCell::new(),
+            resolved_bounds: OnceCell::new(),
         }
     }
 
-    pub fn check(&self, mut scope: Box<Scope>, span: TokenSpan) -> Box<Scope> {
+    pub fn check(&self, scope: Box<Scope>, span: TokenSpan) -> Box<Scope> {
+        for name in self.get_inferred_names() {
+            scope.diagnostics.error(
+                name.span,
+                &format!("Unexpected inferred type `{name}`"),
+                "`infer` can only be used in the bounds of a function's type parameters",
+            );
+        }
+
+        let names = self.list.iter().map(|type_param| &type_param.name);
+        self.check_parameters(scope, span, names.zip(self.get_types_list().iter()))
+    }
+
+    // Functions also declare the parameters named with `infer`, which are solved from the
+    // implementation of the bound containing them. Bounds inferring the same name share it.
+    pub fn check_function(&self, scope: Box<Scope>, span: TokenSpan) -> Box<Scope> {
+        let names = self.list.iter().map(|type_param| &type_param.name);
+        let mut scope = self.check_parameters(scope, span, names.zip(self.get_types_list().iter()));
+
+        let declared = self
+            .list
+            .iter()
+            .map(|type_param| &type_param.name.value)
+            .collect::<HashSet<_>>();
+        let mut inferred = HashSet::new();
+        for name in self.get_inferred_names() {
+            if declared.contains(&name.value) {
+                scope.diagnostics.error(
+                    name.span,
+                    &format!("Duplicate type parameter name `{name}`"),
+                    "a type parameter already exists with this name",
+                );
+            } else if inferred.insert(&name.value) {
+                let type_parameter = self.get_types_map()[&name.value].clone();
+                scope.add_type(name, Type::TypeParameter(type_parameter));
+            }
+        }
+        scope
+    }
+
+    fn check_parameters<'a>(
+        &self,
+        mut scope: Box<Scope>,
+        span: TokenSpan,
+        type_parameters: impl Iterator<Item = (&'a NameNode, &'a Rc<TypeParameter>)>,
+    ) -> Box<Scope> {
         if self.list.is_empty() {
-            scope.source.print_error(
+            scope.diagnostics.error(
                 span,
                 "Type parameter list should not be empty",
                 "must provide at least one type parameter",
             );
         }
 
+        self.resolve_bounds(&*scope);
         let mut names = HashSet::new();
-        for type_param in &self.list {
-            if names.insert(&type_param.name.value) {
-                let type_parameter = Rc::new(TypeParameter::new(type_param.name.clone()));
-                scope.add_type(&type_param.name, Type::TypeParameter(type_parameter));
+        for (name, type_parameter) in type_parameters {
+            if names.insert(&name.value) {
+                scope.add_type(name, Type::TypeParameter(type_parameter.clone()));
             } else {
-                scope.source.print_error(
-                    type_param.name.span,
-                    &format!("Duplicate type parameter name `{}`", type_param.name),
-                    "type alias already contains a type parameter with this name",
+                scope.diagnostics.error(
+                    name.span,
+                    &format!("Duplicate type parameter name `{name}`"),
+                    "a type parameter already exists with this name",
                 );
             }
         }
 
+        for bound in self
+            .list
+            .iter()
+            .flat_map(|type_param| type_param.bounds.iter())
+        {
+            bound.check(&scope);
+        }
+
         scope
     }
 
+    // Bounds may refer to any parameter in this list, so they are resolved once all exist
+    pub fn resolve_bounds(&self, types: &impl Types) {
+        self.resolved_bounds.get_or_init(|| self.init_bounds(types));
+    }
+
+    fn init_bounds(&self, types: &impl Types) {
+        let type_parameters = self.list.iter().zip(self.get_types_list().iter());
+        for (type_param, type_parameter) in type_parameters {
+            let mut bounds = vec![];
+            for bound in type_param.bounds.iter() {
+                match bound.get_type(types, Some(self.get_types_map()), None) {
+                    Type::Interface(interface_type) => bounds.push(interface_type),
+                    Type::Error => {}
+                    bound_type => types.get_diagnostics().error(
+                        bound.span,
+                        "Type parameter bounds must be interfaces",
+                        &format!("found non interface type: `{bound_type}`"),
+                    ),
+                }
+            }
+            type_parameter.set_bounds(bounds);
+        }
+    }
+
     pub fn get_types_list(&self) -> &TypeParameterList {
         &self.get_data().types_list
     }
 
+    pub fn get_function_types_list(&self) -> &TypeParameterList {
+        &self.get_data().function_types_list
+    }
+
     pub fn get_types_map(&self) -> &TypeParameterMap {
         &self.get_data().types_map
     }
@@ -69,16 +154,41 @@ impl TypeParameterListNode {
         let mut types_map = HashMap::new();
         let mut types_list = vec![];
         for node in &self.list {
-            let type_param = Rc::new(TypeParameter::new(node.name.clone()));
+            let type_param = Rc::new(TypeParameter::with_variadic(
+                node.name.value.clone(),
+                node.is_variadic,
+            ));
             types_map
                 .entry(node.name.value.clone())
                 .or_insert(type_param.clone());
             types_list.push(type_param);
         }
 
+        let mut function_types_list = types_list.clone();
+        for name in self.get_inferred_names() {
+            if let Entry::Vacant(entry) = types_map.entry(name.value.clone()) {
+                let type_param = Rc::new(TypeParameter::new(name.value.clone()));
+                entry.insert(type_param.clone());
+                function_types_list.push(type_param);
+            }
+        }
+
         TypeParameterListNodeData {
             types_list: TypeParameterList::new(types_list),
+            function_types_list: TypeParameterList::new(function_types_list),
             types_map,
         }
     }
+
+    fn get_inferred_names(&self) -> Vec<&NameNode> {
+        let mut names = vec![];
+        for bound in self
+            .list
+            .iter()
+            .flat_map(|type_param| type_param.bounds.iter())
+        {
+            bound.get_inferred_names(&mut names);
+        }
+        names
+    }
 }
@@ -1,5 +1,8 @@
-use crate::parser::NameNode;
+use crate::parser::{NameNode, Node, TypeNode};
 
 pub struct TypeParameterNode {
     pub name: NameNode,
+    // Declared as `...Name`, standing for a tuple of types
+    pub is_variadic: bool,
+    pub bounds: Vec<Node<TypeNode>>,
 }
@@ -1,14 +1,21 @@
 use std::cell::OnceCell;
 
 use crate::{
-    checker::{Type, TypeParameterMap, Types},
-    parser::{NameNode, NodeVec, TypeNode, VisitedTypes},
+    checker::{Scope, Type, TypeParameterList, TypeParameterMap, Types, check_bounds},
+    parser::{NameNode, NodeVec, TokenSpan, TypeNode, VisitedTypes},
 };
 
 pub struct UserDefinedTypeNode {
     pub name: NameNode,
     bound_type_parameters: Option<NodeVec<TypeNode>>,
     resolved_type: OnceCell<Type>,
+    type_arguments: OnceCell<TypeArguments>,
+}
+
+// The type arguments given to a generic type, kept to verify their bounds once checking
+struct TypeArguments {
+    base_type: Type,
+    bound_types: Vec<Type>,
 }
 
 impl UserDefinedTypeNode {
@@ -17,6 +24,7 @@ impl UserDefinedTypeNode {
             name,
             bound_type_parameters,
             resolved_type: OnceCell::new(),
+            type_arguments: OnceCell::new(),
         }
     }
 
@@ -31,6 +39,32 @@ impl UserDefinedTypeNode {
             .clone()
     }
 
+    pub fn check(&self, scope: &Scope) {
+        self.get_type(scope, None, None);
+        let Some(bound_type_params) = self.bound_type_parameters.as_ref() else {
+            return;
+        };
+
+        for bound_type_param in bound_type_params.iter() {
+            bound_type_param.check(scope);
+        }
+
+        if let Some(type_arguments) = self.type_arguments.get() {
+            check_type_arguments(
+                scope,
+                &type_arguments.base_type,
+                bound_type_params,
+                &type_arguments.bound_types,
+            );
+        }
+    }
+
+    pub fn get_inferred_names<'a>(&'a self, names: &mut Vec<&'a NameNode>) {
+        for bound_type_param in self.bound_type_parameters.iter().flat_map(|p| p.iter()) {
+            bound_type_param.get_inferred_names(names);
+        }
+    }
+
     fn init_type(
         &self,
         types: &impl Types,
@@ -38,25 +72,44 @@ impl UserDefinedTypeNode {
         visited: VisitedTypes,
     ) -> Type {
         let base_type = self.get_base_type(types, type_params, visited.clone());
-        if let Some(bound_type_params) = self.bound_type_parameters.as_ref() {
-            bind_type(types, &base_type, bound_type_params, type_params, visited)
-        } else {
-            self.unbound_type(types, base_type)
-        }
+        let Some(bound_type_params) = self.bound_type_parameters.as_ref() else {
+            return self.unbound_type(types, base_type);
+        };
+
+        let bound_types = bound_type_params
+            .iter()
+            .map(|p| p.get_type(types, type_params, visited.clone()))
+            .collect::<Vec<_>>();
+        let bound_type = bind_type(types, &base_type, bound_type_params, &bound_types);
+        let _ = self.type_arguments.set(TypeArguments {
+            base_type,
+            bound_types,
+        });
+        bound_type
     }
 
     fn unbound_type(&self, types: &impl Types, base_type: Type) -> Type {
         match &base_type {
             Type::Generic(generic_type) => {
-                types.print_error(
-                    self.name.span,
-                    "Type parameters required",
-                    &format!("type `{base_type}` is generic"),
-                );
+                print_type_parameters_required_error(types, self.name.span, &base_type);
                 let error_bindings = generic_type.type_parameters.get_bindings(&[]);
                 generic_type.base_type.bind(types, &error_bindings)
             }
-            // TODO check for unbound generic interfaces/structs/enums
+            Type::Enum(enum_type) if enum_type.is_unbound() => {
+                print_type_parameters_required_error(types, self.name.span, &base_type);
+                let error_bindings = enum_type.type_parameters.get_bindings(&[]);
+                Type::Enum(enum_type.bind(types, &error_bindings))
+            }
+            Type::Struct(struct_type) if struct_type.is_unbound() => {
+                print_type_parameters_required_error(types, self.name.span, &base_type);
+                let error_bindings = struct_type.type_parameters.get_bindings(&[]);
+                Type::Struct(struct_type.bind(types, &error_bindings))
+            }
+            Type::Interface(interface_type) if interface_type.is_unbound() => {
+                print_type_parameters_required_error(types, self.name.span, &base_type);
+                let error_bindings = interface_type.type_parameters.get_bindings(&[]);
+                Type::Interface(interface_type.bind(types, &error_bindings))
+            }
             _ => base_type,
         }
     }
@@ -76,7 +129,7 @@ impl UserDefinedTypeNode {
         let base_type = types.get_type(&self.name);
         let type_id = types.get_type_id(&self.name);
         let Some(base_type) = base_type else {
-            types.print_error(
+            types.get_diagnostics().error(
                 self.name.span,
                 &format!("Unknown type `{}`", self.name),
                 "could not find a type with this name",
@@ -88,7 +141,7 @@ impl UserDefinedTypeNode {
         if let Some(visited) = visited {
             let mut visited = visited.borrow_mut();
             if !visited.insert(type_id) {
-                types.print_error(
+                types.get_diagnostics().error(
                     self.name.span,
                     &format!("Type alias `{}` used recursively", self.name),
                     "use of this type creates a circular type alias",
@@ -105,20 +158,59 @@ pub fn bind_type(
     types: &impl Types,
     base_type: &Type,
     bound_type_params: &NodeVec<TypeNode>,
-    type_params: Option<&TypeParameterMap>,
-    visited: VisitedTypes,
+    bound_types: &[Type],
 ) -> Type {
-    let bound_types = bound_type_params
-        .iter()
-        .map(|p| p.get_type(types, type_params, visited.clone()))
-        .collect::<Vec<_>>();
-
     match base_type {
-        Type::Generic(generic_type) => generic_type.bind(types, bound_type_params, &bound_types),
-        Type::Enum(_) => todo!("Implement generic binding for enums"),
-        Type::Interface(_) => todo!("Implement generic binding for interfaces"),
-        Type::Struct(_) => todo!("Implement generic binding for structs"),
+        Type::Generic(generic_type) => generic_type.bind(types, bound_type_params, bound_types),
+        Type::Enum(enum_type) => {
+            Type::Enum(enum_type.bind_arguments(types, bound_type_params, bound_types))
+        }
+        Type::Interface(interface_type) => {
+            Type::Interface(interface_type.bind_arguments(types, bound_type_params, bound_types))
+        }
+        Type::Struct(struct_type) => {
+            Type::Struct(struct_type.bind_arguments(types, bound_type_params, bound_types))
+        }
         Type::Error => Type::Error,
         _ => panic!("Type encountered that should not be possible? {base_type}"),
     }
 }
+
+// Verifies that each type argument satisfies the bounds of its type parameter
+pub fn check_type_arguments(
+    scope: &Scope,
+    base_type: &Type,
+    bound_type_params: &NodeVec<TypeNode>,
+    bound_types: &[Type],
+) {
+    let Some(type_parameters) = get_type_parameters(base_type) else {
+        return;
+    };
+
+    let bindings = type_parameters.get_bindings(bound_types);
+    let arguments = type_parameters
+        .iter()
+        .zip(bound_types.iter())
+        .zip(bound_type_params.iter());
+    for ((type_parameter, bound_type), node) in arguments {
+        check_bounds(scope, node.span, type_parameter, bound_type, &bindings);
+    }
+}
+
+fn get_type_parameters(base_type: &Type) -> Option<&TypeParameterList> {
+    match base_type {
+        Type::Enum(enum_type) => Some(&enum_type.type_parameters),
+        Type::Generic(generic_type) => Some(&generic_type.type_parameters),
+        Type::Interface(interface_type) => Some(&interface_type.type_parameters),
+        Type::Struct(struct_type) => Some(&struct_type.type_parameters),
+        _ => None,
+    }
+}
+
+fn print_type_parameters_required_error(types: &impl Types, span: TokenSpan, base_type: &Type) {
+    types.get_diagnostics().error(
+        span,
+        "Type parameters required",
+        &format!("type `{base_type}` is generic"),
+    );
+}
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::{
-    lexer::{LocatedToken, Token, TokenMatch},
+    lexer::{Keyword, LocatedToken, Symbol, Token, TokenMatch},
     parser::{
         LocatedSyntaxError, NameNode, NameType, Node, ParseResult, SyntaxError, TokenSpan, name,
     },
@@ -9,15 +9,18 @@ use crate::{
 
 pub struct TokenStream {
     tokens: Rc<Vec<LocatedToken>>,
+    // Indices are offset so that the spans of every file in the program are distinct
+    offset: usize,
     index: usize,
     pub errors: Vec<LocatedSyntaxError>,
 }
 
 impl TokenStream {
-    pub fn from(tokens: Rc<Vec<LocatedToken>>) -> Self {
+    pub fn from(tokens: Rc<Vec<LocatedToken>>, offset: usize) -> Self {
         TokenStream {
             tokens,
-            index: 0,
+            offset,
+            index: offset,
             errors: vec![],
         }
     }
@@ -40,7 +43,13 @@ impl TokenStream {
     }
 
     pub fn peek(&self) -> &Token {
-        &self.tokens[self.index].token
+        &self.tokens[self.index - self.offset].token
+    }
+
+    // Looks the given number of tokens past the current one, stopping at the end of file token
+    pub fn peek_ahead(&self, distance: usize) -> &Token {
+        let index = (self.index - self.offset + distance).min(self.tokens.len() - 1);
+        &self.tokens[index].token
     }
 
     pub fn next(&mut self) {
@@ -49,7 +58,7 @@ impl TokenStream {
 
     pub fn is_done(&self) -> bool {
         // The last token is an EOF token
-        self.index >= self.tokens.len() - 1
+        self.index - self.offset >= self.tokens.len() - 1
     }
 
     pub fn name(&mut self, name_type: NameType) -> ParseResult<NameNode> {
@@ -106,4 +115,116 @@ impl TokenStream {
             error,
         }
     }
+
+    // Parses a node, or reports the error and skips ahead to one of the stop symbols, returning a
+    // placeholder node covering the skipped tokens
+    pub fn located_or<P>(
+        &mut self,
+        parse: impl Fn(&mut TokenStream) -> ParseResult<P>,
+        stop: &[Symbol],
+        placeholder: P,
+    ) -> Node<P> {
+        let start_index = self.index;
+        match parse(self) {
+            Ok(value) => self.close(value, start_index),
+            Err(error) => {
+                self.recover(error, stop);
+                if self.index == start_index {
+                    self.current_span().wrap(placeholder)
+                } else {
+                    self.close(placeholder, start_index)
+                }
+            }
+        }
+    }
+
+    // Reports an error and skips tokens until one of the stop symbols, a closing delimiter which
+    // was not opened while skipping, the start of a top level definition or the end of the file
+    pub fn recover(&mut self, error: LocatedSyntaxError, stop: &[Symbol]) {
+        self.errors.push(error);
+        self.skip_to(stop);
+    }
+
+    pub fn skip_to(&mut self, stop: &[Symbol]) {
+        let mut depth = 0;
+        while !self.is_done() {
+            let token = self.peek();
+            let is_stop = stop.iter().any(|symbol| symbol.matches(token));
+            if depth == 0 && (is_stop || is_close(token) || is_definition(token)) {
+                break;
+            }
+
+            if is_open(token) {
+                depth += 1;
+            } else if is_close(token) {
+                depth -= 1;
+            }
+            self.next();
+        }
+    }
+
+    // Whether the next token starts a top level definition, which no list or statement contains
+    pub fn at_definition(&self) -> bool {
+        is_definition(self.peek())
+    }
+
+    // Skips closing brackets which were never opened, up to the end of the statement after them
+    pub fn skip_stray_closes(&mut self) {
+        while self.accept(Symbol::CloseParen) || self.accept(Symbol::CloseBracket) {
+            self.skip_to(&[Symbol::Semicolon]);
+        }
+    }
+
+    // Reports an error and skips tokens until the start of the next top level definition
+    pub fn recover_definition(&mut self, error: LocatedSyntaxError, start_index: usize) {
+        self.errors.push(error);
+        if self.index == start_index {
+            self.next();
+        }
+
+        let mut depth = 0usize;
+        while !self.is_done() {
+            let token = self.peek();
+            if depth == 0 && is_definition(token) {
+                break;
+            }
+
+            if is_open(token) {
+                depth += 1;
+            } else if is_close(token) {
+                depth = depth.saturating_sub(1);
+            }
+            self.next();
+        }
+    }
+}
+
+fn is_open(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Symbol(Symbol::OpenBrace | Symbol::OpenBracket | Symbol::OpenParen)
+    )
+}
+
+// Keywords which may only start a definition at the top level of a module
+fn is_definition(token: &Token) -> bool {
+    use Keyword as K;
+    [
+        K::Enum,
+        K::Fn,
+        K::Import,
+        K::Interface,
+        K::Pub,
+        K::Struct,
+        K::Type,
+    ]
+    .iter()
+    .any(|keyword| keyword.matches(token))
+}
+
+fn is_close(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Symbol(Symbol::CloseBrace | Symbol::CloseBracket | Symbol::CloseParen)
+    )
 }